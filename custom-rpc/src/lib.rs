@@ -1,12 +1,20 @@
 use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::CallError};
 use sc_client_api::HeaderBackend;
 use serde::{Deserialize, Serialize};
-use sp_runtime::traits::Block as BlockT;
+use sp_runtime::{traits::Block as BlockT, Perbill};
 
 use pallet_auction::AuctionDataFor;
-use pallet_bank::{AccountData, LockReason, LockedFund};
-use pallet_nft::NftData;
-use primitives::{AccountId, AuctionId, Balance, BlockNumber, Hash, LockId, NftId, PendingNftPods};
+use pallet_bank::{
+	AccountDataOf, AssetMetadata, FreezeInfoOf, InterestProductOf, LedgerEntryOf, LoanOf,
+	LockReason, LockedFund, ProductDepositOf, StandingOrderOf, VelocityLimit, WithdrawalRequestOf,
+	MAX_STATEMENT_PAGE_SIZE,
+};
+use pallet_nft::NftDataOf;
+use pallet_treasury::TreasuryReport;
+use primitives::{
+	AccountId, AssetId, AuctionId, Balance, BlockNumber, Hash, LockId, NftId, OrderId,
+	PendingNftPods, ProductId,
+};
 use xy_chain_runtime::{
 	runtime_api::{CustomRuntimeApi, DispatchErrorTranslator},
 	Runtime,
@@ -33,12 +41,15 @@ pub struct RpcAccountData {
 	pub free: Balance,
 	pub reserved: Balance,
 	pub locked: Vec<RpcLockedFund>,
+	pub debt: Balance,
+	pub frozen: Option<FreezeInfoOf<Runtime>>,
 }
 
 impl RpcAccountData {
 	fn from_account_data(
-		account_data: AccountData<Balance>,
+		account_data: AccountDataOf<Runtime>,
 		unlock_query: &dyn Fn(LockId) -> BlockNumber,
+		frozen: Option<FreezeInfoOf<Runtime>>,
 	) -> Self {
 		Self {
 			free: account_data.free,
@@ -48,6 +59,8 @@ impl RpcAccountData {
 				.into_iter()
 				.map(|lock| RpcLockedFund::new(lock, unlock_query(lock.id)))
 				.collect::<Vec<_>>(),
+			debt: account_data.debt,
+			frozen,
 		}
 	}
 }
@@ -74,7 +87,105 @@ pub trait CustomRpcApi {
 	) -> RpcResult<Vec<(AuctionId, AuctionDataFor<Runtime>)>>;
 	/// Return a specific NFT data with a NFT id.
 	#[method(name = "nft_data")]
-	fn rpc_nft_data(&self, nft_id: NftId, at: Option<Hash>) -> RpcResult<Option<NftData>>;
+	fn rpc_nft_data(
+		&self,
+		nft_id: NftId,
+		at: Option<Hash>,
+	) -> RpcResult<Option<NftDataOf<Runtime>>>;
+	/// Returns the Account Data of an asset for a user.
+	#[method(name = "asset_account_data")]
+	fn rpc_asset_account_data(
+		&self,
+		who: AccountId,
+		asset_id: AssetId,
+		at: Option<Hash>,
+	) -> RpcResult<RpcAccountData>;
+	/// Returns all the registered assets with their metadata.
+	#[method(name = "assets")]
+	fn rpc_assets(&self, at: Option<Hash>) -> RpcResult<Vec<(AssetId, AssetMetadata<Balance>)>>;
+	/// Returns the active standing orders paid by a user.
+	#[method(name = "standing_orders")]
+	fn rpc_standing_orders(
+		&self,
+		who: AccountId,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<(OrderId, StandingOrderOf<Runtime>)>>;
+	/// Returns the catalogue of savings products.
+	#[method(name = "products")]
+	fn rpc_products(
+		&self,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<(ProductId, InterestProductOf<Runtime>)>>;
+	/// Returns the product deposits of a user, with the interest earned so far.
+	#[method(name = "product_deposits")]
+	fn rpc_product_deposits(
+		&self,
+		who: AccountId,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<(LockId, ProductDepositOf<Runtime>, Balance)>>;
+	/// Returns the open loans of a user, with the interest accrued so far.
+	#[method(name = "loans")]
+	fn rpc_loans(
+		&self,
+		who: AccountId,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<(LockId, LoanOf<Runtime>)>>;
+	/// Returns the pending withdrawal requests of a user, or of all users without a user.
+	#[method(name = "withdrawal_requests")]
+	fn rpc_withdrawal_requests(
+		&self,
+		who: Option<AccountId>,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<(LockId, WithdrawalRequestOf<Runtime>)>>;
+	/// Returns a page of the ledger entries of a user recorded between two blocks inclusive,
+	/// oldest first. The page skips `offset` entries, and holds at most `limit` entries, capped
+	/// at `MAX_STATEMENT_PAGE_SIZE`.
+	#[method(name = "account_statement")]
+	fn rpc_account_statement(
+		&self,
+		who: AccountId,
+		from_block: BlockNumber,
+		to_block: BlockNumber,
+		offset: Option<u32>,
+		limit: Option<u32>,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<LedgerEntryOf<Runtime>>>;
+	/// Returns the fee a user pays to transfer an amount.
+	#[method(name = "transfer_fee")]
+	fn rpc_transfer_fee(
+		&self,
+		who: AccountId,
+		amount: Balance,
+		at: Option<Hash>,
+	) -> RpcResult<Balance>;
+	/// Returns the outgoing value a user can still send today and this week, or `None` if the
+	/// user is not limited.
+	#[method(name = "remaining_allowance")]
+	fn rpc_remaining_allowance(
+		&self,
+		who: AccountId,
+		at: Option<Hash>,
+	) -> RpcResult<Option<VelocityLimit<Balance>>>;
+	/// Returns a page of the interest rates that took effect between two blocks inclusive, oldest
+	/// first, with the block they took effect at. The page skips `offset` rates, and holds at
+	/// most `limit` rates, capped at `MAX_STATEMENT_PAGE_SIZE`.
+	#[method(name = "interest_rate_history")]
+	fn rpc_interest_rate_history(
+		&self,
+		from_block: BlockNumber,
+		to_block: BlockNumber,
+		offset: Option<u32>,
+		limit: Option<u32>,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<(BlockNumber, Perbill)>>;
+	/// Returns the treasury reports of the budget periods between two blocks inclusive.
+	#[method(name = "treasury_reports")]
+	fn rpc_treasury_reports(
+		&self,
+		from_block: BlockNumber,
+		to_block: BlockNumber,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<(BlockNumber, TreasuryReport<Balance>)>>;
 }
 
 pub struct CustomRpc<C, B> {
@@ -124,13 +235,19 @@ where
 			.runtime_api()
 			.account_data(hash, who.clone())
 			.map_err(to_rpc_error)?;
+		let frozen =
+			self.client.runtime_api().freeze_info(hash, who.clone()).map_err(to_rpc_error)?;
 
-		Ok(RpcAccountData::from_account_data(account_data, &|lock_id| {
-			self.client
-				.runtime_api()
-				.fund_unlock_at(hash, who.clone(), lock_id)
-				.unwrap_or_default()
-		}))
+		Ok(RpcAccountData::from_account_data(
+			account_data,
+			&|lock_id| {
+				self.client
+					.runtime_api()
+					.fund_unlock_at(hash, who.clone(), lock_id)
+					.unwrap_or_default()
+			},
+			frozen,
+		))
 	}
 
 	fn rpc_interest_pa(&self, who: AccountId, at: Option<Hash>) -> RpcResult<Balance> {
@@ -158,10 +275,175 @@ where
 			.map_err(to_rpc_error)
 	}
 
-	fn rpc_nft_data(&self, nft_id: NftId, at: Option<Hash>) -> RpcResult<Option<NftData>> {
+	fn rpc_nft_data(
+		&self,
+		nft_id: NftId,
+		at: Option<Hash>,
+	) -> RpcResult<Option<NftDataOf<Runtime>>> {
 		self.client
 			.runtime_api()
 			.nft_data(self.unwrap_or_best(at), nft_id)
 			.map_err(to_rpc_error)
 	}
+	fn rpc_asset_account_data(
+		&self,
+		who: AccountId,
+		asset_id: AssetId,
+		at: Option<Hash>,
+	) -> RpcResult<RpcAccountData> {
+		let hash = self.unwrap_or_best(at);
+		let account_data = self
+			.client
+			.runtime_api()
+			.asset_account_data(hash, who.clone(), asset_id)
+			.map_err(to_rpc_error)?;
+		let frozen =
+			self.client.runtime_api().freeze_info(hash, who.clone()).map_err(to_rpc_error)?;
+
+		Ok(RpcAccountData::from_account_data(
+			account_data,
+			&|lock_id| {
+				self.client
+					.runtime_api()
+					.fund_unlock_at(hash, who.clone(), lock_id)
+					.unwrap_or_default()
+			},
+			frozen,
+		))
+	}
+
+	fn rpc_assets(&self, at: Option<Hash>) -> RpcResult<Vec<(AssetId, AssetMetadata<Balance>)>> {
+		self.client.runtime_api().assets(self.unwrap_or_best(at)).map_err(to_rpc_error)
+	}
+
+	fn rpc_standing_orders(
+		&self,
+		who: AccountId,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<(OrderId, StandingOrderOf<Runtime>)>> {
+		self.client
+			.runtime_api()
+			.standing_orders(self.unwrap_or_best(at), who)
+			.map_err(to_rpc_error)
+	}
+
+	fn rpc_products(
+		&self,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<(ProductId, InterestProductOf<Runtime>)>> {
+		self.client
+			.runtime_api()
+			.products(self.unwrap_or_best(at))
+			.map_err(to_rpc_error)
+	}
+
+	fn rpc_product_deposits(
+		&self,
+		who: AccountId,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<(LockId, ProductDepositOf<Runtime>, Balance)>> {
+		self.client
+			.runtime_api()
+			.product_deposits(self.unwrap_or_best(at), who)
+			.map_err(to_rpc_error)
+	}
+
+	fn rpc_loans(
+		&self,
+		who: AccountId,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<(LockId, LoanOf<Runtime>)>> {
+		self.client
+			.runtime_api()
+			.loans(self.unwrap_or_best(at), who)
+			.map_err(to_rpc_error)
+	}
+
+	fn rpc_withdrawal_requests(
+		&self,
+		who: Option<AccountId>,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<(LockId, WithdrawalRequestOf<Runtime>)>> {
+		self.client
+			.runtime_api()
+			.withdrawal_requests(self.unwrap_or_best(at), who)
+			.map_err(to_rpc_error)
+	}
+
+	fn rpc_account_statement(
+		&self,
+		who: AccountId,
+		from_block: BlockNumber,
+		to_block: BlockNumber,
+		offset: Option<u32>,
+		limit: Option<u32>,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<LedgerEntryOf<Runtime>>> {
+		self.client
+			.runtime_api()
+			.account_statement(
+				self.unwrap_or_best(at),
+				who,
+				from_block,
+				to_block,
+				offset.unwrap_or_default(),
+				limit.unwrap_or(MAX_STATEMENT_PAGE_SIZE),
+			)
+			.map_err(to_rpc_error)
+	}
+
+	fn rpc_transfer_fee(
+		&self,
+		who: AccountId,
+		amount: Balance,
+		at: Option<Hash>,
+	) -> RpcResult<Balance> {
+		self.client
+			.runtime_api()
+			.transfer_fee(self.unwrap_or_best(at), who, amount)
+			.map_err(to_rpc_error)
+	}
+
+	fn rpc_remaining_allowance(
+		&self,
+		who: AccountId,
+		at: Option<Hash>,
+	) -> RpcResult<Option<VelocityLimit<Balance>>> {
+		self.client
+			.runtime_api()
+			.remaining_allowance(self.unwrap_or_best(at), who)
+			.map_err(to_rpc_error)
+	}
+
+	fn rpc_interest_rate_history(
+		&self,
+		from_block: BlockNumber,
+		to_block: BlockNumber,
+		offset: Option<u32>,
+		limit: Option<u32>,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<(BlockNumber, Perbill)>> {
+		self.client
+			.runtime_api()
+			.interest_rate_history(
+				self.unwrap_or_best(at),
+				from_block,
+				to_block,
+				offset.unwrap_or_default(),
+				limit.unwrap_or(MAX_STATEMENT_PAGE_SIZE),
+			)
+			.map_err(to_rpc_error)
+	}
+
+	fn rpc_treasury_reports(
+		&self,
+		from_block: BlockNumber,
+		to_block: BlockNumber,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<(BlockNumber, TreasuryReport<Balance>)>> {
+		self.client
+			.runtime_api()
+			.treasury_reports(self.unwrap_or_best(at), from_block, to_block)
+			.map_err(to_rpc_error)
+	}
 }
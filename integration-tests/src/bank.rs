@@ -1,10 +1,20 @@
-use crate::*;
+use crate::{lottery::dispatch_governance_call, *};
+
+use frame_support::{
+	dispatch::{GetDispatchInfo, PostDispatchInfo},
+	pallet_prelude::Encode,
+	traits::fungible::Mutate,
+};
+use pallet_transaction_payment::ChargeTransactionPayment;
+use primitives::IncomeSource;
+use sp_runtime::traits::SignedExtension;
+use xy_chain_runtime::TransactionPayment;
 
 #[test]
 fn can_set_and_accrue_interest_rate() {
 	ExtBuilder::default().build().execute_with(|| {
 		// Set interest rate to 10%
-		assert_ok!(Bank::set_interest_rate(Manager.sign(), 1_000u32));
+		pallet_bank::InterestRate::<Runtime>::set(Perbill::from_percent(10));
 
 		let amount = 1_000 * DOLLAR;
 
@@ -22,7 +32,7 @@ fn can_set_and_accrue_interest_rate() {
 		assert_staked(Alice.account(), amount + interest);
 
 		// Change to a higher interest rate 20%.
-		assert_ok!(Bank::set_interest_rate(Manager.sign(), 2_000u32));
+		pallet_bank::InterestRate::<Runtime>::set(Perbill::from_percent(20));
 
 		// Verify
 		Bank::on_finalize(DAY);
@@ -31,7 +41,7 @@ fn can_set_and_accrue_interest_rate() {
 		assert_staked(Alice.account(), staked_2 + interest_2);
 
 		// Change to a lower interest rate 5%.
-		assert_ok!(Bank::set_interest_rate(Manager.sign(), 500u32));
+		pallet_bank::InterestRate::<Runtime>::set(Perbill::from_percent(5));
 
 		// Verify
 		Bank::on_finalize(DAY);
@@ -41,6 +51,28 @@ fn can_set_and_accrue_interest_rate() {
 	});
 }
 
+#[test]
+fn scheduled_interest_rate_takes_effect_unless_vetoed() {
+	ExtBuilder::default().build().execute_with(|| {
+		let effective_at = System::block_number() + 7 * DAY;
+		assert_ok!(Bank::schedule_interest_rate(Manager.sign(), 1_000u32, effective_at));
+
+		// Governance vetoes the rate during the notice period.
+		dispatch_governance_call(Box::new(RuntimeCall::Bank(
+			pallet_bank::Call::veto_interest_rate {},
+		)));
+		assert_eq!(Bank::next_interest_rate(), None);
+
+		assert_ok!(Bank::schedule_interest_rate(Manager.sign(), 500u32, effective_at));
+		Bank::on_finalize(effective_at);
+		assert_eq!(Bank::interest_rate(), Perbill::from_percent(5));
+		assert_eq!(
+			Bank::interest_rate_history(0, effective_at, 0, 10),
+			vec![(effective_at, Perbill::from_percent(5))]
+		);
+	});
+}
+
 #[test]
 fn can_print_test_accounts() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -54,3 +86,104 @@ fn can_print_test_accounts() {
 		);
 	});
 }
+
+#[test]
+fn transaction_fees_are_paid_from_bank_funds_into_the_treasury() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Bank accounts can send transactions without any other balance.
+		assert_eq!(System::providers(&Alice.account()), 1);
+		assert_ok!(<Bank as Mutate<AccountId>>::mint_into(&Treasury.account(), DOLLAR));
+
+		let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+		let info = call.get_dispatch_info();
+		let fee = TransactionPayment::compute_fee(10, &info, 0);
+		let total_issuance = Bank::total_issuance();
+
+		let pre = ChargeTransactionPayment::<Runtime>::from(0)
+			.pre_dispatch(&Alice.account(), &call, &info, 10)
+			.unwrap();
+		assert_balance(Alice.account(), INITIAL_BALANCE - fee);
+
+		assert_ok!(ChargeTransactionPayment::<Runtime>::post_dispatch(
+			Some(pre),
+			&info,
+			&PostDispatchInfo::default(),
+			10,
+			&Ok(())
+		));
+
+		// The fee is paid into the treasury.
+		assert_balance(Alice.account(), INITIAL_BALANCE - fee);
+		assert_balance(Treasury.account(), DOLLAR + fee);
+		assert_eq!(Bank::total_issuance(), total_issuance);
+		assert_eq!(
+			pallet_treasury::Reports::<Runtime>::get(0).income,
+			vec![(IncomeSource::TransactionFee, fee)]
+		);
+	});
+}
+
+#[test]
+fn treasury_pays_the_fees_of_sponsored_calls() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(<Bank as Mutate<AccountId>>::mint_into(&Treasury.account(), DOLLAR));
+
+		let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+		let info = call.get_dispatch_info();
+		let fee = TransactionPayment::compute_fee(10, &info, 0);
+		let total_issuance = Bank::total_issuance();
+
+		// Governance sponsors the call by its pallet index and call index.
+		let encoded = call.encode();
+		dispatch_governance_call(Box::new(RuntimeCall::Bank(
+			pallet_bank::Call::set_call_sponsorship {
+				pallet_index: encoded[0],
+				call_index: encoded[1],
+				sponsored: true,
+			},
+		)));
+		assert!(Bank::is_sponsored(&call));
+
+		// Calls are only sponsored within the treasury's budget.
+		dispatch_governance_call(Box::new(RuntimeCall::Treasury(
+			pallet_treasury::Call::set_budget { budget: Some(fee) },
+		)));
+
+		// Sponsored transactions cannot carry a tip.
+		assert!(ChargeTransactionPayment::<Runtime>::from(1)
+			.pre_dispatch(&Alice.account(), &call, &info, 10)
+			.is_err());
+
+		let pre = ChargeTransactionPayment::<Runtime>::from(0)
+			.pre_dispatch(&Alice.account(), &call, &info, 10)
+			.unwrap();
+		assert_ok!(ChargeTransactionPayment::<Runtime>::post_dispatch(
+			Some(pre),
+			&info,
+			&PostDispatchInfo::default(),
+			10,
+			&Ok(())
+		));
+
+		// The treasury paid the fee, which is burned.
+		assert_balance(Alice.account(), INITIAL_BALANCE);
+		assert_balance(Treasury.account(), DOLLAR - fee);
+		assert_eq!(Bank::total_issuance(), total_issuance - fee);
+		assert_eq!(pallet_treasury::Reports::<Runtime>::get(0).sponsored, fee);
+
+		// Once the budget is used up, the sender pays the fee.
+		let pre = ChargeTransactionPayment::<Runtime>::from(0)
+			.pre_dispatch(&Alice.account(), &call, &info, 10)
+			.unwrap();
+		assert_ok!(ChargeTransactionPayment::<Runtime>::post_dispatch(
+			Some(pre),
+			&info,
+			&PostDispatchInfo::default(),
+			10,
+			&Ok(())
+		));
+		assert_balance(Alice.account(), INITIAL_BALANCE - fee);
+		assert_balance(Treasury.account(), DOLLAR);
+		assert_eq!(pallet_treasury::Reports::<Runtime>::get(0).sponsored, fee);
+	});
+}
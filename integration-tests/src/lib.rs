@@ -1,7 +1,7 @@
 #![cfg(test)]
 
-use frame_support::{assert_ok, traits::Hooks};
-use sp_runtime::{BuildStorage, Percent};
+use frame_support::{assert_ok, bounded_vec, traits::Hooks};
+use sp_runtime::{BuildStorage, Perbill, Percent};
 
 use primitives::{constants::*, AccountId, Balance, Role, DAY};
 use traits::{BasicAccounting, Stakable};
@@ -15,6 +15,7 @@ use xy_chain_runtime::{
 mod bank;
 mod lottery;
 mod nft;
+mod treasury;
 
 pub const INITIAL_BALANCE: Balance = 1_000_000 * DOLLAR;
 pub const TICKETPRICE: Balance = DOLLAR;
@@ -0,0 +1,56 @@
+use crate::*;
+
+use lottery::dispatch_governance_call;
+use pallet_treasury::TreasuryReport;
+use primitives::IncomeSource;
+
+#[test]
+fn treasury_records_income_and_spends_with_governance_approval() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Lottery::buy_ticket(Alice.sign(), 100));
+		dispatch_governance_call(Box::new(RuntimeCall::Lottery(
+			pallet_lottery::Call::force_draw {},
+		)));
+		Lottery::on_finalize(System::block_number());
+
+		// The lottery tax is recorded as the treasury's income.
+		let tax = Percent::from_percent(5) * 100 * DOLLAR;
+		assert_balance(Treasury.account(), tax);
+		assert_eq!(
+			pallet_treasury::Reports::<Runtime>::get(0),
+			TreasuryReport {
+				income: bounded_vec![(IncomeSource::LotteryTax, tax)],
+				spent: 0,
+				sponsored: 0,
+				burned: 0,
+			}
+		);
+
+		// Governance can spend the treasury's funds within the budget.
+		dispatch_governance_call(Box::new(RuntimeCall::Treasury(
+			pallet_treasury::Call::set_budget { budget: Some(DOLLAR) },
+		)));
+		dispatch_governance_call(Box::new(RuntimeCall::Treasury(pallet_treasury::Call::spend {
+			beneficiary: Bob.account(),
+			amount: DOLLAR,
+		})));
+		System::assert_has_event(RuntimeEvent::Treasury(
+			pallet_treasury::Event::<Runtime>::Spent { beneficiary: Bob.account(), amount: DOLLAR },
+		));
+		assert_balance(Treasury.account(), tax - DOLLAR);
+		assert_balance(Bob.account(), INITIAL_BALANCE + DOLLAR);
+
+		assert_eq!(
+			pallet_treasury::Pallet::<Runtime>::reports_between(0, System::block_number()),
+			vec![(
+				0,
+				TreasuryReport {
+					income: bounded_vec![(IncomeSource::LotteryTax, tax)],
+					spent: DOLLAR,
+					sponsored: 0,
+					burned: 0,
+				}
+			)]
+		);
+	});
+}
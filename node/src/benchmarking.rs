@@ -5,7 +5,7 @@
 use crate::service::FullClient;
 
 use primitives::{AccountId, Balance};
-use runtime::{BalancesCall, SystemCall};
+use runtime::{BankCall, SystemCall};
 use sc_cli::Result;
 use sc_client_api::BlockBackend;
 use sp_core::{Encode, Pair};
@@ -52,38 +52,38 @@ impl frame_benchmarking_cli::ExtrinsicBuilder for RemarkBuilder {
 	}
 }
 
-/// Generates `Balances::TransferKeepAlive` extrinsics for the benchmarks.
+/// Generates `Bank::Transfer` extrinsics for the benchmarks.
 ///
 /// Note: Should only be used for benchmarking.
-pub struct TransferKeepAliveBuilder {
+pub struct TransferBuilder {
 	client: Arc<FullClient>,
 	dest: AccountId,
 	value: Balance,
 }
 
-impl TransferKeepAliveBuilder {
+impl TransferBuilder {
 	/// Creates a new [`Self`] from the given client.
 	pub fn new(client: Arc<FullClient>, dest: AccountId, value: Balance) -> Self {
 		Self { client, dest, value }
 	}
 }
 
-impl frame_benchmarking_cli::ExtrinsicBuilder for TransferKeepAliveBuilder {
+impl frame_benchmarking_cli::ExtrinsicBuilder for TransferBuilder {
 	fn pallet(&self) -> &str {
-		"balances"
+		"bank"
 	}
 
 	fn extrinsic(&self) -> &str {
-		"transfer_keep_alive"
+		"transfer"
 	}
 
 	fn build(&self, nonce: u32) -> std::result::Result<OpaqueExtrinsic, &'static str> {
-		let acc = Sr25519Keyring::Bob.pair();
+		// Transfers are sent by customers.
+		let acc = Sr25519Keyring::Charlie.pair();
 		let extrinsic: OpaqueExtrinsic = create_benchmark_extrinsic(
 			self.client.as_ref(),
 			acc,
-			BalancesCall::transfer_keep_alive { dest: self.dest.clone().into(), value: self.value }
-				.into(),
+			BankCall::transfer { to_user: self.dest.clone(), amount: self.value }.into(),
 			nonce,
 		)
 		.into();
@@ -78,10 +78,6 @@ fn testnet_genesis(
 	_enable_println: bool,
 ) -> serde_json::Value {
 	serde_json::json!({
-		"balances": {
-			// Configure endowed accounts with initial balance of 1 << 60.
-			"balances": endowed_accounts.iter().cloned().map(|k| (k, 1u64 << 60)).collect::<Vec<_>>(),
-		},
 		"aura": {
 			"authorities": initial_authorities.iter().map(|x| (x.0.clone())).collect::<Vec<_>>(),
 		},
@@ -102,6 +98,14 @@ fn testnet_genesis(
 						None
 					}
 				})
+				// Configure the other endowed accounts with initial balance of 1 << 60 to pay
+				// transaction fees.
+				.chain(
+					endowed_accounts
+						.iter()
+						.filter(|acc| !roles.contains(&((*acc).clone(), Role::Customer)))
+						.map(|acc| (acc.clone(), 1u128 << 60, 0u128)),
+				)
 				.collect::<Vec<_>>(),
 			"treasury": Some(get_account_id_from_seed::<sr25519::Public>("Treasury"))
 		},
@@ -1,5 +1,5 @@
 use crate::{
-	benchmarking::{inherent_benchmark_data, RemarkBuilder, TransferKeepAliveBuilder},
+	benchmarking::{inherent_benchmark_data, RemarkBuilder, TransferBuilder},
 	chain_spec,
 	cli::{Cli, Subcommand},
 	service,
@@ -151,12 +151,12 @@ pub fn run() -> sc_cli::Result<()> {
 					},
 					BenchmarkCmd::Extrinsic(cmd) => {
 						let PartialComponents { client, .. } = service::new_partial(&config)?;
-						// Register the *Remark* and *TKA* builders.
+						// Register the *Remark* and *Transfer* builders.
 						let ext_factory = ExtrinsicFactory(vec![
 							Box::new(RemarkBuilder::new(client.clone())),
-							Box::new(TransferKeepAliveBuilder::new(
+							Box::new(TransferBuilder::new(
 								client.clone(),
-								Sr25519Keyring::Alice.to_account_id(),
+								Sr25519Keyring::Dave.to_account_id(),
 								DOLLAR,
 							)),
 						]);
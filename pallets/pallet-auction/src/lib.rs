@@ -15,8 +15,10 @@ use sp_runtime::{
 };
 use sp_std::{fmt::Debug, prelude::*, vec::Vec};
 
-use primitives::{AuctionData, AuctionId, NftId, NftState};
-use traits::{BasicAccounting, GetTreasury, ManageAuctions, ManageNfts, ManageRoles};
+use primitives::{AuctionData, AuctionId, IncomeSource, NftId, NftState, TransferReason};
+use traits::{
+	BasicAccounting, GetTreasury, ManageAuctions, ManageNfts, ManageRoles, RecordTreasuryIncome,
+};
 
 mod mock;
 mod tests;
@@ -67,6 +69,9 @@ pub mod module {
 
 		type Bank: BasicAccounting<Self::AccountId, Self::Balance> + GetTreasury<Self::AccountId>;
 
+		/// Records the income paid into the treasury account.
+		type TreasuryIncome: RecordTreasuryIncome<Self::Balance>;
+
 		type NftManager: ManageNfts<Self::AccountId>;
 
 		#[pallet::constant]
@@ -86,6 +91,10 @@ pub mod module {
 
 		#[pallet::constant]
 		type ExtendedLength: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of auctions that expire at a block.
+		#[pallet::constant]
+		type MaxAuctionsPerBlock: Get<u32>;
 	}
 
 	#[pallet::error]
@@ -97,6 +106,8 @@ pub mod module {
 		/// When the current auction price exceeds the reserve price, the auction can not be
 		/// canceled.
 		CannotCancelAuction,
+		/// Too many auctions expire at the same block.
+		TooManyAuctionsExpiring,
 	}
 
 	#[pallet::event]
@@ -134,11 +145,18 @@ pub mod module {
 
 	/// Stores the auction IDs that are set to expire at a specific block.
 	#[pallet::storage]
-	pub type AuctionsExpiryBlock<T: Config> =
-		StorageMap<_, Blake2_128Concat, BlockNumberFor<T>, Vec<AuctionId>, ValueQuery>;
+	pub type AuctionsExpiryBlock<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>,
+		BoundedVec<AuctionId, T::MaxAuctionsPerBlock>,
+		ValueQuery,
+	>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
 
 	#[pallet::pallet]
-	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -146,13 +164,20 @@ pub mod module {
 		fn on_finalize(block_number: BlockNumberFor<T>) {
 			// Expire auctions that are due.
 			AuctionsExpiryBlock::<T>::take(block_number).into_iter().for_each(|auction_id| {
-				if let Some(auction_data) = Auctions::<T>::take(auction_id) {
+				// A bid may have extended the auction, leaving its old expiry behind.
+				if let Some(auction_data) = Auctions::<T>::get(auction_id) {
 					if auction_data.expiry_block == block_number {
+						Auctions::<T>::remove(auction_id);
 						Self::resolve_auction(auction_id, auction_data);
 					}
 				}
 			});
 		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_block_number: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
 	}
 
 	#[pallet::call]
@@ -192,13 +217,20 @@ pub mod module {
 			);
 
 			// Append the auction id to the AuctionsExpiryBlock storage.
-			AuctionsExpiryBlock::<T>::append(expiry_block, auction_id);
+			AuctionsExpiryBlock::<T>::try_append(expiry_block, auction_id)
+				.map_err(|_| Error::<T>::TooManyAuctionsExpiring)?;
 
 			// Ensure the nft state is free, then change Nft state to auction.
 			T::NftManager::change_nft_state(nft_id, NftState::Auction(auction_id))?;
 
 			// Pay fee.
-			T::Bank::transfer(&id, &T::Bank::treasury()?, T::AuctionStartFee::get())?;
+			T::Bank::transfer_with_reason(
+				&id,
+				&T::Bank::treasury()?,
+				T::AuctionStartFee::get(),
+				TransferReason::AuctionFee,
+			)?;
+			T::TreasuryIncome::record_income(IncomeSource::AuctionFee, T::AuctionStartFee::get());
 
 			Self::deposit_event(Event::<T>::AuctionCreated { who: id, auction_id });
 			Ok(())
@@ -221,12 +253,22 @@ pub mod module {
 				// Refound money to the last bidder, the bid pool has enough money to transfer back,
 				// therefore the transfer will succeed.
 				auction_data.current_bid.as_ref().map(|(last_bidder, last_price)| {
-					T::Bank::transfer(&T::BidsPoolAccount::get(), last_bidder, *last_price)
+					T::Bank::transfer_with_reason(
+						&T::BidsPoolAccount::get(),
+						last_bidder,
+						*last_price,
+						TransferReason::AuctionRefund,
+					)
 				});
 				// When the bid price is greater than Buy now, the auction is end.
 				if new_price >= auction_data.buy_now.unwrap_or_default() {
 					// Transfer bid to Bids Pool's account.
-					T::Bank::transfer(&new_bidder, &T::BidsPoolAccount::get(), new_price)?;
+					T::Bank::transfer_with_reason(
+						&new_bidder,
+						&T::BidsPoolAccount::get(),
+						new_price,
+						TransferReason::AuctionBid,
+					)?;
 
 					Self::complete_auction(new_bidder, new_price, auction_id, auction_data.nft_id);
 					// nft change state
@@ -250,7 +292,12 @@ pub mod module {
 					);
 
 					// Transfer bid to Bids Pool's account.
-					T::Bank::transfer(&new_bidder, &T::BidsPoolAccount::get(), new_price)?;
+					T::Bank::transfer_with_reason(
+						&new_bidder,
+						&T::BidsPoolAccount::get(),
+						new_price,
+						TransferReason::AuctionBid,
+					)?;
 
 					// Update current bid's storage.
 					auction_data.current_bid = Some((new_bidder.clone(), new_price));
@@ -265,7 +312,8 @@ pub mod module {
 						auction_data.expiry_block = new_expiry;
 
 						// Add the new block number to the storage.
-						AuctionsExpiryBlock::<T>::append(new_expiry, auction_id);
+						AuctionsExpiryBlock::<T>::try_append(new_expiry, auction_id)
+							.map_err(|_| Error::<T>::TooManyAuctionsExpiring)?;
 					}
 
 					Self::deposit_event(Event::<T>::BidRegistered {
@@ -307,7 +355,12 @@ pub mod module {
 					Self::complete_auction(bider, price, auction_id, auction_data.nft_id);
 				} else {
 					// return money to last bider
-					let _ = T::Bank::transfer(&bid_pool, &bider, price);
+					let _ = T::Bank::transfer_with_reason(
+						&bid_pool,
+						&bider,
+						price,
+						TransferReason::AuctionRefund,
+					);
 					Self::deposit_event(Event::<T>::AuctionExpired { auction_id });
 				}
 			} else {
@@ -329,11 +382,25 @@ pub mod module {
 			let tax = T::AuctionSuccessFeePercentage::get() * price;
 			let rest = price.saturating_sub(tax);
 			if let Ok(treasury) = T::Bank::treasury() {
-				let _ = T::Bank::transfer(&bid_pool, &treasury, tax);
+				if T::Bank::transfer_with_reason(
+					&bid_pool,
+					&treasury,
+					tax,
+					TransferReason::AuctionFee,
+				)
+				.is_ok()
+				{
+					T::TreasuryIncome::record_income(IncomeSource::AuctionFee, tax);
+				}
 			}
 			// nft change owner, every Nft must have an owner, so that it must be ok.
 			if let Ok(owner) = T::NftManager::nft_transfer(nft_id, &bider) {
-				let _ = T::Bank::transfer(&bid_pool, &owner, rest);
+				let _ = T::Bank::transfer_with_reason(
+					&bid_pool,
+					&owner,
+					rest,
+					TransferReason::AuctionPayout,
+				);
 			}
 			Self::deposit_event(Event::<T>::AuctionSucceeded {
 				auction_id,
@@ -358,7 +425,12 @@ pub mod module {
 					if let Some((bider, price)) = auction_data.current_bid {
 						(cancel == CancelOption::Force ||
 							price < auction_data.reserve.unwrap_or_default())
-						.then_some(T::Bank::transfer(&T::BidsPoolAccount::get(), &bider, price))
+						.then_some(T::Bank::transfer_with_reason(
+							&T::BidsPoolAccount::get(),
+							&bider,
+							price,
+							TransferReason::AuctionRefund,
+						))
 						.ok_or(Error::<T>::CannotCancelAuction)??;
 						// Transfer back the money to the bider.
 					}
@@ -370,11 +442,28 @@ pub mod module {
 				}) // Result here is Some(Ok())
 				.ok_or(Error::<T>::InvalidAuctionId)? // Convert to Ok(Ok()) then use ? to become Ok()
 		}
+
+		/// Checks that the bids pool holds the current bids of all auctions.
+		#[cfg(any(feature = "try-runtime", test))]
+		pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+			let bids = Auctions::<T>::iter_values()
+				.filter_map(|auction_data| auction_data.current_bid.map(|(_, price)| price))
+				.fold(T::Balance::default(), |total, price| total.saturating_add(price));
+			ensure!(
+				T::Bank::free_balance(&T::BidsPoolAccount::get()) >= bids,
+				"The bids pool does not hold the current bids."
+			);
+			Ok(())
+		}
 	}
 
 	impl<T: Config> ManageAuctions<T::AccountId> for Pallet<T> {
 		fn force_cancel(auction_id: AuctionId) -> DispatchResult {
 			Self::do_cancel_auction(auction_id, CancelOption::Force)
 		}
+
+		fn auction_nft(auction_id: AuctionId) -> Option<NftId> {
+			Auctions::<T>::get(auction_id).map(|auction_data| auction_data.nft_id)
+		}
 	}
 }
@@ -8,7 +8,7 @@ use frame_support::{
 
 use sp_runtime::{testing::H256, traits::IdentityLookup, BuildStorage};
 
-use primitives::{Balance, Role};
+use primitives::{Balance, Role, TransferReason};
 
 use crate as pallet_auction;
 
@@ -77,9 +77,31 @@ impl BasicAccounting<AccountId, Balance> for MockBank {
 		TransferHistory::set(history);
 		Ok(())
 	}
-	fn free_balance(_user: &AccountId) -> Balance {
+	fn transfer_with_reason(
+		from: &AccountId,
+		to: &AccountId,
+		amount: Balance,
+		_reason: TransferReason,
+	) -> DispatchResult {
+		Self::transfer(from, to, amount)
+	}
+	fn transfer_from(
+		_spender: &AccountId,
+		_from: &AccountId,
+		_to: &AccountId,
+		_amount: Balance,
+	) -> DispatchResult {
 		unimplemented!();
 	}
+	fn free_balance(user: &AccountId) -> Balance {
+		TransferHistory::get().iter().fold(0, |balance, (from, to, amount)| {
+			match (from == user, to == user) {
+				(false, true) => balance + amount,
+				(true, false) => balance.saturating_sub(*amount),
+				_ => balance,
+			}
+		})
+	}
 }
 impl GetTreasury<AccountId> for MockBank {
 	fn treasury() -> Result<AccountId, DispatchError> {
@@ -102,6 +124,7 @@ impl Config for Runtime {
 	type RoleManager = Roles;
 	type Balance = Balance;
 	type Bank = MockBank;
+	type TreasuryIncome = ();
 	type NftManager = Nft;
 	type BidsPoolAccount = BidsPoolAccount;
 	type AuctionSuccessFeePercentage = AuctionSuccessFeePercentage;
@@ -109,6 +132,7 @@ impl Config for Runtime {
 	type MinimumIncrease = MinimumIncrease;
 	type AuctionLength = AuctionLength;
 	type ExtendedLength = ExtendedLength;
+	type MaxAuctionsPerBlock = ConstU32<100>;
 }
 
 parameter_types! {
@@ -126,9 +150,11 @@ impl pallet_nft::Config for Runtime {
 	type Bank = MockBank;
 	type AuctionManager = Auction;
 	type EnsureGovernance = traits::SuccessOrigin<Runtime>;
+	type TreasuryIncome = ();
 	type MaxSize = MaxSize;
 	type PodFee = Fee;
 	type NftLockedPeriod = NftLockedPeriod;
+	type MaxPodsPerBlock = ConstU32<100>;
 }
 
 impl pallet_roles::Config for Runtime {
@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use crate::{mock::*, *};
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, bounded_vec};
 use primitives::{AuctionData, DOLLAR};
 
 #[test]
@@ -121,7 +121,7 @@ fn can_success_auction() {
 			},
 		);
 
-		AuctionsExpiryBlock::<Runtime>::insert(100, vec![1u32]);
+		AuctionsExpiryBlock::<Runtime>::insert(100, bounded_vec![1u32]);
 
 		Auction::on_finalize(100);
 
@@ -156,7 +156,7 @@ fn can_expire_auction() {
 			},
 		);
 
-		AuctionsExpiryBlock::<Runtime>::insert(100, vec![1u32]);
+		AuctionsExpiryBlock::<Runtime>::insert(100, bounded_vec![1u32]);
 
 		Auction::on_finalize(100);
 
@@ -282,3 +282,44 @@ fn error_handling() {
 		);
 	});
 }
+
+#[test]
+fn try_state_checks_bids_pool() {
+	default_test_ext().execute_with(|| {
+		Auctions::<Runtime>::insert(
+			1u32,
+			AuctionData {
+				nft_id: 1u32,
+				start: Some(100u128),
+				reserve: Some(10 * DOLLAR),
+				buy_now: Some(20 * DOLLAR),
+				expiry_block: 100,
+				current_bid: None,
+			},
+		);
+
+		AuctionsExpiryBlock::<Runtime>::insert(100, bounded_vec![1u32]);
+
+		System::set_block_number(99);
+		assert_ok!(Auction::bid(RuntimeOrigin::signed(BOB), 1u32, 10 * DOLLAR));
+		assert_ok!(Auction::do_try_state());
+
+		// The old expiry must not resolve the extended auction.
+		Auction::on_finalize(100);
+		assert_eq!(Auctions::<Runtime>::get(1).unwrap().expiry_block, 109);
+		assert_ok!(Auction::do_try_state());
+
+		Auctions::<Runtime>::insert(
+			2u32,
+			AuctionData {
+				nft_id: 2u32,
+				start: Some(100u128),
+				reserve: Some(10 * DOLLAR),
+				buy_now: Some(20 * DOLLAR),
+				expiry_block: 100,
+				current_bid: Some((ALICE, 11 * DOLLAR)),
+			},
+		);
+		assert!(Auction::do_try_state().is_err());
+	});
+}
@@ -3,7 +3,7 @@
 use super::*;
 
 use frame_benchmarking::v2::*;
-use frame_support::{assert_ok, traits::UnfilteredDispatchable};
+use frame_support::{assert_ok, bounded_vec, traits::UnfilteredDispatchable};
 use frame_system::RawOrigin;
 use primitives::DOLLAR;
 #[benchmarks]
@@ -31,7 +31,8 @@ mod benchmarks {
 			AccountData {
 				free: (DOLLAR * 1_000_000).into(),
 				reserved: (DOLLAR * 1_000_000).into(),
-				locked: vec![],
+				locked: bounded_vec![],
+				debt: Zero::zero(),
 			},
 		);
 		Accounts::<T>::insert(
@@ -39,17 +40,58 @@ mod benchmarks {
 			AccountData {
 				free: (DOLLAR * 1_000_000).into(),
 				reserved: (DOLLAR * 1_000_000).into(),
-				locked: vec![LockedFund {
+				locked: bounded_vec![LockedFund {
 					id: 1u64,
 					amount: (DOLLAR * 5).into(),
 					reason: LockReason::Auditor,
 				}],
+				debt: Zero::zero(),
 			},
 		);
 
 		MockUsers { manager, auditor, customer_1, customer_2 }
 	}
 
+	fn setup_asset<T: Config>(accounts: &MockUsers<T::AccountId>) -> AssetId {
+		let asset_id = 1u32;
+		Assets::<T>::insert(
+			asset_id,
+			AssetMetadata {
+				name: b"Euro".to_vec().try_into().unwrap(),
+				symbol: b"EUR".to_vec().try_into().unwrap(),
+				decimals: 12u8,
+				existential_deposit: DOLLAR.into(),
+			},
+		);
+		AssetAccounts::<T>::insert(
+			&accounts.customer_1,
+			asset_id,
+			AccountData {
+				free: (DOLLAR * 1_000_000).into(),
+				reserved: (DOLLAR * 1_000_000).into(),
+				locked: bounded_vec![],
+				debt: Zero::zero(),
+			},
+		);
+		asset_id
+	}
+
+	fn setup_product<T: Config>() -> ProductId {
+		let product_id = 1u32;
+		Products::<T>::insert(
+			product_id,
+			InterestProduct {
+				kind: ProductKind::FixedTerm {
+					term: 100u32.into(),
+					rate: Perbill::from_percent(5),
+					penalty: Perbill::from_percent(1),
+				},
+				active: true,
+			},
+		);
+		product_id
+	}
+
 	#[benchmark]
 	fn deposit() {
 		let accounts = setup::<T>();
@@ -142,7 +184,8 @@ mod benchmarks {
 			AccountData {
 				free: free_balance - amount,
 				reserved: reserved_balance,
-				locked: vec![LockedFund { id: 1u64, amount, reason: LockReason::Auditor },]
+				locked: bounded_vec![LockedFund { id: 1u64, amount, reason: LockReason::Auditor },],
+				debt: Zero::zero(),
 			}
 		);
 	}
@@ -166,15 +209,29 @@ mod benchmarks {
 	}
 
 	#[benchmark]
-	fn set_interest_rate() {
+	fn schedule_interest_rate() {
 		let accounts = setup::<T>();
 		let interest_rate = Perbill::from_percent(5);
+		let effective_at =
+			frame_system::Pallet::<T>::current_block_number() + T::InterestRateNoticePeriod::get();
+		RateBounds::<T>::put(InterestRateBounds {
+			min: Zero::zero(),
+			max: Perbill::from_percent(10),
+			max_change: Perbill::from_percent(5),
+		});
 
 		#[extrinsic_call]
-		set_interest_rate(RawOrigin::Signed(accounts.manager.clone()), interest_rate * 10_000u32);
+		schedule_interest_rate(
+			RawOrigin::Signed(accounts.manager.clone()),
+			interest_rate * 10_000u32,
+			effective_at,
+		);
 
 		// Verify
-		assert_eq!(InterestRate::<T>::get(), interest_rate);
+		assert_eq!(
+			NextInterestRate::<T>::get(),
+			Some(ScheduledInterestRate { manager: accounts.manager, interest_rate, effective_at })
+		);
 	}
 
 	#[benchmark]
@@ -217,5 +274,1076 @@ mod benchmarks {
 		assert_eq!(Accounts::<T>::get(&accounts.customer_2).free, initial_balance_2 + amount);
 	}
 
+	#[benchmark]
+	fn register_asset() {
+		let call = Call::<T>::register_asset {
+			name: b"Euro".to_vec().try_into().unwrap(),
+			symbol: b"EUR".to_vec().try_into().unwrap(),
+			decimals: 12u8,
+			existential_deposit: DOLLAR.into(),
+		};
+		let origin = T::EnsureGovernance::try_successful_origin().unwrap();
+
+		#[block]
+		{
+			assert_ok!(call.dispatch_bypass_filter(origin));
+		}
+
+		// Verify
+		assert!(Assets::<T>::contains_key(1u32));
+	}
+
+	#[benchmark]
+	fn deposit_asset() {
+		let accounts = setup::<T>();
+		let asset_id = setup_asset::<T>(&accounts);
+		let initial_balance = AssetAccounts::<T>::get(&accounts.customer_1, asset_id).free;
+		let amount = (DOLLAR * 5).into();
+
+		#[extrinsic_call]
+		deposit_asset(
+			RawOrigin::Signed(accounts.manager),
+			asset_id,
+			accounts.customer_1.clone(),
+			amount,
+		);
+
+		// Verify
+		assert_eq!(
+			AssetAccounts::<T>::get(&accounts.customer_1, asset_id).free,
+			initial_balance + amount
+		);
+	}
+
+	#[benchmark]
+	fn withdraw_asset() {
+		let accounts = setup::<T>();
+		let asset_id = setup_asset::<T>(&accounts);
+		let initial_balance = AssetAccounts::<T>::get(&accounts.customer_1, asset_id).free;
+		let amount = (DOLLAR * 5).into();
+
+		#[extrinsic_call]
+		withdraw_asset(
+			RawOrigin::Signed(accounts.manager),
+			asset_id,
+			accounts.customer_1.clone(),
+			amount,
+		);
+
+		// Verify
+		assert_eq!(
+			AssetAccounts::<T>::get(&accounts.customer_1, asset_id).free,
+			initial_balance - amount
+		);
+	}
+
+	#[benchmark]
+	fn transfer_asset() {
+		let accounts = setup::<T>();
+		let asset_id = setup_asset::<T>(&accounts);
+		let initial_balance = AssetAccounts::<T>::get(&accounts.customer_1, asset_id).free;
+		let amount = (DOLLAR * 5).into();
+
+		#[extrinsic_call]
+		transfer_asset(
+			RawOrigin::Signed(accounts.customer_1.clone()),
+			asset_id,
+			accounts.customer_2.clone(),
+			amount,
+		);
+
+		// Verify
+		assert_eq!(
+			AssetAccounts::<T>::get(&accounts.customer_1, asset_id).free,
+			initial_balance - amount
+		);
+		assert_eq!(AssetAccounts::<T>::get(&accounts.customer_2, asset_id).free, amount);
+	}
+
+	#[benchmark]
+	fn stake_asset_funds() {
+		let accounts = setup::<T>();
+		let asset_id = setup_asset::<T>(&accounts);
+		let initial_balance = AssetAccounts::<T>::get(&accounts.customer_1, asset_id).free;
+		let amount = (DOLLAR * 5).into();
+
+		#[extrinsic_call]
+		stake_asset_funds(RawOrigin::Signed(accounts.customer_1.clone()), asset_id, amount);
+
+		// Verify
+		let account_data = AssetAccounts::<T>::get(&accounts.customer_1, asset_id);
+		assert_eq!(account_data.free, initial_balance - amount);
+		assert_eq!(account_data.locked[0].amount, amount);
+		assert_eq!(AssetLocks::<T>::get(account_data.locked[0].id), Some(asset_id));
+	}
+
+	#[benchmark]
+	fn redeem_asset_funds() {
+		let accounts = setup::<T>();
+		let asset_id = setup_asset::<T>(&accounts);
+		let reserved_balance = AssetAccounts::<T>::get(&accounts.customer_1, asset_id).reserved;
+		let amount = (DOLLAR * 5).into();
+
+		#[extrinsic_call]
+		redeem_asset_funds(RawOrigin::Signed(accounts.customer_1.clone()), asset_id, amount);
+
+		// Verify
+		let account_data = AssetAccounts::<T>::get(&accounts.customer_1, asset_id);
+		assert_eq!(account_data.reserved, reserved_balance - amount);
+		assert_eq!(account_data.locked[0].amount, amount);
+	}
+
+	fn setup_standing_order<T: Config>(accounts: &MockUsers<T::AccountId>) -> OrderId {
+		let order_id = 1u32;
+		StandingOrders::<T>::insert(
+			order_id,
+			StandingOrder {
+				from: accounts.customer_1.clone(),
+				to: accounts.customer_2.clone(),
+				amount: (DOLLAR * 5).into(),
+				next_execution: 10u32.into(),
+				period: Some(10u32.into()),
+				policy: FailurePolicy::Skip,
+				retries: 0u32,
+			},
+		);
+		assert_ok!(StandingOrderSchedule::<T>::try_append(
+			BlockNumberFor::<T>::from(10u32),
+			order_id
+		));
+		order_id
+	}
+
+	#[benchmark]
+	fn create_standing_order() {
+		let accounts = setup::<T>();
+		let amount = (DOLLAR * 5).into();
+
+		#[extrinsic_call]
+		create_standing_order(
+			RawOrigin::Signed(accounts.customer_1.clone()),
+			accounts.customer_2.clone(),
+			amount,
+			10u32.into(),
+			Some(10u32.into()),
+			FailurePolicy::Retry,
+		);
+
+		// Verify
+		assert_eq!(StandingOrders::<T>::get(1u32).map(|order| order.amount), Some(amount));
+		assert_eq!(StandingOrderSchedule::<T>::get(BlockNumberFor::<T>::from(10u32)), vec![1u32]);
+	}
+
+	#[benchmark]
+	fn amend_standing_order() {
+		let accounts = setup::<T>();
+		let order_id = setup_standing_order::<T>(&accounts);
+		let amount = (DOLLAR * 10).into();
+
+		#[extrinsic_call]
+		amend_standing_order(
+			RawOrigin::Signed(accounts.customer_1),
+			order_id,
+			amount,
+			20u32.into(),
+			None,
+			FailurePolicy::Retry,
+		);
+
+		// Verify
+		assert_eq!(StandingOrders::<T>::get(order_id).map(|order| order.amount), Some(amount));
+		assert_eq!(
+			StandingOrderSchedule::<T>::get(BlockNumberFor::<T>::from(20u32)),
+			vec![order_id]
+		);
+	}
+
+	#[benchmark]
+	fn cancel_standing_order() {
+		let accounts = setup::<T>();
+		let order_id = setup_standing_order::<T>(&accounts);
+
+		#[extrinsic_call]
+		cancel_standing_order(RawOrigin::Signed(accounts.customer_1), order_id);
+
+		// Verify
+		assert!(!StandingOrders::<T>::contains_key(order_id));
+	}
+
+	fn setup_escrow<T: Config>(accounts: &MockUsers<T::AccountId>) -> LockId {
+		let lock_id = 2u64;
+		Accounts::<T>::mutate(&accounts.customer_1, |account| {
+			assert_ok!(account.locked.try_push(LockedFund {
+				id: lock_id,
+				amount: (DOLLAR * 5).into(),
+				reason: LockReason::Escrow,
+			}));
+		});
+		Escrows::<T>::insert(
+			lock_id,
+			EscrowInfo {
+				payer: accounts.customer_1.clone(),
+				payee: accounts.customer_2.clone(),
+				arbiter: accounts.auditor.clone(),
+				deadline: 10u32.into(),
+				disputed: false,
+			},
+		);
+		lock_id
+	}
+
+	#[benchmark]
+	fn create_escrow() {
+		let accounts = setup::<T>();
+		let initial_balance = Accounts::<T>::get(&accounts.customer_1).free;
+		let amount = (DOLLAR * 5).into();
+
+		#[extrinsic_call]
+		create_escrow(
+			RawOrigin::Signed(accounts.customer_1.clone()),
+			accounts.customer_2.clone(),
+			accounts.auditor.clone(),
+			amount,
+			10u32.into(),
+		);
+
+		// Verify
+		let account_data = Accounts::<T>::get(&accounts.customer_1);
+		assert_eq!(account_data.free, initial_balance - amount);
+		assert_eq!(account_data.locked[0].reason, LockReason::Escrow);
+		assert!(Escrows::<T>::contains_key(account_data.locked[0].id));
+	}
+
+	#[benchmark]
+	fn release_escrow() {
+		let accounts = setup::<T>();
+		let lock_id = setup_escrow::<T>(&accounts);
+		let initial_balance = Accounts::<T>::get(&accounts.customer_2).free;
+
+		#[extrinsic_call]
+		release_escrow(RawOrigin::Signed(accounts.customer_1.clone()), lock_id);
+
+		// Verify
+		assert!(!Escrows::<T>::contains_key(lock_id));
+		assert!(Accounts::<T>::get(&accounts.customer_1).locked.is_empty());
+		assert_eq!(
+			Accounts::<T>::get(&accounts.customer_2).free,
+			initial_balance + (DOLLAR * 5).into()
+		);
+	}
+
+	#[benchmark]
+	fn refund_escrow() {
+		let accounts = setup::<T>();
+		let lock_id = setup_escrow::<T>(&accounts);
+		let initial_balance = Accounts::<T>::get(&accounts.customer_1).free;
+
+		#[extrinsic_call]
+		refund_escrow(RawOrigin::Signed(accounts.auditor), lock_id);
+
+		// Verify
+		assert!(!Escrows::<T>::contains_key(lock_id));
+		assert_eq!(
+			Accounts::<T>::get(&accounts.customer_1).free,
+			initial_balance + (DOLLAR * 5).into()
+		);
+	}
+
+	#[benchmark]
+	fn dispute_escrow() {
+		let accounts = setup::<T>();
+		let lock_id = setup_escrow::<T>(&accounts);
+
+		#[extrinsic_call]
+		dispute_escrow(RawOrigin::Signed(accounts.customer_2), lock_id);
+
+		// Verify
+		assert_eq!(Escrows::<T>::get(lock_id).map(|escrow| escrow.disputed), Some(true));
+	}
+
+	#[benchmark]
+	fn pay_interest(n: Linear<1, 500>) {
+		let total_accounts = Accounts::<T>::iter_keys().count() as u32 + n;
+		(0..n).for_each(|i| {
+			Accounts::<T>::insert(
+				account::<T::AccountId>("customer", i, 0u32),
+				AccountData {
+					free: Zero::zero(),
+					reserved: (DOLLAR * 1_000_000).into(),
+					locked: bounded_vec![],
+					debt: Zero::zero(),
+				},
+			);
+		});
+		InterestRate::<T>::set(Perbill::from_percent(5));
+		let period_end = T::InterestPayoutPeriod::get();
+		frame_system::Pallet::<T>::set_block_number(period_end);
+		Pallet::<T>::update_interest_index(period_end);
+		InterestPayoutQueue::<T>::put(BoundedVec::truncate_from(vec![InterestPayout {
+			period_end,
+			interest_rate: Perbill::from_percent(5),
+			total_interest_payed: Zero::zero(),
+			last_account: None,
+		}]));
+
+		#[block]
+		{
+			Pallet::<T>::pay_interest(total_accounts);
+		}
+
+		// Verify
+		assert!(InterestPayoutQueue::<T>::get().is_empty());
+	}
+
+	#[benchmark]
+	fn charge_overdraft_interest(n: Linear<1, 500>) {
+		let accounts = setup::<T>();
+		TreasuryAccount::<T>::set(Some(accounts.manager.clone()));
+		(0..n).for_each(|i| {
+			let user: T::AccountId = account("overdraft", i, 0u32);
+			let limit: T::Balance = (DOLLAR * 1_000).into();
+			OverdraftLimits::<T>::insert(&user, limit);
+			Accounts::<T>::insert(
+				&user,
+				AccountData {
+					free: Zero::zero(),
+					reserved: Zero::zero(),
+					locked: bounded_vec![],
+					debt: (DOLLAR * 1_000).into(),
+				},
+			);
+		});
+		OverdraftChargeQueue::<T>::put(BoundedVec::truncate_from(vec![OverdraftCharge {
+			rate: FixedU128::from_perbill(Perbill::from_percent(10)),
+			last_account: None,
+		}]));
+
+		#[block]
+		{
+			Pallet::<T>::charge_overdraft_interest(n);
+		}
+
+		// Verify
+		assert!(OverdraftChargeQueue::<T>::get().is_empty());
+	}
+
+	#[benchmark]
+	fn reap_accounts(n: Linear<1, 100>) {
+		let accounts = setup::<T>();
+		TreasuryAccount::<T>::set(Some(accounts.manager.clone()));
+		let dust: T::Balance = 1u32.into();
+		(0..n).for_each(|i| {
+			let user: T::AccountId = account("dust", i, 0u32);
+			Accounts::<T>::insert(
+				&user,
+				AccountData {
+					free: dust,
+					reserved: Zero::zero(),
+					locked: bounded_vec![],
+					debt: Zero::zero(),
+				},
+			);
+			DustCandidates::<T>::insert(&user, NATIVE_ASSET_ID, ());
+		});
+
+		#[block]
+		{
+			Pallet::<T>::reap_accounts(n);
+		}
+
+		// Verify
+		assert_eq!(DustCandidates::<T>::iter().count(), 0);
+		assert_eq!(Accounts::<T>::get(&accounts.manager).free, dust * n.into());
+	}
+
+	#[benchmark]
+	fn create_product() {
+		let accounts = setup::<T>();
+		let kind = ProductKind::Tiered(bounded_vec![
+			(Zero::zero(), Perbill::from_percent(1)),
+			((DOLLAR * 1_000).into(), Perbill::from_percent(2)),
+		]);
+
+		#[extrinsic_call]
+		create_product(RawOrigin::Signed(accounts.manager), kind);
+
+		// Verify
+		assert!(Products::<T>::contains_key(1u32));
+	}
+
+	#[benchmark]
+	fn retire_product() {
+		let accounts = setup::<T>();
+		let product_id = setup_product::<T>();
+
+		#[extrinsic_call]
+		retire_product(RawOrigin::Signed(accounts.manager), product_id);
+
+		// Verify
+		assert_eq!(Products::<T>::get(product_id).map(|product| product.active), Some(false));
+	}
+
+	#[benchmark]
+	fn stake_funds_into_product() {
+		let accounts = setup::<T>();
+		let product_id = setup_product::<T>();
+		let initial_balance = Accounts::<T>::get(&accounts.customer_1).free;
+		let amount = (DOLLAR * 5).into();
+
+		#[extrinsic_call]
+		stake_funds_into_product(
+			RawOrigin::Signed(accounts.customer_1.clone()),
+			product_id,
+			amount,
+		);
+
+		// Verify
+		let account_data = Accounts::<T>::get(&accounts.customer_1);
+		assert_eq!(account_data.free, initial_balance - amount);
+		assert_eq!(account_data.locked[0].reason, LockReason::Product);
+		assert!(ProductDeposits::<T>::contains_key(account_data.locked[0].id));
+	}
+
+	#[benchmark]
+	fn redeem_product_funds() {
+		let accounts = setup::<T>();
+		let product_id = setup_product::<T>();
+		TreasuryAccount::<T>::set(Some(accounts.manager.clone()));
+		let amount = (DOLLAR * 5).into();
+		assert_ok!(Pallet::<T>::stake_funds_into_product(
+			RawOrigin::Signed(accounts.customer_1.clone()).into(),
+			product_id,
+			amount,
+		));
+		let lock_id = Accounts::<T>::get(&accounts.customer_1).locked[0].id;
+
+		// Withdraw early, so the penalty is paid to the treasury.
+		#[extrinsic_call]
+		redeem_product_funds(RawOrigin::Signed(accounts.customer_1.clone()), lock_id);
+
+		// Verify
+		assert!(!ProductDeposits::<T>::contains_key(lock_id));
+		assert!(Accounts::<T>::get(&accounts.customer_1).locked.is_empty());
+		assert_eq!(Accounts::<T>::get(&accounts.manager).free, Perbill::from_percent(1) * amount);
+	}
+
+	fn setup_loan<T: Config>(accounts: &MockUsers<T::AccountId>, interest: T::Balance) -> LockId {
+		let lock_id = 2u64;
+		Accounts::<T>::mutate(&accounts.customer_1, |account| {
+			assert_ok!(account.locked.try_push(LockedFund {
+				id: lock_id,
+				amount: (DOLLAR * 10).into(),
+				reason: LockReason::Collateral,
+			}));
+		});
+		Loans::<T>::insert(
+			lock_id,
+			Loan {
+				borrower: accounts.customer_1.clone(),
+				collateral: (DOLLAR * 10).into(),
+				principal: (DOLLAR * 5).into(),
+				interest,
+				rate: Perbill::from_percent(10),
+				updated_at: frame_system::Pallet::<T>::current_block_number(),
+				liquidation_at: None,
+			},
+		);
+		lock_id
+	}
+
+	#[benchmark]
+	fn set_borrow_rate() {
+		let accounts = setup::<T>();
+
+		#[extrinsic_call]
+		set_borrow_rate(RawOrigin::Signed(accounts.manager), 1_000u32);
+
+		// Verify
+		assert_eq!(BorrowRate::<T>::get(), Perbill::from_percent(10));
+	}
+
+	#[benchmark]
+	fn borrow() {
+		let accounts = setup::<T>();
+		BorrowRate::<T>::set(Perbill::from_percent(10));
+		let initial_balance = Accounts::<T>::get(&accounts.customer_1).free;
+		let collateral = (DOLLAR * 10).into();
+		let amount = (DOLLAR * 5).into();
+
+		#[extrinsic_call]
+		borrow(RawOrigin::Signed(accounts.customer_1.clone()), collateral, amount);
+
+		// Verify
+		let account_data = Accounts::<T>::get(&accounts.customer_1);
+		assert_eq!(account_data.free, initial_balance + amount);
+		assert_eq!(account_data.locked[0].reason, LockReason::Collateral);
+		assert!(Loans::<T>::contains_key(account_data.locked[0].id));
+	}
+
+	#[benchmark]
+	fn repay_loan() {
+		let accounts = setup::<T>();
+		TreasuryAccount::<T>::set(Some(accounts.manager.clone()));
+		let interest = DOLLAR.into();
+		let lock_id = setup_loan::<T>(&accounts, interest);
+
+		// Repay the loan in full, so the collateral is returned.
+		#[extrinsic_call]
+		repay_loan(RawOrigin::Signed(accounts.customer_1.clone()), lock_id, (DOLLAR * 6).into());
+
+		// Verify
+		assert!(!Loans::<T>::contains_key(lock_id));
+		assert!(Accounts::<T>::get(&accounts.customer_1).locked.is_empty());
+		assert_eq!(Accounts::<T>::get(&accounts.manager).free, interest);
+	}
+
+	#[benchmark]
+	fn liquidate_loan() {
+		let accounts = setup::<T>();
+		TreasuryAccount::<T>::set(Some(accounts.manager.clone()));
+		let lock_id = setup_loan::<T>(&accounts, (DOLLAR * 3).into());
+
+		#[extrinsic_call]
+		liquidate_loan(RawOrigin::Signed(accounts.manager.clone()), lock_id);
+
+		// Verify
+		assert!(!Loans::<T>::contains_key(lock_id));
+		assert!(Accounts::<T>::get(&accounts.customer_1).locked.is_empty());
+		assert_eq!(Accounts::<T>::get(&accounts.manager).free, (DOLLAR * 10).into());
+	}
+
+	#[benchmark]
+	fn set_overdraft_limit() {
+		let accounts = setup::<T>();
+		let limit = (DOLLAR * 100).into();
+
+		#[extrinsic_call]
+		set_overdraft_limit(
+			RawOrigin::Signed(accounts.manager),
+			accounts.customer_1.clone(),
+			limit,
+		);
+
+		// Verify
+		assert_eq!(OverdraftLimits::<T>::get(&accounts.customer_1), limit);
+	}
+
+	#[benchmark]
+	fn set_overdraft_rate() {
+		let accounts = setup::<T>();
+
+		#[extrinsic_call]
+		set_overdraft_rate(RawOrigin::Signed(accounts.manager), 2_000u32);
+
+		// Verify
+		assert_eq!(OverdraftRate::<T>::get(), Perbill::from_percent(20));
+	}
+
+	#[benchmark]
+	fn freeze_account() {
+		let accounts = setup::<T>();
+
+		#[extrinsic_call]
+		freeze_account(
+			RawOrigin::Signed(accounts.auditor),
+			accounts.customer_1.clone(),
+			FreezeReason::Investigation,
+			Some(100u32.into()),
+		);
+
+		// Verify
+		assert!(FrozenAccounts::<T>::contains_key(&accounts.customer_1));
+	}
+
+	#[benchmark]
+	fn unfreeze_account() {
+		let accounts = setup::<T>();
+		FrozenAccounts::<T>::insert(
+			&accounts.customer_1,
+			FreezeInfo {
+				auditor: accounts.auditor.clone(),
+				reason: FreezeReason::Investigation,
+				expiry: None,
+			},
+		);
+
+		#[extrinsic_call]
+		unfreeze_account(RawOrigin::Signed(accounts.auditor), accounts.customer_1.clone());
+
+		// Verify
+		assert!(!FrozenAccounts::<T>::contains_key(&accounts.customer_1));
+	}
+
+	#[benchmark]
+	fn increase_auditor_lock() {
+		let accounts = setup::<T>();
+		assert_ok!(AccountWithUnlockedFund::<T>::try_append(
+			BlockNumberFor::<T>::from(100u32),
+			(accounts.customer_2.clone(), 1u64),
+		));
+		LockExpiries::<T>::insert(&accounts.customer_2, 1u64, BlockNumberFor::<T>::from(100u32));
+
+		#[extrinsic_call]
+		increase_auditor_lock(
+			RawOrigin::Signed(accounts.auditor),
+			accounts.customer_2.clone(),
+			1u64,
+			(DOLLAR * 5).into(),
+		);
+
+		// Verify
+		assert_eq!(Accounts::<T>::get(&accounts.customer_2).locked[0].amount, (DOLLAR * 10).into());
+	}
+
+	#[benchmark]
+	fn partially_unlock_funds_auditor() {
+		let accounts = setup::<T>();
+		assert_ok!(AccountWithUnlockedFund::<T>::try_append(
+			BlockNumberFor::<T>::from(100u32),
+			(accounts.customer_2.clone(), 1u64),
+		));
+		LockExpiries::<T>::insert(&accounts.customer_2, 1u64, BlockNumberFor::<T>::from(100u32));
+
+		#[extrinsic_call]
+		partially_unlock_funds_auditor(
+			RawOrigin::Signed(accounts.auditor),
+			accounts.customer_2.clone(),
+			1u64,
+			(DOLLAR * 2).into(),
+		);
+
+		// Verify
+		assert_eq!(Accounts::<T>::get(&accounts.customer_2).locked[0].amount, (DOLLAR * 3).into());
+	}
+
+	#[benchmark]
+	fn extend_auditor_lock() {
+		let accounts = setup::<T>();
+		assert_ok!(AccountWithUnlockedFund::<T>::try_append(
+			BlockNumberFor::<T>::from(100u32),
+			(accounts.customer_2.clone(), 1u64),
+		));
+		LockExpiries::<T>::insert(&accounts.customer_2, 1u64, BlockNumberFor::<T>::from(100u32));
+
+		#[extrinsic_call]
+		extend_auditor_lock(
+			RawOrigin::Signed(accounts.auditor),
+			accounts.customer_2.clone(),
+			1u64,
+			100u32.into(),
+		);
+
+		// Verify
+		assert_eq!(
+			Pallet::<T>::fund_unlock_at(accounts.customer_2, 1u64),
+			BlockNumberFor::<T>::from(200u32)
+		);
+	}
+
+	#[benchmark]
+	fn set_transfer_fee_schedule() {
+		let schedule = FeeSchedule::Tiered(bounded_vec![
+			(Zero::zero(), DOLLAR.into()),
+			((DOLLAR * 1_000).into(), (DOLLAR * 5).into()),
+		]);
+		let call = Call::<T>::set_transfer_fee_schedule { schedule: Some(schedule.clone()) };
+		let origin = T::EnsureGovernance::try_successful_origin().unwrap();
+
+		#[block]
+		{
+			assert_ok!(call.dispatch_bypass_filter(origin));
+		}
+
+		// Verify
+		assert_eq!(TransferFeeSchedule::<T>::get(), Some(schedule));
+	}
+
+	#[benchmark]
+	fn set_default_velocity_limit() {
+		let accounts = setup::<T>();
+		let limit =
+			VelocityLimit { daily: (DOLLAR * 1_000).into(), weekly: (DOLLAR * 5_000).into() };
+
+		#[extrinsic_call]
+		set_default_velocity_limit(RawOrigin::Signed(accounts.manager), Some(limit));
+
+		// Verify
+		assert_eq!(DefaultVelocityLimit::<T>::get(), Some(limit));
+	}
+
+	#[benchmark]
+	fn set_velocity_limit() {
+		let accounts = setup::<T>();
+		let limit =
+			VelocityLimit { daily: (DOLLAR * 1_000).into(), weekly: (DOLLAR * 5_000).into() };
+
+		#[extrinsic_call]
+		set_velocity_limit(
+			RawOrigin::Signed(accounts.manager),
+			accounts.customer_1.clone(),
+			Some(limit),
+		);
+
+		// Verify
+		assert_eq!(VelocityLimits::<T>::get(&accounts.customer_1), Some(limit));
+	}
+
+	#[benchmark]
+	fn create_joint_account(n: Linear<2, { T::MaxJointHolders::get() }>) {
+		let accounts = setup::<T>();
+		let mut holders = vec![accounts.customer_1.clone()];
+		(1..n).for_each(|i| {
+			let holder: T::AccountId = account("holder", i, 0u32);
+			assert_ok!(T::RoleManager::register_role(&holder, Role::Customer));
+			holders.push(holder);
+		});
+		holders.sort();
+
+		#[extrinsic_call]
+		create_joint_account(RawOrigin::Signed(accounts.customer_1), holders.clone(), n);
+
+		// Verify
+		assert!(JointAccounts::<T>::contains_key(Pallet::<T>::joint_account_id(&holders, n)));
+	}
+
+	fn setup_joint_account<T: Config>(accounts: &MockUsers<T::AccountId>) -> T::AccountId {
+		let mut holders = vec![accounts.customer_1.clone(), accounts.customer_2.clone()];
+		holders.sort();
+		let joint_account = Pallet::<T>::joint_account_id(&holders, 2u32);
+		assert_ok!(T::RoleManager::register_role(&joint_account, Role::Customer));
+		JointAccounts::<T>::insert(
+			&joint_account,
+			JointAccount { holders: holders.try_into().unwrap(), threshold: 2u32 },
+		);
+		Accounts::<T>::insert(
+			&joint_account,
+			AccountData {
+				free: (DOLLAR * 1_000).into(),
+				reserved: Zero::zero(),
+				locked: bounded_vec![],
+				debt: Zero::zero(),
+			},
+		);
+		joint_account
+	}
+
+	#[benchmark]
+	fn propose_joint_transfer() {
+		let accounts = setup::<T>();
+		let joint_account = setup_joint_account::<T>(&accounts);
+
+		#[extrinsic_call]
+		propose_joint_transfer(
+			RawOrigin::Signed(accounts.customer_1),
+			joint_account,
+			accounts.customer_2,
+			(DOLLAR * 100).into(),
+		);
+
+		// Verify
+		assert!(JointTransfers::<T>::contains_key(1u32));
+	}
+
+	#[benchmark]
+	fn vote_joint_transfer() {
+		let accounts = setup::<T>();
+		let joint_account = setup_joint_account::<T>(&accounts);
+		assert_ok!(Pallet::<T>::propose_joint_transfer(
+			RawOrigin::Signed(accounts.customer_1.clone()).into(),
+			joint_account.clone(),
+			accounts.customer_1.clone(),
+			(DOLLAR * 100).into(),
+		));
+
+		#[extrinsic_call]
+		vote_joint_transfer(RawOrigin::Signed(accounts.customer_2), 1u32, true);
+
+		// Verify
+		assert!(!JointTransfers::<T>::contains_key(1u32));
+		assert_eq!(Accounts::<T>::get(&joint_account).free, (DOLLAR * 900).into());
+	}
+
+	#[benchmark]
+	fn cancel_joint_transfer() {
+		let accounts = setup::<T>();
+		let joint_account = setup_joint_account::<T>(&accounts);
+		assert_ok!(Pallet::<T>::propose_joint_transfer(
+			RawOrigin::Signed(accounts.customer_1.clone()).into(),
+			joint_account,
+			accounts.customer_1.clone(),
+			(DOLLAR * 100).into(),
+		));
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::current_block_number() + T::JointTransferPeriod::get(),
+		);
+
+		#[extrinsic_call]
+		cancel_joint_transfer(RawOrigin::Signed(accounts.customer_2), 1u32);
+
+		// Verify
+		assert!(!JointTransfers::<T>::contains_key(1u32));
+	}
+
+	#[benchmark]
+	fn approve() {
+		let accounts = setup::<T>();
+		let amount = (DOLLAR * 100).into();
+
+		#[extrinsic_call]
+		approve(
+			RawOrigin::Signed(accounts.customer_1.clone()),
+			accounts.customer_2.clone(),
+			amount,
+			Some(100u32.into()),
+		);
+
+		// Verify
+		assert_eq!(
+			Allowances::<T>::get(&accounts.customer_1, &accounts.customer_2).map(|a| a.amount),
+			Some(amount)
+		);
+	}
+
+	#[benchmark]
+	fn increase_allowance() {
+		let accounts = setup::<T>();
+		Allowances::<T>::insert(
+			&accounts.customer_1,
+			&accounts.customer_2,
+			Allowance { amount: (DOLLAR * 100).into(), expiry: None },
+		);
+
+		#[extrinsic_call]
+		increase_allowance(
+			RawOrigin::Signed(accounts.customer_1.clone()),
+			accounts.customer_2.clone(),
+			(DOLLAR * 100).into(),
+		);
+
+		// Verify
+		assert_eq!(
+			Allowances::<T>::get(&accounts.customer_1, &accounts.customer_2).map(|a| a.amount),
+			Some((DOLLAR * 200).into())
+		);
+	}
+
+	#[benchmark]
+	fn revoke() {
+		let accounts = setup::<T>();
+		Allowances::<T>::insert(
+			&accounts.customer_1,
+			&accounts.customer_2,
+			Allowance { amount: (DOLLAR * 100).into(), expiry: None },
+		);
+
+		#[extrinsic_call]
+		revoke(RawOrigin::Signed(accounts.customer_1.clone()), accounts.customer_2.clone());
+
+		// Verify
+		assert!(!Allowances::<T>::contains_key(&accounts.customer_1, &accounts.customer_2));
+	}
+
+	#[benchmark]
+	fn transfer_from() {
+		let accounts = setup::<T>();
+		let amount = (DOLLAR * 100).into();
+		Allowances::<T>::insert(
+			&accounts.customer_1,
+			&accounts.customer_2,
+			Allowance { amount: (DOLLAR * 200).into(), expiry: None },
+		);
+		let initial_balance = Accounts::<T>::get(&accounts.customer_2).free;
+
+		#[extrinsic_call]
+		transfer_from(
+			RawOrigin::Signed(accounts.customer_2.clone()),
+			accounts.customer_1.clone(),
+			accounts.customer_2.clone(),
+			amount,
+		);
+
+		// Verify
+		assert_eq!(Accounts::<T>::get(&accounts.customer_2).free, initial_balance + amount);
+	}
+
+	#[benchmark]
+	fn batch_transfer(n: Linear<1, { T::MaxBatchTransfers::get() }>) {
+		let accounts = setup::<T>();
+		let amount = (DOLLAR * 100).into();
+		let transfers = (0..n)
+			.map(|i| {
+				let recipient: T::AccountId = account("recipient", i, 0u32);
+				assert_ok!(T::RoleManager::register_role(&recipient, Role::Customer));
+				(recipient, amount)
+			})
+			.collect::<Vec<_>>();
+		let transfers: BoundedVec<_, T::MaxBatchTransfers> = transfers.try_into().unwrap();
+
+		#[extrinsic_call]
+		batch_transfer(
+			RawOrigin::Signed(accounts.customer_1),
+			transfers.clone(),
+			BatchMode::Atomic,
+		);
+
+		// Verify
+		transfers.iter().for_each(|(recipient, amount)| {
+			assert_eq!(Accounts::<T>::get(recipient).free, *amount);
+		});
+	}
+
+	#[benchmark]
+	fn veto_interest_rate() {
+		let accounts = setup::<T>();
+		NextInterestRate::<T>::put(ScheduledInterestRate {
+			manager: accounts.manager,
+			interest_rate: Perbill::from_percent(5),
+			effective_at: T::InterestRateNoticePeriod::get(),
+		});
+		let call = Call::<T>::veto_interest_rate {};
+		let origin = T::EnsureGovernance::try_successful_origin().unwrap();
+
+		#[block]
+		{
+			assert_ok!(call.dispatch_bypass_filter(origin));
+		}
+
+		// Verify
+		assert_eq!(NextInterestRate::<T>::get(), None);
+	}
+
+	#[benchmark]
+	fn set_interest_rate_bounds() {
+		let bounds = InterestRateBounds {
+			min: Perbill::from_percent(1),
+			max: Perbill::from_percent(10),
+			max_change: Perbill::from_percent(2),
+		};
+		let call = Call::<T>::set_interest_rate_bounds { bounds: Some(bounds) };
+		let origin = T::EnsureGovernance::try_successful_origin().unwrap();
+
+		#[block]
+		{
+			assert_ok!(call.dispatch_bypass_filter(origin));
+		}
+
+		// Verify
+		assert_eq!(RateBounds::<T>::get(), Some(bounds));
+	}
+
+	#[benchmark]
+	fn set_call_sponsorship() {
+		let call =
+			Call::<T>::set_call_sponsorship { pallet_index: 1, call_index: 2, sponsored: true };
+		let origin = T::EnsureGovernance::try_successful_origin().unwrap();
+
+		#[block]
+		{
+			assert_ok!(call.dispatch_bypass_filter(origin));
+		}
+
+		// Verify
+		assert!(SponsoredCalls::<T>::get().contains(&(1, 2)));
+	}
+
+	fn setup_withdrawal<T: Config>(accounts: &MockUsers<T::AccountId>) -> LockId {
+		assert_ok!(Pallet::<T>::request_withdrawal(
+			RawOrigin::Signed(accounts.customer_1.clone()).into(),
+			(DOLLAR * 5).into()
+		));
+		NextLockId::<T>::get()
+	}
+
+	#[benchmark]
+	fn request_withdrawal() {
+		let accounts = setup::<T>();
+		let initial_balance = Accounts::<T>::get(&accounts.customer_1).free;
+		let amount = (DOLLAR * 5).into();
+
+		#[extrinsic_call]
+		request_withdrawal(RawOrigin::Signed(accounts.customer_1.clone()), amount);
+
+		// Verify
+		let account_data = Accounts::<T>::get(&accounts.customer_1);
+		assert_eq!(account_data.free, initial_balance - amount);
+		assert_eq!(account_data.locked[0].reason, LockReason::Withdrawal);
+		assert!(WithdrawalRequests::<T>::contains_key(account_data.locked[0].id));
+	}
+
+	#[benchmark]
+	fn approve_withdrawal() {
+		let accounts = setup::<T>();
+		let lock_id = setup_withdrawal::<T>(&accounts);
+		let initial_issuance = TotalIssuance::<T>::get();
+
+		#[extrinsic_call]
+		approve_withdrawal(RawOrigin::Signed(accounts.manager), lock_id);
+
+		// Verify
+		assert!(!WithdrawalRequests::<T>::contains_key(lock_id));
+		assert!(Accounts::<T>::get(&accounts.customer_1).locked.is_empty());
+		assert_eq!(TotalIssuance::<T>::get(), initial_issuance.saturating_sub((DOLLAR * 5).into()));
+	}
+
+	#[benchmark]
+	fn reject_withdrawal() {
+		let accounts = setup::<T>();
+		let lock_id = setup_withdrawal::<T>(&accounts);
+		let initial_balance = Accounts::<T>::get(&accounts.customer_1).free;
+
+		#[extrinsic_call]
+		reject_withdrawal(RawOrigin::Signed(accounts.manager), lock_id);
+
+		// Verify
+		assert!(!WithdrawalRequests::<T>::contains_key(lock_id));
+		assert_eq!(
+			Accounts::<T>::get(&accounts.customer_1).free,
+			initial_balance + (DOLLAR * 5).into()
+		);
+	}
+
+	#[benchmark]
+	fn transfer_with_memo() {
+		let accounts = setup::<T>();
+		let amount = (DOLLAR * 5).into();
+		let initial_balance_1 = Accounts::<T>::get(&accounts.customer_1).free;
+		let initial_balance_2 = Accounts::<T>::get(&accounts.customer_2).free;
+		let memo = Memo::Invoice(
+			BoundedVec::try_from(vec![b'x'; MEMO_MAXSIZE as usize]).expect("Memo is bounded"),
+		);
+		#[extrinsic_call]
+		transfer_with_memo(
+			RawOrigin::Signed(accounts.customer_1.clone()),
+			accounts.customer_2.clone(),
+			amount,
+			memo,
+		);
+
+		// Verify
+		assert_eq!(Accounts::<T>::get(&accounts.customer_1).free, initial_balance_1 - amount);
+		assert_eq!(Accounts::<T>::get(&accounts.customer_2).free, initial_balance_2 + amount);
+	}
+
+	#[benchmark]
+	fn deposit_with_reference() {
+		let accounts = setup::<T>();
+		let initial_balance = Accounts::<T>::get(&accounts.customer_1).free;
+		let amount = (DOLLAR * 5).into();
+		let reference = Memo::External(
+			BoundedVec::try_from(vec![b'x'; MEMO_MAXSIZE as usize]).expect("Memo is bounded"),
+		);
+
+		#[extrinsic_call]
+		deposit_with_reference(
+			RawOrigin::Signed(accounts.manager),
+			accounts.customer_1.clone(),
+			amount,
+			reference,
+		);
+
+		// Verify
+		assert_eq!(Accounts::<T>::get(accounts.customer_1).free, initial_balance + amount);
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::default_test_ext(), crate::mock::Runtime);
 }
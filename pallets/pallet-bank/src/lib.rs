@@ -3,23 +3,48 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::MaxEncodedLen;
-use frame_support::{pallet_prelude::*, traits::BuildGenesisConfig};
+use frame_support::{
+	pallet_prelude::*,
+	storage::with_storage_layer,
+	traits::{
+		fungible,
+		tokens::{DepositConsequence, Fortitude, Preservation, Provenance, WithdrawConsequence},
+		BuildGenesisConfig, Imbalance, OnUnbalanced,
+	},
+};
 use frame_system::pallet_prelude::*;
 use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
 use sp_arithmetic::traits::Zero;
 use sp_runtime::{
-	traits::{AtLeast32BitUnsigned, BlockNumberProvider, One, Saturating},
+	traits::{
+		AtLeast32BitUnsigned, BlakeTwo256, BlockNumberProvider, CheckedAdd, CheckedDiv, CheckedSub,
+		Hash, One, Saturating, TrailingZeroInput,
+	},
 	DispatchResult, FixedPointNumber, FixedU128, Perbill,
 };
-use sp_std::{cmp::min, fmt::Debug, prelude::*, vec::Vec};
+use sp_std::{
+	cmp::{max, min},
+	collections::btree_set::BTreeSet,
+	fmt::Debug,
+	marker::PhantomData,
+	prelude::*,
+	vec::Vec,
+};
 
-use primitives::{LockId, Role};
-use traits::{BasicAccounting, GetTreasury, ManageRoles, Stakable};
+use primitives::{
+	AssetId, IncomeSource, LockId, OrderId, ProductId, ProposalId, Role, TransferReason,
+	ASSET_NAME_MAXSIZE, ASSET_SYMBOL_MAXSIZE, MEMO_MAXSIZE, NATIVE_ASSET_ID,
+};
+use traits::{
+	BasicAccounting, GetTreasury, ManageRoles, MultiAssetAccounting, MultiAssetStakable,
+	RecordTreasuryIncome, Stakable,
+};
 
 mod mock;
 mod tests;
 
+pub mod migrations;
 pub mod weights;
 pub use weights::*;
 
@@ -43,6 +68,11 @@ pub enum LockReason {
 	Stake,
 	Redeem,
 	Auditor,
+	Escrow,
+	Product,
+	Collateral,
+	Hold,
+	Withdrawal,
 }
 
 #[derive(
@@ -87,633 +117,4642 @@ pub struct LockedFund<Balance> {
 #[derive(
 	Encode,
 	Decode,
-	Clone,
-	PartialEq,
-	Eq,
-	Default,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	DefaultNoBound,
 	MaxEncodedLen,
-	RuntimeDebug,
+	RuntimeDebugNoBound,
 	TypeInfo,
 	Serialize,
 	Deserialize,
 )]
-pub struct AccountData<Balance> {
+#[serde(bound(serialize = "Balance: Serialize", deserialize = "Balance: Deserialize<'de>"))]
+#[codec(mel_bound(Balance: MaxEncodedLen))]
+#[scale_info(skip_type_params(MaxLocks))]
+pub struct AccountData<Balance: Clone + Eq + Default + Debug, MaxLocks: Get<u32>> {
 	pub free: Balance,
 	pub reserved: Balance,
-	pub locked: Vec<LockedFund<Balance>>,
+	pub locked: BoundedVec<LockedFund<Balance>, MaxLocks>,
+	/// The funds drawn on the overdraft facility and not repaid yet.
+	pub debt: Balance,
 }
 
-impl<Balance: Saturating + Copy + sp_std::iter::Sum> AccountData<Balance> {
+impl<
+		Balance: Saturating + Copy + Ord + Default + Debug + sp_std::iter::Sum,
+		MaxLocks: Get<u32>,
+	> AccountData<Balance, MaxLocks>
+{
+	/// Returns the funds held by the account. The overdraft debt is not deducted: overdrawn
+	/// funds are minted when drawn and burned when repaid, so the total issuance is still the
+	/// sum of the accounts' totals.
 	pub fn total(&self) -> Balance {
 		self.free
 			.saturating_add(self.reserved)
 			.saturating_add(self.locked.iter().map(|l| l.amount).sum())
 	}
+
+	/// Add funds to "free", repaying the overdraft debt first. Returns the debt repaid.
+	pub fn credit(&mut self, amount: Balance) -> Balance {
+		let repaid = min(self.debt, amount);
+		self.debt = self.debt.saturating_sub(repaid);
+		self.free = self.free.saturating_add(amount.saturating_sub(repaid));
+		repaid
+	}
 }
 
-pub use module::*;
+/// Why an auditor froze an account.
+#[derive(
+	Encode,
+	Decode,
+	Copy,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub enum FreezeReason {
+	Investigation,
+	Fraud,
+	Sanctions,
+	LegalOrder,
+}
 
-#[frame_support::pallet]
-pub mod module {
+/// An account-level freeze. Frozen accounts can receive funds, but cannot move funds out.
+#[derive(
+	Encode,
+	Decode,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub struct FreezeInfo<AccountId, BlockNumber> {
+	pub auditor: AccountId,
+	pub reason: FreezeReason,
+	/// The account is unfrozen at this block, if any.
+	pub expiry: Option<BlockNumber>,
+}
 
-	use super::*;
+/// Progress of settling the interest of a payout period.
+#[derive(
+	Encode,
+	Decode,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub struct InterestPayout<AccountId, Balance, BlockNumber> {
+	/// The block that ended the payout period.
+	pub period_end: BlockNumber,
+	/// The interest rate per annum when the period ended.
+	pub interest_rate: Perbill,
+	/// The interest realised by the payout, not including the interest realised earlier when
+	/// the accounts were touched.
+	pub total_interest_payed: Balance,
+	/// The last account paid, `None` if no account is paid yet.
+	pub last_account: Option<AccountId>,
+}
 
-	#[pallet::config]
-	pub trait Config: frame_system::Config {
-		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+/// The maximum number of payout periods whose interest is being settled.
+pub type MaxInterestPayouts = ConstU32<16>;
 
-		/// Type representing the weight of this pallet
-		type WeightInfo: WeightInfo;
+/// Progress of charging the overdraft interest of a payout period.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct OverdraftCharge<AccountId> {
+	/// The overdraft interest rate of the period.
+	pub rate: FixedU128,
+	/// The last account charged, `None` if no account is charged yet.
+	pub last_account: Option<AccountId>,
+}
 
-		/// The balance type
-		type Balance: Member
-			+ Parameter
-			+ MaxEncodedLen
-			+ AtLeast32BitUnsigned
-			+ Default
-			+ Copy
-			+ MaybeSerializeDeserialize
-			+ Debug
-			+ From<u128>
-			+ sp_std::iter::Sum;
+/// Metadata of an asset registered in the bank.
+#[derive(
+	Encode,
+	Decode,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub struct AssetMetadata<Balance> {
+	pub name: BoundedVec<u8, ConstU32<ASSET_NAME_MAXSIZE>>,
+	pub symbol: BoundedVec<u8, ConstU32<ASSET_SYMBOL_MAXSIZE>>,
+	pub decimals: u8,
+	pub existential_deposit: Balance,
+}
 
-		type RoleManager: ManageRoles<Self::AccountId>;
+/// What to do when a standing order fails to execute, e.g. due to insufficient funds.
+#[derive(
+	Encode,
+	Decode,
+	Copy,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub enum FailurePolicy {
+	/// Skip this execution, and wait for the next period.
+	Skip,
+	/// Retry in the next block, up to `MaxStandingOrderRetries` times.
+	Retry,
+}
 
-		type EnsureGovernance: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+/// A transfer that runs at a future block, and optionally repeats every `period` blocks.
+#[derive(
+	Encode,
+	Decode,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub struct StandingOrder<AccountId, Balance, BlockNumber> {
+	pub from: AccountId,
+	pub to: AccountId,
+	pub amount: Balance,
+	pub next_execution: BlockNumber,
+	pub period: Option<BlockNumber>,
+	pub policy: FailurePolicy,
+	pub retries: u32,
+}
 
-		#[pallet::constant]
-		type ExistentialDeposit: Get<Self::Balance>;
+/// The counterparty and the conditions of funds locked in escrow by the payer.
+#[derive(
+	Encode,
+	Decode,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub struct EscrowInfo<AccountId, BlockNumber> {
+	pub payer: AccountId,
+	pub payee: AccountId,
+	/// The Auditor that can decide to release or refund the escrow.
+	pub arbiter: AccountId,
+	/// The funds are released to the payee at this block, unless disputed.
+	pub deadline: BlockNumber,
+	pub disputed: bool,
+}
 
-		#[pallet::constant]
-		type MinimumAmount: Get<Self::Balance>;
+/// A customer's request to withdraw funds held on the account, waiting for a manager.
+#[derive(
+	Encode,
+	Decode,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub struct WithdrawalRequest<AccountId, Balance, BlockNumber> {
+	pub user: AccountId,
+	pub amount: Balance,
+	/// The held funds are released to the user at this block, unless the request is settled.
+	pub expiry: BlockNumber,
+}
 
-		#[pallet::constant]
-		type RedeemPeriod: Get<BlockNumberFor<Self>>;
+/// How a batch transfer handles failed legs.
+#[derive(
+	Encode,
+	Decode,
+	Copy,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub enum BatchMode {
+	/// The whole batch fails if any leg fails.
+	Atomic,
+	/// Failed legs are skipped, the other legs are transferred.
+	BestEffort,
+}
 
-		#[pallet::constant]
-		type StakePeriod: Get<BlockNumberFor<Self>>;
+/// Funds an owner allows a spender to transfer out of the owner's account.
+#[derive(
+	Encode,
+	Decode,
+	Copy,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub struct Allowance<Balance, BlockNumber> {
+	pub amount: Balance,
+	/// The allowance can no longer be spent from this block, if any.
+	pub expiry: Option<BlockNumber>,
+}
 
-		#[pallet::constant]
-		type InterestPayoutPeriod: Get<BlockNumberFor<Self>>;
+/// An account shared by its holders. Outgoing transfers need the approval of `threshold` holders.
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	MaxEncodedLen,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+#[serde(bound(serialize = "AccountId: Serialize", deserialize = "AccountId: Deserialize<'de>"))]
+#[codec(mel_bound(AccountId: MaxEncodedLen))]
+#[scale_info(skip_type_params(MaxHolders))]
+pub struct JointAccount<AccountId: Clone + Eq + Debug, MaxHolders: Get<u32>> {
+	/// The holders, sorted and without duplicates.
+	pub holders: BoundedVec<AccountId, MaxHolders>,
+	pub threshold: u32,
+}
 
-		#[pallet::constant]
-		type TotalBlocksPerYear: Get<BlockNumberFor<Self>>;
-	}
+/// A transfer out of a joint account, waiting for the approval of its holders.
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	MaxEncodedLen,
+	RuntimeDebugNoBound,
+	TypeInfo,
+)]
+#[codec(mel_bound(
+	AccountId: MaxEncodedLen,
+	Balance: MaxEncodedLen,
+	BlockNumber: MaxEncodedLen
+))]
+#[scale_info(skip_type_params(MaxHolders))]
+pub struct JointTransfer<
+	AccountId: Ord + Clone + Debug,
+	Balance: Clone + Eq + Debug,
+	BlockNumber: Clone + Eq + Debug,
+	MaxHolders: Get<u32>,
+> {
+	pub joint_account: AccountId,
+	pub to: AccountId,
+	pub amount: Balance,
+	pub approvals: BoundedBTreeSet<AccountId, MaxHolders>,
+	pub rejections: BoundedBTreeSet<AccountId, MaxHolders>,
+	/// The block from which the transfer can no longer be voted on, and can be cancelled.
+	pub expiry: BlockNumber,
+}
 
-	#[pallet::error]
-	pub enum Error<T> {
-		/// The account's free balance is not sufficient.
-		InsufficientBalance,
-		/// The amount given is too small.
-		AmountTooSmall,
-		/// Unlock reason is not compatible with the lock.
-		UnauthorisedUnlock,
-		/// Interest rate must be between 0 - 10000(0% - 100%).
-		InvalidInterestRate,
-		/// No lock corresponds to the given lock Id.
-		InvalidLockId,
-		/// The treasury account storage is not set.
-		TreasuryAccountNotSet,
-		/// The account already exists.
-		AccountIdAlreadyTaken,
+impl<
+		AccountId: Ord + Clone + Debug,
+		Balance: Clone + Eq + Debug,
+		BlockNumber: Clone + Eq + Debug,
+		MaxHolders: Get<u32>,
+	> JointTransfer<AccountId, Balance, BlockNumber, MaxHolders>
+{
+	/// Casts a vote of a holder. Returns false if the holder has already voted, or there are
+	/// more voters than holders.
+	pub fn cast_vote(&mut self, who: AccountId, approve: bool) -> bool {
+		if self.approvals.contains(&who) || self.rejections.contains(&who) {
+			false
+		} else if approve {
+			self.approvals.try_insert(who).unwrap_or(false)
+		} else {
+			self.rejections.try_insert(who).unwrap_or(false)
+		}
 	}
 
-	#[pallet::event]
-	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
-	pub enum Event<T: Config> {
-		/// A manager role has minted some funds into an account.
-		Deposited { user: T::AccountId, amount: T::Balance },
-
-		/// A manager role has burned some fund from an account.
-		Withdrew { user: T::AccountId, amount: T::Balance },
-
-		/// Transfered some fund from an account into another account.
-		Transferred { from: T::AccountId, to: T::AccountId, amount: T::Balance },
+	/// Returns Some(true) if the transfer is approved, Some(false) if it can no longer be
+	/// approved, or None if it is not resolved yet.
+	pub fn can_resolve(&self, holders: u32, threshold: u32) -> Option<bool> {
+		if self.approvals.len() as u32 >= threshold {
+			Some(true)
+		} else if self.rejections.len() as u32 > holders.saturating_sub(threshold) {
+			Some(false)
+		} else {
+			None
+		}
+	}
+}
 
-		/// Reaped some fund from an account and removed this account.
-		Reaped { user: T::AccountId, dust: T::Balance },
+/// The outgoing value a customer can send in a day and in a week.
+#[derive(
+	Encode,
+	Decode,
+	Copy,
+	Clone,
+	PartialEq,
+	Eq,
+	Default,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub struct VelocityLimit<Balance> {
+	pub daily: Balance,
+	pub weekly: Balance,
+}
 
-		/// Auditor or client locked some fund from an account's "free" and "reserved" to "locked".
-		Locked {
-			user: T::AccountId,
-			amount: T::Balance,
-			length: BlockNumberFor<T>,
-			reason: LockReason,
-		},
+/// The outgoing value a customer has sent in the current day and week. Days and weeks are
+/// counted from genesis.
+#[derive(
+	Encode,
+	Decode,
+	Copy,
+	Clone,
+	PartialEq,
+	Eq,
+	Default,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub struct OutgoingValue<Balance, BlockNumber> {
+	pub day: BlockNumber,
+	pub daily: Balance,
+	pub week: BlockNumber,
+	pub weekly: Balance,
+}
+
+/// An interest rate announced by a manager, that takes effect at a future block unless
+/// governance vetoes it.
+#[derive(
+	Encode,
+	Decode,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub struct ScheduledInterestRate<AccountId, BlockNumber> {
+	pub manager: AccountId,
+	pub interest_rate: Perbill,
+	pub effective_at: BlockNumber,
+}
+
+/// The bounds governance sets on the interest rate, and on how much a single change moves it.
+#[derive(
+	Encode,
+	Decode,
+	Copy,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub struct InterestRateBounds {
+	pub min: Perbill,
+	pub max: Perbill,
+	pub max_change: Perbill,
+}
+
+/// The maximum number of bands of a tiered fee schedule or savings product.
+pub type MaxTiers = ConstU32<16>;
+
+/// The maximum number of items returned in a page of an account statement or of the interest
+/// rate history.
+pub const MAX_STATEMENT_PAGE_SIZE: u32 = 100;
+
+/// The fee charged on customer transfers, in the native currency.
+#[derive(
+	Encode,
+	Decode,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub enum FeeSchedule<Balance> {
+	/// The same fee for every transfer.
+	Flat(Balance),
+	/// A proportion of the transferred amount.
+	Percentage(Perbill),
+	/// The fee of the highest band whose threshold the amount reaches, bands are sorted by
+	/// threshold.
+	Tiered(BoundedVec<(Balance, Balance), MaxTiers>),
+}
+
+/// The interest terms of a savings product.
+#[derive(
+	Encode,
+	Decode,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub enum ProductKind<Balance, BlockNumber> {
+	/// Funds can be withdrawn at any time. The deposit earns the rate of the highest band whose
+	/// threshold it reaches, bands are sorted by threshold.
+	Tiered(BoundedVec<(Balance, Perbill), MaxTiers>),
+	/// Funds are locked for `term` blocks and earn `rate`. Withdrawing before the term ends
+	/// forfeits the interest, and `penalty` of the deposit goes to the treasury.
+	FixedTerm { term: BlockNumber, rate: Perbill, penalty: Perbill },
+}
+
+/// A savings product in the catalogue.
+#[derive(
+	Encode,
+	Decode,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub struct InterestProduct<Balance, BlockNumber> {
+	pub kind: ProductKind<Balance, BlockNumber>,
+	/// Retired products no longer accept deposits, existing deposits keep their terms.
+	pub active: bool,
+}
+
+/// Funds a customer has locked into a savings product.
+#[derive(
+	Encode,
+	Decode,
+	Copy,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub struct ProductDeposit<Balance, BlockNumber> {
+	pub product_id: ProductId,
+	pub amount: Balance,
+	pub start: BlockNumber,
+	/// The end of the term of fixed-term products.
+	pub maturity: Option<BlockNumber>,
+}
+
+/// The kind of change recorded in the ledger of an account.
+#[derive(
+	Encode,
+	Decode,
+	Copy,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub enum LedgerEntryKind {
+	Deposit,
+	Withdrawal,
+	TransferIn,
+	TransferOut,
+	Interest,
+	Lock,
+	Unlock,
+	Reaped,
+	Fee,
+}
+
+/// A memo or a structured reference a customer or a manager attaches to a payment.
+#[derive(
+	Encode,
+	Decode,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub enum Memo {
+	/// Free text.
+	Text(BoundedVec<u8, ConstU32<MEMO_MAXSIZE>>),
+	/// The number of the invoice the payment settles.
+	Invoice(BoundedVec<u8, ConstU32<MEMO_MAXSIZE>>),
+	/// The reference of the payment on an external fiat rail.
+	External(BoundedVec<u8, ConstU32<MEMO_MAXSIZE>>),
+}
+
+/// What a payment is for, recorded in its events and ledger entries.
+#[derive(
+	Encode,
+	Decode,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub enum PaymentReference {
+	/// Attached by the customer or the manager that made the payment.
+	Memo(Memo),
+	/// Attached by the pallet that moved the funds.
+	Reason(TransferReason),
+}
+
+/// An entry in the ledger of an account, with the running balances after the entry.
+#[derive(
+	Encode,
+	Decode,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub struct LedgerEntry<AccountId, Balance, BlockNumber> {
+	pub block: BlockNumber,
+	pub kind: LedgerEntryKind,
+	pub amount: Balance,
+	/// The other side of a transfer.
+	pub counterparty: Option<AccountId>,
+	/// What the payment is for, if given.
+	pub reference: Option<PaymentReference>,
+	pub free: Balance,
+	pub reserved: Balance,
+}
+
+/// A loan borrowed against collateral locked from the borrower's "reserved".
+#[derive(
+	Encode,
+	Decode,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub struct Loan<AccountId, Balance, BlockNumber> {
+	pub borrower: AccountId,
+	pub collateral: Balance,
+	/// The outstanding principal.
+	pub principal: Balance,
+	/// The borrowing interest accrued and not repaid yet.
+	pub interest: Balance,
+	/// The yearly borrowing rate, fixed when the loan is opened.
+	pub rate: Perbill,
+	/// The block the interest is accrued up to.
+	pub updated_at: BlockNumber,
+	/// The block the loan reaches the liquidation threshold, if the debt grows.
+	pub liquidation_at: Option<BlockNumber>,
+}
+
+pub use module::*;
+
+#[frame_support::pallet]
+pub mod module {
+
+	use super::*;
+
+	pub type ScheduledInterestRateOf<T> =
+		ScheduledInterestRate<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+	pub type FreezeInfoOf<T> =
+		FreezeInfo<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+	pub type EscrowInfoOf<T> =
+		EscrowInfo<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+	pub type WithdrawalRequestOf<T> = WithdrawalRequest<
+		<T as frame_system::Config>::AccountId,
+		<T as Config>::Balance,
+		BlockNumberFor<T>,
+	>;
+
+	pub type InterestProductOf<T> = InterestProduct<<T as Config>::Balance, BlockNumberFor<T>>;
+
+	pub type ProductDepositOf<T> = ProductDeposit<<T as Config>::Balance, BlockNumberFor<T>>;
+
+	pub type AllowanceOf<T> = Allowance<<T as Config>::Balance, BlockNumberFor<T>>;
+
+	pub type AccountDataOf<T> = AccountData<<T as Config>::Balance, <T as Config>::MaxLocks>;
+
+	pub type InterestPayoutOf<T> = InterestPayout<
+		<T as frame_system::Config>::AccountId,
+		<T as Config>::Balance,
+		BlockNumberFor<T>,
+	>;
+
+	pub type JointAccountOf<T> =
+		JointAccount<<T as frame_system::Config>::AccountId, <T as Config>::MaxJointHolders>;
+
+	pub type JointTransferOf<T> = JointTransfer<
+		<T as frame_system::Config>::AccountId,
+		<T as Config>::Balance,
+		BlockNumberFor<T>,
+		<T as Config>::MaxJointHolders,
+	>;
+
+	pub type OutgoingValueOf<T> = OutgoingValue<<T as Config>::Balance, BlockNumberFor<T>>;
+
+	pub type LedgerEntryOf<T> = LedgerEntry<
+		<T as frame_system::Config>::AccountId,
+		<T as Config>::Balance,
+		BlockNumberFor<T>,
+	>;
+
+	pub type LoanOf<T> =
+		Loan<<T as frame_system::Config>::AccountId, <T as Config>::Balance, BlockNumberFor<T>>;
+
+	pub type StandingOrderOf<T> = StandingOrder<
+		<T as frame_system::Config>::AccountId,
+		<T as Config>::Balance,
+		BlockNumberFor<T>,
+	>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Type representing the weight of this pallet
+		type WeightInfo: WeightInfo;
+
+		/// The balance type
+		type Balance: Member
+			+ Parameter
+			+ MaxEncodedLen
+			+ AtLeast32BitUnsigned
+			+ Default
+			+ Copy
+			+ MaybeSerializeDeserialize
+			+ Debug
+			+ From<u128>
+			+ sp_std::iter::Sum;
+
+		type RoleManager: ManageRoles<Self::AccountId>;
+
+		type EnsureGovernance: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+		/// Records the income paid into the treasury account.
+		type TreasuryIncome: RecordTreasuryIncome<Self::Balance>;
+
+		/// The reason funds are held on an account through the fungible hold traits.
+		type RuntimeHoldReason: Parameter + Member + MaxEncodedLen + Ord + Copy;
+
+		#[pallet::constant]
+		type ExistentialDeposit: Get<Self::Balance>;
+
+		#[pallet::constant]
+		type MinimumAmount: Get<Self::Balance>;
+
+		#[pallet::constant]
+		type RedeemPeriod: Get<BlockNumberFor<Self>>;
+
+		#[pallet::constant]
+		type StakePeriod: Get<BlockNumberFor<Self>>;
+
+		#[pallet::constant]
+		type InterestPayoutPeriod: Get<BlockNumberFor<Self>>;
+
+		#[pallet::constant]
+		type TotalBlocksPerYear: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of accounts paid interest, and the maximum number of overdrafts
+		/// charged interest, in a block.
+		#[pallet::constant]
+		type MaxInterestPayoutsPerBlock: Get<u32>;
+
+		/// The minimum notice before a scheduled interest rate takes effect, during which
+		/// governance can veto it.
+		#[pallet::constant]
+		type InterestRateNoticePeriod: Get<BlockNumberFor<Self>>;
+
+		/// The number of blocks a withdrawal request waits for a manager, before it expires and
+		/// the held funds are released.
+		#[pallet::constant]
+		type WithdrawalRequestPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The number of blocks a joint transfer can be voted on, before it expires and can be
+		/// cancelled by a holder.
+		#[pallet::constant]
+		type JointTransferPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The length of the daily window of the velocity limits.
+		#[pallet::constant]
+		type BlocksPerDay: Get<BlockNumberFor<Self>>;
+
+		/// The length of the weekly window of the velocity limits.
+		#[pallet::constant]
+		type BlocksPerWeek: Get<BlockNumberFor<Self>>;
+
+		/// The maximum value of a loan, as a proportion of its collateral, when it is opened.
+		#[pallet::constant]
+		type MaxLoanToValue: Get<Perbill>;
+
+		/// Loans whose debt reaches this proportion of their collateral can be liquidated.
+		#[pallet::constant]
+		type LiquidationLoanToValue: Get<Perbill>;
+
+		/// The maximum number of dust candidates visited for reaping in a block.
+		#[pallet::constant]
+		type MaxReapsPerBlock: Get<u32>;
+
+		/// The maximum number of times a failed standing order is retried.
+		#[pallet::constant]
+		type MaxStandingOrderRetries: Get<u32>;
+
+		/// The number of ledger entries kept for each account. Older entries are pruned.
+		#[pallet::constant]
+		type MaxLedgerEntries: Get<u32>;
+
+		/// The maximum number of holders of a joint account.
+		#[pallet::constant]
+		type MaxJointHolders: Get<u32>;
+
+		/// The maximum number of legs in a batch transfer.
+		#[pallet::constant]
+		type MaxBatchTransfers: Get<u32>;
+
+		/// The maximum number of locks on an account.
+		#[pallet::constant]
+		type MaxLocks: Get<u32>;
+
+		/// The maximum number of unlocks, standing orders, freeze expiries or loan liquidations
+		/// scheduled at the same block, for each kind.
+		#[pallet::constant]
+		type MaxScheduledPerBlock: Get<u32>;
+
+		/// The maximum number of calls whose transaction fees are paid by the treasury.
+		#[pallet::constant]
+		type MaxSponsoredCalls: Get<u32>;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account's free balance is not sufficient.
+		InsufficientBalance,
+		/// The amount given is too small.
+		AmountTooSmall,
+		/// Unlock reason is not compatible with the lock.
+		UnauthorisedUnlock,
+		/// Interest rate must be between 0 - 10000(0% - 100%).
+		InvalidInterestRate,
+		/// The interest rate, or the change from the current rate, is out of the bounds.
+		InterestRateOutOfBounds,
+		/// The interest rate does not take effect after the notice period.
+		InterestRateNoticeTooShort,
+		/// There is no scheduled interest rate.
+		NoScheduledInterestRate,
+		/// No lock corresponds to the given lock Id.
+		InvalidLockId,
+		/// The treasury account storage is not set.
+		TreasuryAccountNotSet,
+		/// The account already exists.
+		AccountIdAlreadyTaken,
+		/// The asset is not registered.
+		AssetNotFound,
+		/// The asset name or symbol is too long.
+		AssetMetadataTooLong,
+		/// No standing order corresponds to the given order Id.
+		InvalidOrderId,
+		/// Only the payer of a standing order can amend or cancel it.
+		NotOrderOwner,
+		/// The standing order must execute in a future block, and repeat after a non-zero period.
+		/// Escrows, freezes, allowances and lock extensions must last a non-zero number of blocks.
+		InvalidSchedule,
+		/// No escrow corresponds to the given lock Id.
+		InvalidEscrowId,
+		/// The caller is not allowed to settle or dispute this escrow.
+		NotEscrowParty,
+		/// The escrow is already disputed.
+		EscrowAlreadyDisputed,
+		/// No product corresponds to the given product Id.
+		InvalidProductId,
+		/// Tiers must be non-empty and sorted by threshold, and terms must be non-zero.
+		InvalidProduct,
+		/// The product is retired and no longer accepts deposits.
+		ProductRetired,
+		/// Tiers must be non-empty and sorted by threshold.
+		InvalidFeeSchedule,
+		/// The outgoing value exceeds the account's daily or weekly limit.
+		LimitExceeded,
+		/// Joint accounts need 2 to `MaxJointHolders` distinct holders, and a threshold between 1
+		/// and the number of holders.
+		InvalidJointAccount,
+		/// The account is not a joint account.
+		JointAccountNotFound,
+		/// The caller is not a holder of the joint account.
+		NotJointHolder,
+		/// No joint transfer corresponds to the given proposal Id.
+		InvalidJointTransferId,
+		/// The holder has already voted on the joint transfer.
+		AlreadyVoted,
+		/// The owner has not granted the spender an allowance.
+		AllowanceNotFound,
+		/// The allowance has expired.
+		AllowanceExpired,
+		/// The transfer exceeds the allowance.
+		AllowanceExceeded,
+		/// A partial unlock must leave some funds in the lock.
+		InvalidUnlockAmount,
+		/// The account is frozen, and cannot move funds out.
+		AccountFrozen,
+		/// The account is not frozen.
+		AccountNotFrozen,
+		/// The transfer exceeds the account's overdraft limit.
+		OverdraftLimitExceeded,
+		/// No loan corresponds to the given lock Id.
+		InvalidLoanId,
+		/// Only the borrower can repay the loan.
+		NotLoanOwner,
+		/// The loan is too large for the collateral.
+		LoanToValueTooHigh,
+		/// The loan has not reached the liquidation threshold.
+		LoanNotUndercollateralised,
+		/// The account has `MaxLocks` locks.
+		TooManyLocks,
+		/// `MaxScheduledPerBlock` items of this kind are already scheduled at the block.
+		TooManyScheduled,
+		/// `MaxSponsoredCalls` calls are already sponsored.
+		TooManySponsoredCalls,
+		/// No withdrawal request corresponds to the given lock Id.
+		InvalidWithdrawalId,
+		/// The lock is not scheduled to expire.
+		LockNotScheduled,
+		/// The joint transfer has expired, it can only be cancelled.
+		JointTransferExpired,
+		/// The joint transfer has not expired, it can't be cancelled yet.
+		JointTransferNotExpired,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A manager role has minted some funds into an account.
+		Deposited { user: T::AccountId, amount: T::Balance },
+
+		/// A manager role has burned some fund from an account.
+		Withdrew { user: T::AccountId, amount: T::Balance },
+
+		/// Transfered some fund from an account into another account.
+		Transferred { from: T::AccountId, to: T::AccountId, amount: T::Balance },
+
+		/// Reaped some fund from an account and removed this account.
+		Reaped { user: T::AccountId, dust: T::Balance },
+
+		/// Auditor or client locked some fund from an account's "free" and "reserved" to "locked".
+		Locked {
+			user: T::AccountId,
+			amount: T::Balance,
+			length: BlockNumberFor<T>,
+			reason: LockReason,
+		},
+
+		/// Auditor or client unlocked some fund from an account's "locked" to "free".
+		Unlocked { user: T::AccountId, amount: T::Balance, reason: UnlockReason },
+
+		/// A scheduled interest rate took effect.
+		InterestRateSet {
+			manager: T::AccountId,
+			old_interest_rate: Perbill,
+			new_interest_rate: Perbill,
+		},
+
+		/// Manager scheduled an interest rate to take effect at a future block.
+		InterestRateScheduled {
+			manager: T::AccountId,
+			interest_rate: Perbill,
+			effective_at: BlockNumberFor<T>,
+		},
+
+		/// Governance vetoed the scheduled interest rate.
+		InterestRateVetoed { interest_rate: Perbill, effective_at: BlockNumberFor<T> },
+
+		/// Governance set the bounds on the interest rate.
+		InterestRateBoundsSet { bounds: Option<InterestRateBounds> },
+
+		/// Governance set whether the treasury pays the transaction fees of a call.
+		CallSponsorshipSet { pallet_index: u8, call_index: u8, sponsored: bool },
+
+		/// Manager paid total interest.
+		InterestPayed { interest_rate: Perbill, total_interest_payed: T::Balance },
+
+		/// The interest accrued since the last snapshot is added to an account's "reserved".
+		InterestAccrued { user: T::AccountId, amount: T::Balance },
+
+		/// TreasuryAccount rotated.
+		TreasuryAccountRotated { old: Option<T::AccountId>, new: T::AccountId },
+
+		/// A batch transfer is completed, with the result of each leg.
+		BatchTransferred { from: T::AccountId, results: Vec<DispatchResult> },
+
+		/// An owner granted a spender an allowance, or increased it.
+		AllowanceApproved {
+			owner: T::AccountId,
+			spender: T::AccountId,
+			amount: T::Balance,
+			expiry: Option<BlockNumberFor<T>>,
+		},
+
+		/// An owner revoked the allowance of a spender.
+		AllowanceRevoked { owner: T::AccountId, spender: T::AccountId },
+
+		/// A spender transferred funds on behalf of an owner.
+		TransferredFrom {
+			spender: T::AccountId,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: T::Balance,
+		},
+
+		/// A joint account is created and registered as a customer.
+		JointAccountCreated {
+			joint_account: T::AccountId,
+			holders: Vec<T::AccountId>,
+			threshold: u32,
+		},
+
+		/// A holder proposed a transfer out of a joint account.
+		JointTransferProposed {
+			proposal_id: ProposalId,
+			joint_account: T::AccountId,
+			proposer: T::AccountId,
+			to: T::AccountId,
+			amount: T::Balance,
+			expiry: BlockNumberFor<T>,
+		},
+
+		/// A holder voted on a joint transfer.
+		JointTransferVoted { proposal_id: ProposalId, who: T::AccountId, approve: bool },
+
+		/// A joint transfer is approved by enough holders and executed.
+		JointTransferExecuted { proposal_id: ProposalId },
+
+		/// A joint transfer is rejected by too many holders to be approved.
+		JointTransferRejected { proposal_id: ProposalId },
+
+		/// A joint transfer is approved by enough holders, but failed. It is dropped.
+		JointTransferFailed { proposal_id: ProposalId, error: DispatchError },
+
+		/// A holder cancelled an expired joint transfer.
+		JointTransferCancelled { proposal_id: ProposalId, who: T::AccountId },
+
+		/// Manager set the default velocity limit of the customers.
+		DefaultVelocityLimitSet { limit: Option<VelocityLimit<T::Balance>> },
+
+		/// Manager set the velocity limit of a customer, overriding the default.
+		VelocityLimitSet { user: T::AccountId, limit: Option<VelocityLimit<T::Balance>> },
+
+		/// The transfer fee schedule is set by governance.
+		TransferFeeScheduleSet { schedule: Option<FeeSchedule<T::Balance>> },
+
+		/// A transfer fee is paid to the treasury.
+		TransferFeeCharged { user: T::AccountId, fee: T::Balance },
+
+		/// A new asset is registered.
+		AssetRegistered { asset_id: AssetId, metadata: AssetMetadata<T::Balance> },
+
+		/// A manager role has minted some funds of an asset into an account.
+		AssetDeposited { asset_id: AssetId, user: T::AccountId, amount: T::Balance },
+
+		/// A manager role has burned some funds of an asset from an account.
+		AssetWithdrew { asset_id: AssetId, user: T::AccountId, amount: T::Balance },
+
+		/// Transfered some funds of an asset from an account into another account.
+		AssetTransferred {
+			asset_id: AssetId,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: T::Balance,
+		},
+
+		/// Reaped some funds of an asset from an account and removed this asset account.
+		AssetReaped { asset_id: AssetId, user: T::AccountId, dust: T::Balance },
+
+		/// Locked some funds of an asset from an account's "free" or "reserved" to "locked".
+		AssetLocked {
+			asset_id: AssetId,
+			user: T::AccountId,
+			amount: T::Balance,
+			length: BlockNumberFor<T>,
+			reason: LockReason,
+		},
+
+		/// Unlocked some funds of an asset from an account's "locked".
+		AssetUnlocked {
+			asset_id: AssetId,
+			user: T::AccountId,
+			amount: T::Balance,
+			reason: UnlockReason,
+		},
+
+		/// A standing order is created.
+		StandingOrderCreated {
+			order_id: OrderId,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: T::Balance,
+			next_execution: BlockNumberFor<T>,
+			period: Option<BlockNumberFor<T>>,
+		},
+
+		/// A standing order is amended by its payer.
+		StandingOrderAmended {
+			order_id: OrderId,
+			amount: T::Balance,
+			next_execution: BlockNumberFor<T>,
+			period: Option<BlockNumberFor<T>>,
+		},
+
+		/// A standing order is cancelled by its payer.
+		StandingOrderCancelled { order_id: OrderId },
+
+		/// A standing order is executed and the funds are transferred.
+		StandingOrderExecuted {
+			order_id: OrderId,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: T::Balance,
+		},
+
+		/// A standing order failed to execute. It is executed again at `next_execution`, if any.
+		StandingOrderFailed {
+			order_id: OrderId,
+			error: DispatchError,
+			next_execution: Option<BlockNumberFor<T>>,
+		},
+
+		/// A standing order has no more executions and is removed.
+		StandingOrderCompleted { order_id: OrderId },
+
+		/// A payer locked some fund in escrow for a payee.
+		EscrowCreated {
+			lock_id: LockId,
+			payer: T::AccountId,
+			payee: T::AccountId,
+			arbiter: T::AccountId,
+			amount: T::Balance,
+			deadline: BlockNumberFor<T>,
+		},
+
+		/// The escrow is released, and the funds are moved to the payee's "free".
+		EscrowReleased { lock_id: LockId, payee: T::AccountId, amount: T::Balance },
+
+		/// The escrow is refunded, and the funds are moved back to the payer's "free".
+		EscrowRefunded { lock_id: LockId, payer: T::AccountId, amount: T::Balance },
+
+		/// The escrow is disputed, it can only be settled by the arbiter.
+		EscrowDisputed { lock_id: LockId, who: T::AccountId },
+
+		/// A manager added a savings product to the catalogue.
+		ProductCreated { product_id: ProductId, kind: ProductKind<T::Balance, BlockNumberFor<T>> },
+
+		/// A manager retired a savings product.
+		ProductRetired { product_id: ProductId },
+
+		/// A customer locked some fund into a savings product.
+		ProductStaked {
+			user: T::AccountId,
+			product_id: ProductId,
+			lock_id: LockId,
+			amount: T::Balance,
+		},
+
+		/// An auditor amended the amount or the expiry of an auditor lock.
+		AuditorLockAmended {
+			user: T::AccountId,
+			lock_id: LockId,
+			old_amount: T::Balance,
+			new_amount: T::Balance,
+			old_expiry: BlockNumberFor<T>,
+			new_expiry: BlockNumberFor<T>,
+		},
+
+		/// An auditor froze an account.
+		AccountFrozen {
+			user: T::AccountId,
+			auditor: T::AccountId,
+			reason: FreezeReason,
+			expiry: Option<BlockNumberFor<T>>,
+		},
+
+		/// An account is unfrozen by an auditor, or at the expiry of its freeze.
+		AccountUnfrozen { user: T::AccountId },
+
+		/// Manager set the overdraft limit of an account.
+		OverdraftLimitSet { user: T::AccountId, limit: T::Balance },
+
+		/// Manager set the overdraft interest rate.
+		OverdraftRateSet { manager: T::AccountId, old_rate: Perbill, new_rate: Perbill },
+
+		/// An account drew some fund on its overdraft facility.
+		OverdraftDrawn { user: T::AccountId, amount: T::Balance },
+
+		/// Some of an account's overdraft debt is repaid from incoming funds.
+		OverdraftRepaid { user: T::AccountId, amount: T::Balance },
+
+		/// The overdraft interest is added to an account's debt, and paid to the treasury.
+		OverdraftInterestCharged { user: T::AccountId, amount: T::Balance },
+
+		/// Manager set the borrowing interest rate.
+		BorrowRateSet { manager: T::AccountId, old_rate: Perbill, new_rate: Perbill },
+
+		/// A customer locked some fund from "reserved" as collateral, and borrowed the principal
+		/// into "free".
+		LoanOpened {
+			lock_id: LockId,
+			borrower: T::AccountId,
+			collateral: T::Balance,
+			principal: T::Balance,
+		},
+
+		/// The borrower repaid some of the loan. The interest is paid to the treasury and the
+		/// principal is burned.
+		LoanRepaid { lock_id: LockId, interest: T::Balance, principal: T::Balance },
+
+		/// The loan is fully repaid, and the collateral is returned to the borrower's "reserved".
+		LoanClosed { lock_id: LockId },
+
+		/// The loan reached the liquidation threshold, and the collateral is moved to the
+		/// treasury.
+		LoanLiquidated {
+			lock_id: LockId,
+			borrower: T::AccountId,
+			collateral: T::Balance,
+			debt: T::Balance,
+		},
+
+		/// A product deposit is withdrawn to the customer's "free", with the interest earned and
+		/// less the early-withdrawal penalty.
+		ProductRedeemed {
+			user: T::AccountId,
+			product_id: ProductId,
+			lock_id: LockId,
+			amount: T::Balance,
+			interest: T::Balance,
+			penalty: T::Balance,
+		},
+
+		/// A customer requested to withdraw some fund, which is held until a manager settles the
+		/// request or it expires.
+		WithdrawalRequested {
+			lock_id: LockId,
+			user: T::AccountId,
+			amount: T::Balance,
+			expiry: BlockNumberFor<T>,
+		},
+
+		/// A manager approved a withdrawal request, and the funds are burned. The payout
+		/// reference identifies the payout on the off-chain fiat rail.
+		WithdrawalApproved {
+			lock_id: LockId,
+			user: T::AccountId,
+			manager: T::AccountId,
+			amount: T::Balance,
+			payout_reference: T::Hash,
+		},
+
+		/// A manager rejected a withdrawal request, and the funds are released to the user's
+		/// "free".
+		WithdrawalRejected { lock_id: LockId, user: T::AccountId, amount: T::Balance },
+
+		/// A withdrawal request expired, and the funds are released to the user's "free".
+		WithdrawalExpired { lock_id: LockId, user: T::AccountId, amount: T::Balance },
+
+		/// A manager role has minted some funds into an account, with a reference.
+		DepositedWithReference {
+			user: T::AccountId,
+			amount: T::Balance,
+			reference: PaymentReference,
+		},
+
+		/// Transfered some fund from an account into another account, with a memo or the reason of
+		/// the transfer.
+		TransferredWithReference {
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: T::Balance,
+			reference: PaymentReference,
+		},
+	}
+
+	/// The balance of a token type under an account.
+	#[pallet::storage]
+	#[pallet::getter(fn accounts)]
+	pub type Accounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, AccountDataOf<T>, ValueQuery>;
+
+	/// Storage item to track the total issuance of the token.
+	#[pallet::storage]
+	#[pallet::getter(fn total_issuance)]
+	pub type TotalIssuance<T: Config> = StorageValue<_, T::Balance, ValueQuery>;
+
+	/// Stores the user ID that will have their fund unlocked at a block.
+	#[pallet::storage]
+	pub type AccountWithUnlockedFund<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(T::AccountId, LockId), T::MaxScheduledPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Stores the block a scheduled lock of a user expires at.
+	#[pallet::storage]
+	#[pallet::getter(fn lock_expiries)]
+	pub type LockExpiries<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		LockId,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
+	/// Stores the next locked ID should be.
+	#[pallet::storage]
+	pub type NextLockId<T: Config> = StorageValue<_, LockId, ValueQuery>;
+
+	/// Stores the interest rate.
+	#[pallet::storage]
+	#[pallet::getter(fn interest_rate)]
+	pub type InterestRate<T: Config> = StorageValue<_, Perbill, ValueQuery>;
+
+	/// Stores the interest rate scheduled by a manager, that has not taken effect yet.
+	#[pallet::storage]
+	#[pallet::getter(fn next_interest_rate)]
+	pub type NextInterestRate<T: Config> = StorageValue<_, ScheduledInterestRateOf<T>>;
+
+	/// Stores the bounds on the interest rate. Rates are not bounded if it is not set.
+	#[pallet::storage]
+	#[pallet::getter(fn interest_rate_bounds)]
+	pub type RateBounds<T: Config> = StorageValue<_, InterestRateBounds>;
+
+	/// Stores every interest rate that took effect, by the block it took effect at. The rate is
+	/// zero before the first change.
+	#[pallet::storage]
+	#[pallet::getter(fn interest_rate_changes)]
+	pub type InterestRateChanges<T: Config> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, Perbill>;
+
+	#[pallet::type_value]
+	pub fn DefaultInterestIndex() -> FixedU128 {
+		FixedU128::one()
+	}
+
+	/// Stores the cumulative interest index, it grows from one at the interest rate and is
+	/// compounded every payout period.
+	#[pallet::storage]
+	#[pallet::getter(fn interest_index)]
+	pub type InterestIndex<T: Config> =
+		StorageValue<_, FixedU128, ValueQuery, DefaultInterestIndex>;
+
+	/// Stores the block the interest index is last updated at.
+	#[pallet::storage]
+	pub type InterestIndexUpdatedAt<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+	/// Stores the interest index when the interest of an account was last realised.
+	#[pallet::storage]
+	#[pallet::getter(fn interest_snapshot)]
+	pub type InterestSnapshot<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, FixedU128, ValueQuery, DefaultInterestIndex>;
+
+	/// Stores the interest payouts that are not fully settled yet, the first one is in progress.
+	#[pallet::storage]
+	#[pallet::getter(fn interest_payout_queue)]
+	pub type InterestPayoutQueue<T: Config> =
+		StorageValue<_, BoundedVec<InterestPayoutOf<T>, MaxInterestPayouts>, ValueQuery>;
+
+	/// Stores the accounts that may have dropped below the existential deposit of an asset, they
+	/// are reaped in the next blocks.
+	#[pallet::storage]
+	pub type DustCandidates<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, AssetId, ()>;
+
+	/// Stores the treasury account.
+	#[pallet::storage]
+	#[pallet::getter(fn treasury_account)]
+	pub type TreasuryAccount<T: Config> = StorageValue<_, T::AccountId>;
+
+	/// Stores the allowances granted by an owner to a spender.
+	#[pallet::storage]
+	#[pallet::getter(fn allowances)]
+	pub type Allowances<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		AllowanceOf<T>,
+	>;
+
+	/// Stores the holders and the threshold of the joint accounts.
+	#[pallet::storage]
+	#[pallet::getter(fn joint_accounts)]
+	pub type JointAccounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, JointAccountOf<T>>;
+
+	/// Stores the transfers out of joint accounts waiting for approval.
+	#[pallet::storage]
+	#[pallet::getter(fn joint_transfers)]
+	pub type JointTransfers<T: Config> =
+		StorageMap<_, Blake2_128Concat, ProposalId, JointTransferOf<T>>;
+
+	/// Stores the next joint transfer ID should be.
+	#[pallet::storage]
+	pub type NextJointTransferId<T: Config> = StorageValue<_, ProposalId, ValueQuery>;
+
+	/// Stores the velocity limit of the customers without their own limit. Customers are not
+	/// limited if it is not set.
+	#[pallet::storage]
+	#[pallet::getter(fn default_velocity_limit)]
+	pub type DefaultVelocityLimit<T: Config> = StorageValue<_, VelocityLimit<T::Balance>>;
+
+	/// Stores the velocity limits that override the default for a customer.
+	#[pallet::storage]
+	#[pallet::getter(fn velocity_limits)]
+	pub type VelocityLimits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, VelocityLimit<T::Balance>>;
+
+	/// Stores the outgoing value the customers sent in the current day and week.
+	#[pallet::storage]
+	pub type OutgoingValues<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, OutgoingValueOf<T>, ValueQuery>;
+
+	/// Stores the fee schedule of customer transfers. Transfers are free if it is not set.
+	#[pallet::storage]
+	#[pallet::getter(fn transfer_fee_schedule)]
+	pub type TransferFeeSchedule<T: Config> = StorageValue<_, FeeSchedule<T::Balance>>;
+
+	/// Stores the metadata of the registered assets.
+	#[pallet::storage]
+	#[pallet::getter(fn assets)]
+	pub type Assets<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetId, AssetMetadata<T::Balance>>;
+
+	/// Stores the next asset ID should be.
+	#[pallet::storage]
+	pub type NextAssetId<T: Config> = StorageValue<_, AssetId, ValueQuery>;
+
+	/// The balance of the registered assets under an account. The native currency is stored in
+	/// `Accounts`.
+	#[pallet::storage]
+	#[pallet::getter(fn asset_accounts)]
+	pub type AssetAccounts<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		AssetId,
+		AccountDataOf<T>,
+		ValueQuery,
+	>;
+
+	/// Storage item to track the total issuance of the registered assets.
+	#[pallet::storage]
+	#[pallet::getter(fn asset_issuance)]
+	pub type AssetIssuance<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetId, T::Balance, ValueQuery>;
+
+	/// Stores the asset of the locks that do not lock the native currency.
+	#[pallet::storage]
+	pub type AssetLocks<T: Config> = StorageMap<_, Blake2_128Concat, LockId, AssetId>;
+
+	/// Stores the active standing orders.
+	#[pallet::storage]
+	#[pallet::getter(fn standing_orders)]
+	pub type StandingOrders<T: Config> =
+		StorageMap<_, Blake2_128Concat, OrderId, StandingOrderOf<T>>;
+
+	/// Stores the next standing order ID should be.
+	#[pallet::storage]
+	pub type NextOrderId<T: Config> = StorageValue<_, OrderId, ValueQuery>;
+
+	/// Stores the standing orders that are due at a block.
+	#[pallet::storage]
+	pub type StandingOrderSchedule<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>,
+		BoundedVec<OrderId, T::MaxScheduledPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Stores the counterparty and conditions of the funds locked in escrow.
+	#[pallet::storage]
+	#[pallet::getter(fn escrows)]
+	pub type Escrows<T: Config> = StorageMap<_, Blake2_128Concat, LockId, EscrowInfoOf<T>>;
+
+	/// Stores the catalogue of savings products.
+	#[pallet::storage]
+	#[pallet::getter(fn products)]
+	pub type Products<T: Config> = StorageMap<_, Blake2_128Concat, ProductId, InterestProductOf<T>>;
+
+	/// Stores the next product ID should be.
+	#[pallet::storage]
+	pub type NextProductId<T: Config> = StorageValue<_, ProductId, ValueQuery>;
+
+	/// Stores the funds locked into savings products.
+	#[pallet::storage]
+	#[pallet::getter(fn product_deposits)]
+	pub type ProductDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, LockId, ProductDepositOf<T>>;
+
+	/// Stores the accounts frozen by auditors.
+	#[pallet::storage]
+	#[pallet::getter(fn frozen_accounts)]
+	pub type FrozenAccounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, FreezeInfoOf<T>>;
+
+	/// Stores the accounts whose freeze expires at a block.
+	#[pallet::storage]
+	pub type FreezeExpirySchedule<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>,
+		BoundedVec<T::AccountId, T::MaxScheduledPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Stores the overdraft limit granted to the accounts.
+	#[pallet::storage]
+	#[pallet::getter(fn overdraft_limits)]
+	pub type OverdraftLimits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::Balance, ValueQuery>;
+
+	/// The yearly interest rate charged on overdraft debts.
+	#[pallet::storage]
+	#[pallet::getter(fn overdraft_rate)]
+	pub type OverdraftRate<T: Config> = StorageValue<_, Perbill, ValueQuery>;
+
+	/// Stores the overdraft interest charges that are not completed yet, the first one is in
+	/// progress.
+	#[pallet::storage]
+	#[pallet::getter(fn overdraft_charge_queue)]
+	pub type OverdraftChargeQueue<T: Config> =
+		StorageValue<_, BoundedVec<OverdraftCharge<T::AccountId>, MaxInterestPayouts>, ValueQuery>;
+
+	/// The yearly interest rate of new loans.
+	#[pallet::storage]
+	#[pallet::getter(fn borrow_rate)]
+	pub type BorrowRate<T: Config> = StorageValue<_, Perbill, ValueQuery>;
+
+	/// Stores the open loans, by the lock Id of their collateral.
+	#[pallet::storage]
+	#[pallet::getter(fn loans)]
+	pub type Loans<T: Config> = StorageMap<_, Blake2_128Concat, LockId, LoanOf<T>>;
+
+	/// Stores the ledger entries of the accounts, by their index in the ledger.
+	#[pallet::storage]
+	pub type LedgerEntries<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, u32, LedgerEntryOf<T>>;
+
+	/// Stores the index of the oldest kept entry, and the index of the next entry of a ledger.
+	#[pallet::storage]
+	pub type LedgerIndices<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (u32, u32), ValueQuery>;
+
+	/// Stores the loans that reach the liquidation threshold at a block.
+	#[pallet::storage]
+	pub type LoanLiquidationSchedule<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>,
+		BoundedVec<LockId, T::MaxScheduledPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Stores the funds held on an account for each reason through the fungible hold traits. The
+	/// total held is kept in the account's "locked" as a lock with `LockReason::Hold`.
+	#[pallet::storage]
+	pub type Holds<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::RuntimeHoldReason,
+		T::Balance,
+		ValueQuery,
+	>;
+
+	/// Stores the calls, by their pallet index and call index, whose transaction fees are paid
+	/// by the treasury.
+	#[pallet::storage]
+	#[pallet::getter(fn sponsored_calls)]
+	pub type SponsoredCalls<T: Config> =
+		StorageValue<_, BoundedBTreeSet<(u8, u8), T::MaxSponsoredCalls>, ValueQuery>;
+
+	/// Stores the pending withdrawal requests, by the lock Id of their held funds.
+	#[pallet::storage]
+	#[pallet::getter(fn withdrawal_requests)]
+	pub type WithdrawalRequests<T: Config> =
+		StorageMap<_, Blake2_128Concat, LockId, WithdrawalRequestOf<T>>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		pub balances: Vec<(T::AccountId, T::Balance, T::Balance)>,
+		pub treasury: Option<T::AccountId>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			let total: T::Balance = self
+				.balances
+				.iter()
+				.map(|(account_id, initial_free, initial_reserved)| {
+					assert!(
+                    	*initial_free + *initial_reserved >= T::ExistentialDeposit::get(),
+                    	"the balance of any account should always be more than existential deposit.",
+                    );
+					let _ = T::RoleManager::register_role(account_id, Role::Customer);
+					Accounts::<T>::mutate(account_id, |account_data| {
+						account_data.free = *initial_free;
+						account_data.reserved = *initial_reserved;
+					});
+					frame_system::Pallet::<T>::inc_providers(account_id);
+					*initial_free + *initial_reserved
+				})
+				.sum();
+			TotalIssuance::<T>::set(total);
+			TreasuryAccount::<T>::set(self.treasury.clone());
+		}
+	}
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn integrity_test() {
+			// Check if the minimum deposit is greater than or equal to the existential deposit
+			assert!(T::MinimumAmount::get() >= T::ExistentialDeposit::get());
+			assert!(T::InterestPayoutPeriod::get() <= T::StakePeriod::get());
+			assert!(T::InterestPayoutPeriod::get() <= T::RedeemPeriod::get());
+			assert!(!T::TotalBlocksPerYear::get().is_zero());
+			assert!(!T::MaxInterestPayoutsPerBlock::get().is_zero());
+			assert!(!T::BlocksPerDay::get().is_zero());
+			assert!(!T::BlocksPerWeek::get().is_zero());
+			assert!(!T::WithdrawalRequestPeriod::get().is_zero());
+			assert!(!T::JointTransferPeriod::get().is_zero());
+			assert!(!T::MaxReapsPerBlock::get().is_zero());
+			assert!(!T::MaxLedgerEntries::get().is_zero());
+			assert!(T::MaxJointHolders::get() >= 2);
+			assert!(T::MaxLoanToValue::get() <= T::LiquidationLoanToValue::get());
+		}
+
+		fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
+			// Reap accounts below ED
+			let (visited, cleared) = Self::reap_accounts(T::MaxReapsPerBlock::get());
+			let mut weight = T::WeightInfo::reap_accounts(visited)
+				.saturating_add(T::DbWeight::get().writes(cleared.into()));
+
+			// Interest is paid in `on_finalize`, if a payout is in progress or starts this block.
+			let period_end = (block_number % T::InterestPayoutPeriod::get()).is_zero();
+			if period_end || InterestPayoutQueue::<T>::decode_len().unwrap_or_default() > 0 {
+				weight.saturating_accrue(T::WeightInfo::pay_interest(
+					T::MaxInterestPayoutsPerBlock::get(),
+				));
+			} else {
+				weight.saturating_accrue(T::DbWeight::get().reads(1));
+			}
+
+			// Overdraft interest is charged in `on_finalize` the same way.
+			if period_end || OverdraftChargeQueue::<T>::decode_len().unwrap_or_default() > 0 {
+				weight.saturating_accrue(T::WeightInfo::charge_overdraft_interest(
+					T::MaxInterestPayoutsPerBlock::get(),
+				));
+			} else {
+				weight.saturating_accrue(T::DbWeight::get().reads(1));
+			}
+			weight
+		}
+
+		fn on_finalize(block_number: BlockNumberFor<T>) {
+			// Unlock funds that are due.
+			AccountWithUnlockedFund::<T>::take(block_number).into_iter().for_each(
+				|(user, lock_id)| {
+					LockExpiries::<T>::remove(&user, lock_id);
+					// Ignore the unlock result - a failed unlock must not stop the others.
+					let _ = Self::unlock(&user, lock_id, UnlockReason::Expired);
+				},
+			);
+
+			// Unfreeze accounts whose freeze expired.
+			FreezeExpirySchedule::<T>::take(block_number).into_iter().for_each(|user| {
+				// Refrozen accounts leave stale entries in the schedule.
+				if Self::frozen_accounts(&user).and_then(|info| info.expiry) == Some(block_number) {
+					FrozenAccounts::<T>::remove(&user);
+					Self::deposit_event(Event::<T>::AccountUnfrozen { user });
+				}
+			});
+
+			// Execute standing orders that are due.
+			StandingOrderSchedule::<T>::take(block_number).into_iter().for_each(|order_id| {
+				if let Some(order) = StandingOrders::<T>::get(order_id) {
+					// Amended orders leave stale entries in the schedule.
+					if order.next_execution == block_number {
+						Self::execute_standing_order(order_id, order, block_number);
+					}
+				}
+			});
+
+			// Liquidate loans that reached the liquidation threshold.
+			LoanLiquidationSchedule::<T>::take(block_number)
+				.into_iter()
+				.for_each(|lock_id| {
+					if let Some(mut loan) = Loans::<T>::get(lock_id) {
+						// Repaid loans leave stale entries in the schedule.
+						if loan.liquidation_at == Some(block_number) &&
+							Self::liquidate(lock_id, loan.clone()).is_err()
+						{
+							// Try again later, e.g. if the treasury is not set.
+							Self::schedule_liquidation(lock_id, &mut loan);
+							Loans::<T>::insert(lock_id, loan);
+						}
+					}
+				});
+
+			// Apply the scheduled interest rate that is due.
+			if let Some(scheduled) = NextInterestRate::<T>::get() {
+				if scheduled.effective_at <= block_number {
+					NextInterestRate::<T>::kill();
+					Self::apply_interest_rate(scheduled, block_number);
+				}
+			}
+
+			// Compound the interest index at the end of every payout period. The interest is
+			// realised lazily when an account is touched, and the payout settles the interest of
+			// the accounts that are not touched over the next blocks.
+			if (block_number % T::InterestPayoutPeriod::get()).is_zero() {
+				Self::update_interest_index(block_number);
+				// A full queue is not settled in time, its payouts already realise the interest
+				// of this period.
+				let _ = InterestPayoutQueue::<T>::try_append(InterestPayout {
+					period_end: block_number,
+					interest_rate: InterestRate::<T>::get(),
+					total_interest_payed: Zero::zero(),
+					last_account: None,
+				});
+				let rate = FixedU128::from_perbill(OverdraftRate::<T>::get()).saturating_mul(
+					FixedU128::saturating_from_rational(
+						T::InterestPayoutPeriod::get(),
+						T::TotalBlocksPerYear::get(),
+					),
+				);
+				let _ = OverdraftChargeQueue::<T>::try_append(OverdraftCharge {
+					rate,
+					last_account: None,
+				});
+			}
+
+			// Pay out interest, and charge overdraft interest, for a bounded number of accounts.
+			Self::pay_interest(T::MaxInterestPayoutsPerBlock::get());
+			Self::charge_overdraft_interest(T::MaxInterestPayoutsPerBlock::get());
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_block_number: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Mint some fund and deposit into user's account.
+		///
+		/// Requires Manager.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::deposit())]
+		pub fn deposit(
+			origin: OriginFor<T>,
+			user: T::AccountId,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&id, Role::Manager)?;
+
+			if amount < T::MinimumAmount::get() {
+				return Err(Error::<T>::AmountTooSmall.into());
+			}
+			<Self as BasicAccounting<T::AccountId, T::Balance>>::deposit(&user, amount)
+		}
+
+		/// Withdraw from user's account and the withdrew funds are burned.
+		///
+		/// Requires Manager.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::withdraw())]
+		pub fn withdraw(
+			origin: OriginFor<T>,
+			user: T::AccountId,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&id, Role::Manager)?;
+
+			if amount < T::MinimumAmount::get() {
+				return Err(Error::<T>::AmountTooSmall.into());
+			}
+			<Self as BasicAccounting<T::AccountId, T::Balance>>::withdraw(&user, amount)
+		}
+
+		/// Transfer `amount` of fund from the current user to another user.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::transfer())]
+		pub fn transfer(
+			origin: OriginFor<T>,
+			to_user: T::AccountId,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&id, Role::Customer)?;
+			T::RoleManager::ensure_role(&to_user, Role::Customer)?;
+
+			if amount < T::MinimumAmount::get() {
+				return Err(Error::<T>::AmountTooSmall.into());
+			}
+			<Self as BasicAccounting<T::AccountId, T::Balance>>::transfer(&id, &to_user, amount)?;
+			Self::charge_transfer_fee(&id, amount)
+		}
+
+		/// Stake `amount` of fund from the current user's free account to reserved account.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::stake_funds())]
+		pub fn stake_funds(origin: OriginFor<T>, amount: T::Balance) -> DispatchResult {
+			let user = ensure_signed(origin)?;
+			<Self as Stakable<T::AccountId, T::Balance>>::stake_funds(&user, amount)
+		}
+
+		/// Redeem `amount` of fund from the current user's reserved account to locked account.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::redeem_funds())]
+		pub fn redeem_funds(origin: OriginFor<T>, amount: T::Balance) -> DispatchResult {
+			let user = ensure_signed(origin)?;
+			<Self as Stakable<T::AccountId, T::Balance>>::redeem_funds(&user, amount)
+		}
+		/// Auditor locked `amount` of fund from the any user's account to locked account for some
+		/// period. Funds are taken from "free" first, then from "reserved".
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::lock_funds_auditor())]
+		pub fn lock_funds_auditor(
+			origin: OriginFor<T>,
+			user: T::AccountId,
+			amount: T::Balance,
+			length: BlockNumberFor<T>,
+		) -> DispatchResult {
+			// Ensure the caller is the Auditor
+			let id = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&id, Role::Auditor)?;
+			// Ensure the user is a customer to be locked
+			T::RoleManager::ensure_role(&user, Role::Customer)?;
+			// Implement logic to lock funds from free and reserved
+			let unlock = frame_system::Pallet::<T>::current_block_number() + length;
+
+			Self::try_mutate_account(NATIVE_ASSET_ID, &user, |account_data| {
+				ensure!(
+					account_data.free + account_data.reserved >= amount,
+					Error::<T>::InsufficientBalance
+				);
+
+				let mut remain = amount;
+				let free_deduction = min(account_data.free, remain);
+				account_data.free -= free_deduction;
+				remain -= free_deduction;
+
+				account_data.reserved -= remain;
+
+				let new_locked_fund =
+					LockedFund { id: Self::next_lock_id(), amount, reason: LockReason::Auditor };
+				account_data
+					.locked
+					.try_push(new_locked_fund)
+					.map_err(|_| Error::<T>::TooManyLocks)?;
+
+				// Add new unlock user to the AccountWithUnlockedFunds
+				AccountWithUnlockedFund::<T>::try_append(
+					unlock,
+					(user.clone(), new_locked_fund.id),
+				)
+				.map_err(|_| Error::<T>::TooManyScheduled)?;
+				LockExpiries::<T>::insert(&user, new_locked_fund.id, unlock);
+
+				Self::deposit_event(Event::<T>::Locked {
+					user: user.clone(),
+					amount,
+					length,
+					reason: LockReason::Auditor,
+				});
+				Ok(())
+			})?;
+			Self::record_ledger_entry(&user, LedgerEntryKind::Lock, amount, None);
+			Ok(())
+		}
+
+		/// Auditor unlocked the LockId which free the `amount` of fund from the user locked by
+		/// auditor. Funds are returned from "locked" to "free".
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::unlock_funds_auditor())]
+		pub fn unlock_funds_auditor(
+			origin: OriginFor<T>,
+			user: T::AccountId,
+			lock_id: LockId,
+		) -> DispatchResult {
+			// Ensure the caller is the Auditor
+			let id = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&id, Role::Auditor)?;
+			// Ensure the user is a customer to be locked
+			T::RoleManager::ensure_role(&user, Role::Customer)?;
+
+			Self::unlock(&user, lock_id, UnlockReason::Auditor)
+		}
+
+		/// Manager set interest rate in basis point. The rate is scheduled to take effect after
+		/// the notice period, and can be vetoed by governance until then.
+		///
+		/// Deprecated, use `schedule_interest_rate` to choose when the new rate takes effect.
+		///
+		/// Requires Manager.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::schedule_interest_rate())]
+		pub fn set_interest_rate(origin: OriginFor<T>, interest_rate_bps: u32) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&id, Role::Manager)?;
+
+			let effective_at = frame_system::Pallet::<T>::current_block_number() +
+				T::InterestRateNoticePeriod::get();
+			Self::do_schedule_interest_rate(id, interest_rate_bps, effective_at)
+		}
+
+		/// Migrate the old treasury account to a new one.
+		///
+		/// Requires governance approved.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::rotate_treasury())]
+		pub fn rotate_treasury(origin: OriginFor<T>, new_treasury: T::AccountId) -> DispatchResult {
+			// ensure governance
+			T::EnsureGovernance::ensure_origin(origin)?;
+
+			ensure!(!Accounts::<T>::contains_key(&new_treasury), Error::<T>::AccountIdAlreadyTaken);
+			ensure!(
+				T::RoleManager::role(&new_treasury).is_none(),
+				Error::<T>::AccountIdAlreadyTaken
+			);
+			ensure!(
+				AssetAccounts::<T>::iter_prefix_values(&new_treasury).next().is_none(),
+				Error::<T>::AccountIdAlreadyTaken
+			);
+
+			let old_treasury = Self::treasury().ok();
+			if let Some(treasury) = old_treasury.clone() {
+				// Update lock expiry of old treasury to the new treasury account.
+				AccountWithUnlockedFund::<T>::iter().for_each(|(block_number, mut accounts)| {
+					accounts.iter_mut().for_each(|(user_id, _)| {
+						if *user_id == treasury {
+							*user_id = new_treasury.clone();
+						}
+					});
+					// Update the storage map with the modified accounts
+					AccountWithUnlockedFund::<T>::insert(block_number, accounts);
+				});
+				LockExpiries::<T>::drain_prefix(&treasury)
+					.collect::<Vec<_>>()
+					.into_iter()
+					.for_each(|(lock_id, unlock)| {
+						LockExpiries::<T>::insert(&new_treasury, lock_id, unlock);
+					});
+
+				Accounts::<T>::insert(&new_treasury, Accounts::<T>::take(&treasury));
+				InterestSnapshot::<T>::insert(
+					&new_treasury,
+					InterestSnapshot::<T>::take(&treasury),
+				);
+				AssetAccounts::<T>::drain_prefix(&treasury)
+					.collect::<Vec<_>>()
+					.into_iter()
+					.for_each(|(asset_id, account_data)| {
+						AssetAccounts::<T>::insert(&new_treasury, asset_id, account_data);
+					});
+			}
+
+			TreasuryAccount::<T>::set(Some(new_treasury.clone()));
+			Self::deposit_event(Event::<T>::TreasuryAccountRotated {
+				old: old_treasury,
+				new: new_treasury,
+			});
+			Ok(())
+		}
+
+		/// Force transfer `amount` of fund from one user to another user.
+		/// Requires governance approved.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::force_transfer())]
+		pub fn force_transfer(
+			origin: OriginFor<T>,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			// ensure governance
+			T::EnsureGovernance::ensure_origin(origin)?;
+			T::RoleManager::ensure_role(&from, Role::Customer)?;
+			T::RoleManager::ensure_role(&to, Role::Customer)?;
+			<Self as BasicAccounting<T::AccountId, T::Balance>>::transfer(&from, &to, amount)
+		}
+
+		/// Register a new asset that can be held alongside the native currency.
+		///
+		/// Requires governance approved.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::register_asset())]
+		pub fn register_asset(
+			origin: OriginFor<T>,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8,
+			existential_deposit: T::Balance,
+		) -> DispatchResult {
+			// ensure governance
+			T::EnsureGovernance::ensure_origin(origin)?;
+			let name = BoundedVec::try_from(name).map_err(|_| Error::<T>::AssetMetadataTooLong)?;
+			let symbol =
+				BoundedVec::try_from(symbol).map_err(|_| Error::<T>::AssetMetadataTooLong)?;
+
+			let asset_id = Self::next_asset_id();
+			let metadata = AssetMetadata { name, symbol, decimals, existential_deposit };
+			Assets::<T>::insert(asset_id, metadata.clone());
+
+			Self::deposit_event(Event::<T>::AssetRegistered { asset_id, metadata });
+			Ok(())
+		}
+
+		/// Mint some funds of an asset and deposit into user's account.
+		///
+		/// Requires Manager.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::deposit_asset())]
+		pub fn deposit_asset(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			user: T::AccountId,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&id, Role::Manager)?;
+
+			if amount < Self::minimum_amount(asset_id)? {
+				return Err(Error::<T>::AmountTooSmall.into());
+			}
+			<Self as MultiAssetAccounting<T::AccountId, AssetId, T::Balance>>::deposit(
+				asset_id, &user, amount,
+			)
+		}
+
+		/// Withdraw some funds of an asset from user's account and the withdrew funds are burned.
+		///
+		/// Requires Manager.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::withdraw_asset())]
+		pub fn withdraw_asset(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			user: T::AccountId,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&id, Role::Manager)?;
+
+			if amount < Self::minimum_amount(asset_id)? {
+				return Err(Error::<T>::AmountTooSmall.into());
+			}
+			<Self as MultiAssetAccounting<T::AccountId, AssetId, T::Balance>>::withdraw(
+				asset_id, &user, amount,
+			)
+		}
+
+		/// Transfer `amount` of an asset from the current user to another user.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::transfer_asset())]
+		pub fn transfer_asset(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			to_user: T::AccountId,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&id, Role::Customer)?;
+			T::RoleManager::ensure_role(&to_user, Role::Customer)?;
+
+			if amount < Self::minimum_amount(asset_id)? {
+				return Err(Error::<T>::AmountTooSmall.into());
+			}
+			<Self as MultiAssetAccounting<T::AccountId, AssetId, T::Balance>>::transfer(
+				asset_id, &id, &to_user, amount,
+			)
+		}
+
+		/// Stake `amount` of an asset from the current user's free account to reserved account.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::stake_asset_funds())]
+		pub fn stake_asset_funds(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let user = ensure_signed(origin)?;
+			<Self as MultiAssetStakable<T::AccountId, AssetId, T::Balance>>::stake_funds(
+				asset_id, &user, amount,
+			)
+		}
+
+		/// Redeem `amount` of an asset from the current user's reserved account to locked
+		/// account.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::redeem_asset_funds())]
+		pub fn redeem_asset_funds(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let user = ensure_signed(origin)?;
+			<Self as MultiAssetStakable<T::AccountId, AssetId, T::Balance>>::redeem_funds(
+				asset_id, &user, amount,
+			)
+		}
+
+		/// Create a standing order that transfers `amount` of fund from the current user to
+		/// another user at block `start`, then every `period` blocks if a period is given.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::create_standing_order())]
+		pub fn create_standing_order(
+			origin: OriginFor<T>,
+			to_user: T::AccountId,
+			#[pallet::compact] amount: T::Balance,
+			start: BlockNumberFor<T>,
+			period: Option<BlockNumberFor<T>>,
+			policy: FailurePolicy,
+		) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&id, Role::Customer)?;
+			T::RoleManager::ensure_role(&to_user, Role::Customer)?;
+
+			ensure!(amount >= T::MinimumAmount::get(), Error::<T>::AmountTooSmall);
+			Self::ensure_valid_schedule(start, period)?;
+
+			let order_id = Self::next_order_id();
+			StandingOrders::<T>::insert(
+				order_id,
+				StandingOrder {
+					from: id.clone(),
+					to: to_user.clone(),
+					amount,
+					next_execution: start,
+					period,
+					policy,
+					retries: 0u32,
+				},
+			);
+			StandingOrderSchedule::<T>::try_append(start, order_id)
+				.map_err(|_| Error::<T>::TooManyScheduled)?;
+
+			Self::deposit_event(Event::<T>::StandingOrderCreated {
+				order_id,
+				from: id,
+				to: to_user,
+				amount,
+				next_execution: start,
+				period,
+			});
+			Ok(())
+		}
+
+		/// Amend the amount, the schedule and the failure policy of a standing order.
+		///
+		/// Requires the payer of the standing order.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::amend_standing_order())]
+		pub fn amend_standing_order(
+			origin: OriginFor<T>,
+			order_id: OrderId,
+			#[pallet::compact] amount: T::Balance,
+			next_execution: BlockNumberFor<T>,
+			period: Option<BlockNumberFor<T>>,
+			policy: FailurePolicy,
+		) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+
+			ensure!(amount >= T::MinimumAmount::get(), Error::<T>::AmountTooSmall);
+			Self::ensure_valid_schedule(next_execution, period)?;
+
+			StandingOrders::<T>::try_mutate(order_id, |maybe_order| -> DispatchResult {
+				let order = maybe_order.as_mut().ok_or(Error::<T>::InvalidOrderId)?;
+				ensure!(order.from == id, Error::<T>::NotOrderOwner);
+
+				if order.next_execution != next_execution {
+					StandingOrderSchedule::<T>::try_append(next_execution, order_id)
+						.map_err(|_| Error::<T>::TooManyScheduled)?;
+				}
+				order.amount = amount;
+				order.next_execution = next_execution;
+				order.period = period;
+				order.policy = policy;
+				order.retries = 0u32;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::StandingOrderAmended {
+				order_id,
+				amount,
+				next_execution,
+				period,
+			});
+			Ok(())
+		}
+
+		/// Cancel a standing order, so it is never executed again.
+		///
+		/// Requires the payer of the standing order.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::cancel_standing_order())]
+		pub fn cancel_standing_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+
+			let order = StandingOrders::<T>::get(order_id).ok_or(Error::<T>::InvalidOrderId)?;
+			ensure!(order.from == id, Error::<T>::NotOrderOwner);
+			// The entry in the schedule is ignored once the order is removed.
+			StandingOrders::<T>::remove(order_id);
+
+			Self::deposit_event(Event::<T>::StandingOrderCancelled { order_id });
+			Ok(())
+		}
+
+		/// Lock `amount` of fund from the current user's "free" in escrow for the payee. The funds
+		/// are released to the payee when the payer or the arbiter releases them, or after
+		/// `length` blocks unless the escrow is disputed.
+		///
+		/// The arbiter must be an Auditor.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::create_escrow())]
+		pub fn create_escrow(
+			origin: OriginFor<T>,
+			payee: T::AccountId,
+			arbiter: T::AccountId,
+			#[pallet::compact] amount: T::Balance,
+			length: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let payer = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&payer, Role::Customer)?;
+			T::RoleManager::ensure_role(&payee, Role::Customer)?;
+			T::RoleManager::ensure_role(&arbiter, Role::Auditor)?;
+			Self::ensure_not_frozen(&payer)?;
+
+			ensure!(amount >= T::MinimumAmount::get(), Error::<T>::AmountTooSmall);
+			ensure!(!length.is_zero(), Error::<T>::InvalidSchedule);
+
+			let lock_id = Self::next_lock_id();
+			Self::with_velocity_limit(&payer, amount, || {
+				Self::try_mutate_account(NATIVE_ASSET_ID, &payer, |account| {
+					ensure!(account.free >= amount, Error::<T>::InsufficientBalance);
+					account.free -= amount;
+					let new_locked_fund =
+						LockedFund { id: lock_id, amount, reason: LockReason::Escrow };
+					account
+						.locked
+						.try_push(new_locked_fund)
+						.map_err(|_| Error::<T>::TooManyLocks)?;
+					Ok(())
+				})
+			})?;
+			Self::schedule_unlock(NATIVE_ASSET_ID, &payer, lock_id, length)?;
+			Self::record_ledger_entry(&payer, LedgerEntryKind::Lock, amount, None);
+
+			let deadline = frame_system::Pallet::<T>::current_block_number() + length;
+			Escrows::<T>::insert(
+				lock_id,
+				EscrowInfo {
+					payer: payer.clone(),
+					payee: payee.clone(),
+					arbiter: arbiter.clone(),
+					deadline,
+					disputed: false,
+				},
+			);
+
+			Self::deposit_event(Event::<T>::EscrowCreated {
+				lock_id,
+				payer,
+				payee,
+				arbiter,
+				amount,
+				deadline,
+			});
+			Ok(())
+		}
+
+		/// Release the escrowed funds to the payee.
+		///
+		/// Requires the payer, or the arbiter of the escrow.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::release_escrow())]
+		pub fn release_escrow(origin: OriginFor<T>, lock_id: LockId) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			let escrow = Escrows::<T>::get(lock_id).ok_or(Error::<T>::InvalidEscrowId)?;
+			if id != escrow.payer {
+				Self::ensure_arbiter(&id, &escrow)?;
+			}
+
+			Self::settle_escrow(lock_id, escrow, true)
+		}
+
+		/// Refund the escrowed funds to the payer.
+		///
+		/// Requires the payee, or the arbiter of the escrow.
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::refund_escrow())]
+		pub fn refund_escrow(origin: OriginFor<T>, lock_id: LockId) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			let escrow = Escrows::<T>::get(lock_id).ok_or(Error::<T>::InvalidEscrowId)?;
+			if id != escrow.payee {
+				Self::ensure_arbiter(&id, &escrow)?;
+			}
+
+			Self::settle_escrow(lock_id, escrow, false)
+		}
+
+		/// Dispute an escrow, so it is not released at the deadline. A disputed escrow is settled
+		/// by the arbiter.
+		///
+		/// Requires the payer or the payee of the escrow.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::dispute_escrow())]
+		pub fn dispute_escrow(origin: OriginFor<T>, lock_id: LockId) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			Escrows::<T>::try_mutate(lock_id, |maybe_escrow| -> DispatchResult {
+				let escrow = maybe_escrow.as_mut().ok_or(Error::<T>::InvalidEscrowId)?;
+				ensure!(id == escrow.payer || id == escrow.payee, Error::<T>::NotEscrowParty);
+				ensure!(!escrow.disputed, Error::<T>::EscrowAlreadyDisputed);
+				escrow.disputed = true;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::EscrowDisputed { lock_id, who: id });
+			Ok(())
+		}
+
+		/// Add a savings product to the catalogue.
+		///
+		/// Requires Manager.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::create_product())]
+		pub fn create_product(
+			origin: OriginFor<T>,
+			kind: ProductKind<T::Balance, BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&id, Role::Manager)?;
+			match &kind {
+				ProductKind::Tiered(tiers) => ensure!(
+					!tiers.is_empty() && tiers.windows(2).all(|pair| pair[0].0 < pair[1].0),
+					Error::<T>::InvalidProduct
+				),
+				ProductKind::FixedTerm { term, .. } =>
+					ensure!(!term.is_zero(), Error::<T>::InvalidProduct),
+			}
+
+			let product_id = Self::next_product_id();
+			Products::<T>::insert(product_id, InterestProduct { kind: kind.clone(), active: true });
+
+			Self::deposit_event(Event::<T>::ProductCreated { product_id, kind });
+			Ok(())
+		}
+
+		/// Retire a savings product, so it no longer accepts deposits.
+		///
+		/// Requires Manager.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::retire_product())]
+		pub fn retire_product(origin: OriginFor<T>, product_id: ProductId) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&id, Role::Manager)?;
+			Products::<T>::try_mutate(product_id, |maybe_product| -> DispatchResult {
+				let product = maybe_product.as_mut().ok_or(Error::<T>::InvalidProductId)?;
+				product.active = false;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::ProductRetired { product_id });
+			Ok(())
+		}
+
+		/// Stake `amount` of fund from the current user's free account into a savings product.
+		/// Fixed-term deposits are paid out with the interest when the term ends.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::stake_funds_into_product())]
+		pub fn stake_funds_into_product(
+			origin: OriginFor<T>,
+			product_id: ProductId,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let user = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&user, Role::Customer)?;
+			Self::ensure_not_frozen(&user)?;
+			ensure!(amount >= T::MinimumAmount::get(), Error::<T>::AmountTooSmall);
+			let product = Products::<T>::get(product_id).ok_or(Error::<T>::InvalidProductId)?;
+			ensure!(product.active, Error::<T>::ProductRetired);
+
+			let lock_id = Self::next_lock_id();
+			Self::with_velocity_limit(&user, amount, || {
+				Self::try_mutate_account(NATIVE_ASSET_ID, &user, |account| {
+					ensure!(account.free >= amount, Error::<T>::InsufficientBalance);
+					account.free -= amount;
+					let new_locked_fund =
+						LockedFund { id: lock_id, amount, reason: LockReason::Product };
+					account
+						.locked
+						.try_push(new_locked_fund)
+						.map_err(|_| Error::<T>::TooManyLocks)?;
+					Ok(())
+				})
+			})?;
+
+			let start = frame_system::Pallet::<T>::current_block_number();
+			let maturity = match product.kind {
+				ProductKind::FixedTerm { term, .. } => {
+					Self::schedule_unlock(NATIVE_ASSET_ID, &user, lock_id, term)?;
+					Some(start + term)
+				},
+				ProductKind::Tiered(_) => None,
+			};
+			ProductDeposits::<T>::insert(
+				lock_id,
+				ProductDeposit { product_id, amount, start, maturity },
+			);
+
+			Self::deposit_event(Event::<T>::ProductStaked { user, product_id, lock_id, amount });
+			Ok(())
+		}
+
+		/// Withdraw a product deposit to the current user's free account. Fixed-term deposits
+		/// withdrawn before the end of the term pay the early-withdrawal penalty to the treasury
+		/// and earn no interest.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::redeem_product_funds())]
+		pub fn redeem_product_funds(origin: OriginFor<T>, lock_id: LockId) -> DispatchResult {
+			let user = ensure_signed(origin)?;
+			Self::ensure_not_frozen(&user)?;
+			let deposit = ProductDeposits::<T>::get(lock_id).ok_or(Error::<T>::InvalidLockId)?;
+			Self::settle_product_deposit(&user, lock_id, deposit)
+		}
+
+		/// Manager set the yearly interest rate of new loans in basis point.
+		///
+		/// Requires Manager.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::set_borrow_rate())]
+		pub fn set_borrow_rate(origin: OriginFor<T>, borrow_rate_bps: u32) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&id, Role::Manager)?;
+			ensure!(borrow_rate_bps <= 10000u32, Error::<T>::InvalidInterestRate);
+			let old_rate = BorrowRate::<T>::get();
+			let new_rate = Perbill::from_rational(borrow_rate_bps, 10000u32);
+			BorrowRate::<T>::set(new_rate);
+
+			Self::deposit_event(Event::<T>::BorrowRateSet { manager: id, old_rate, new_rate });
+			Ok(())
+		}
+
+		/// Lock `collateral` of fund from the current user's reserved account, and borrow
+		/// `amount` into the free account. The loan accrues interest at the current borrow rate.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::borrow())]
+		pub fn borrow(
+			origin: OriginFor<T>,
+			#[pallet::compact] collateral: T::Balance,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let borrower = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&borrower, Role::Customer)?;
+			Self::ensure_not_frozen(&borrower)?;
+			ensure!(amount >= T::MinimumAmount::get(), Error::<T>::AmountTooSmall);
+			ensure!(
+				amount <= T::MaxLoanToValue::get() * collateral,
+				Error::<T>::LoanToValueTooHigh
+			);
+
+			// The collateral can be liquidated to the treasury, so it counts towards the limit.
+			let lock_id = Self::next_lock_id();
+			Self::with_velocity_limit(&borrower, collateral, || {
+				Self::try_mutate_account(NATIVE_ASSET_ID, &borrower, |account| {
+					ensure!(account.reserved >= collateral, Error::<T>::InsufficientBalance);
+					account.reserved -= collateral;
+					let new_locked_fund = LockedFund {
+						id: lock_id,
+						amount: collateral,
+						reason: LockReason::Collateral,
+					};
+					account
+						.locked
+						.try_push(new_locked_fund)
+						.map_err(|_| Error::<T>::TooManyLocks)?;
+					Ok(())
+				})
+			})?;
+			Self::mint(NATIVE_ASSET_ID, &borrower, amount)?;
+
+			let mut loan = Loan {
+				borrower: borrower.clone(),
+				collateral,
+				principal: amount,
+				interest: Zero::zero(),
+				rate: BorrowRate::<T>::get(),
+				updated_at: frame_system::Pallet::<T>::current_block_number(),
+				liquidation_at: None,
+			};
+			Self::schedule_liquidation(lock_id, &mut loan);
+			Loans::<T>::insert(lock_id, loan);
+
+			Self::deposit_event(Event::<T>::LoanOpened {
+				lock_id,
+				borrower,
+				collateral,
+				principal: amount,
+			});
+			Ok(())
+		}
+
+		/// Repay up to `amount` of a loan from the current user's free account, the interest
+		/// first. The collateral is returned to "reserved" when the loan is fully repaid.
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::repay_loan())]
+		pub fn repay_loan(
+			origin: OriginFor<T>,
+			lock_id: LockId,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let borrower = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::AmountTooSmall);
+			let mut loan = Loans::<T>::get(lock_id).ok_or(Error::<T>::InvalidLoanId)?;
+			ensure!(loan.borrower == borrower, Error::<T>::NotLoanOwner);
+			Self::accrue_loan_interest(&mut loan);
+
+			let amount = min(amount, loan.principal.saturating_add(loan.interest));
+			let interest = min(amount, loan.interest);
+			let principal = amount - interest;
+			if !interest.is_zero() {
+				Self::move_funds(NATIVE_ASSET_ID, &borrower, &Self::treasury()?, interest)?;
+				T::TreasuryIncome::record_income(IncomeSource::Interest, interest);
+			}
+			if !principal.is_zero() {
+				Self::burn(NATIVE_ASSET_ID, &borrower, principal)?;
+			}
+			loan.interest -= interest;
+			loan.principal -= principal;
+			Self::deposit_event(Event::<T>::LoanRepaid { lock_id, interest, principal });
+
+			if loan.principal.is_zero() && loan.interest.is_zero() {
+				Self::try_mutate_account(NATIVE_ASSET_ID, &borrower, |account| {
+					let index = account
+						.locked
+						.iter()
+						.position(|lock| lock.id == lock_id)
+						.ok_or(Error::<T>::InvalidLockId)?;
+					account.reserved = account.reserved.saturating_add(loan.collateral);
+					account.locked.remove(index);
+					Ok::<_, DispatchError>(())
+				})?;
+				Loans::<T>::remove(lock_id);
+				Self::deposit_event(Event::<T>::LoanClosed { lock_id });
+			} else {
+				Self::schedule_liquidation(lock_id, &mut loan);
+				Loans::<T>::insert(lock_id, loan);
+			}
+			Ok(())
+		}
+
+		/// Liquidate a loan that reached the liquidation threshold. The collateral is moved to
+		/// the treasury, and the debt is written off.
+		///
+		/// Requires Manager.
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::liquidate_loan())]
+		pub fn liquidate_loan(origin: OriginFor<T>, lock_id: LockId) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&id, Role::Manager)?;
+			let loan = Loans::<T>::get(lock_id).ok_or(Error::<T>::InvalidLoanId)?;
+			Self::liquidate(lock_id, loan)
+		}
+
+		/// Grant a customer an overdraft facility: transfers above "free" are allowed, as a debt
+		/// up to `limit`. Lowering the limit below the current debt stops new drawings.
+		///
+		/// Requires Manager.
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::set_overdraft_limit())]
+		pub fn set_overdraft_limit(
+			origin: OriginFor<T>,
+			user: T::AccountId,
+			#[pallet::compact] limit: T::Balance,
+		) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&id, Role::Manager)?;
+			T::RoleManager::ensure_role(&user, Role::Customer)?;
+			// Accounts in debt keep accruing overdraft interest until the debt is repaid.
+			if limit.is_zero() && Accounts::<T>::get(&user).debt.is_zero() {
+				OverdraftLimits::<T>::remove(&user);
+			} else {
+				OverdraftLimits::<T>::insert(&user, limit);
+			}
+
+			Self::deposit_event(Event::<T>::OverdraftLimitSet { user, limit });
+			Ok(())
+		}
+
+		/// Manager set the yearly interest rate of overdraft debts in basis point.
+		///
+		/// Requires Manager.
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::set_overdraft_rate())]
+		pub fn set_overdraft_rate(origin: OriginFor<T>, overdraft_rate_bps: u32) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&id, Role::Manager)?;
+			ensure!(overdraft_rate_bps <= 10000u32, Error::<T>::InvalidInterestRate);
+			let old_rate = OverdraftRate::<T>::get();
+			let new_rate = Perbill::from_rational(overdraft_rate_bps, 10000u32);
+			OverdraftRate::<T>::set(new_rate);
+
+			Self::deposit_event(Event::<T>::OverdraftRateSet { manager: id, old_rate, new_rate });
+			Ok(())
+		}
+
+		/// Auditor froze a user's account, optionally for `length` blocks. Freezing a frozen
+		/// account replaces its freeze.
+		///
+		/// Requires Auditor.
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::freeze_account())]
+		pub fn freeze_account(
+			origin: OriginFor<T>,
+			user: T::AccountId,
+			reason: FreezeReason,
+			length: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let auditor = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&auditor, Role::Auditor)?;
+			T::RoleManager::ensure_role(&user, Role::Customer)?;
+
+			ensure!(length.map_or(true, |length| !length.is_zero()), Error::<T>::InvalidSchedule);
+
+			let expiry =
+				length.map(|length| frame_system::Pallet::<T>::current_block_number() + length);
+			if let Some(expiry) = expiry {
+				FreezeExpirySchedule::<T>::try_append(expiry, user.clone())
+					.map_err(|_| Error::<T>::TooManyScheduled)?;
+			}
+			FrozenAccounts::<T>::insert(
+				&user,
+				FreezeInfo { auditor: auditor.clone(), reason, expiry },
+			);
+
+			Self::deposit_event(Event::<T>::AccountFrozen { user, auditor, reason, expiry });
+			Ok(())
+		}
+
+		/// Auditor unfroze a user's account.
+		///
+		/// Requires Auditor.
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::unfreeze_account())]
+		pub fn unfreeze_account(origin: OriginFor<T>, user: T::AccountId) -> DispatchResult {
+			let auditor = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&auditor, Role::Auditor)?;
+			FrozenAccounts::<T>::take(&user).ok_or(Error::<T>::AccountNotFrozen)?;
+
+			Self::deposit_event(Event::<T>::AccountUnfrozen { user });
+			Ok(())
+		}
+
+		/// Auditor increased an auditor lock by `amount`. Funds are taken from "free" first, then
+		/// from "reserved".
+		///
+		/// Requires Auditor.
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::increase_auditor_lock())]
+		pub fn increase_auditor_lock(
+			origin: OriginFor<T>,
+			user: T::AccountId,
+			lock_id: LockId,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&id, Role::Auditor)?;
+			T::RoleManager::ensure_role(&user, Role::Customer)?;
+			ensure!(amount >= T::MinimumAmount::get(), Error::<T>::AmountTooSmall);
+
+			Self::amend_auditor_lock(
+				&user,
+				lock_id,
+				|old_amount| Ok(old_amount.saturating_add(amount)),
+				Zero::zero(),
+			)
+		}
+
+		/// Auditor released `amount` of an auditor lock to "free", keeping the rest locked.
+		///
+		/// Requires Auditor.
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::partially_unlock_funds_auditor())]
+		pub fn partially_unlock_funds_auditor(
+			origin: OriginFor<T>,
+			user: T::AccountId,
+			lock_id: LockId,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&id, Role::Auditor)?;
+			T::RoleManager::ensure_role(&user, Role::Customer)?;
+			ensure!(amount >= T::MinimumAmount::get(), Error::<T>::AmountTooSmall);
+
+			Self::amend_auditor_lock(
+				&user,
+				lock_id,
+				|old_amount| {
+					// Use `unlock_funds_auditor` to release the whole lock.
+					ensure!(amount < old_amount, Error::<T>::InvalidUnlockAmount);
+					Ok(old_amount - amount)
+				},
+				Zero::zero(),
+			)
+		}
+
+		/// Auditor extended the expiry of an auditor lock by `length` blocks.
+		///
+		/// Requires Auditor.
+		#[pallet::call_index(37)]
+		#[pallet::weight(T::WeightInfo::extend_auditor_lock())]
+		pub fn extend_auditor_lock(
+			origin: OriginFor<T>,
+			user: T::AccountId,
+			lock_id: LockId,
+			length: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&id, Role::Auditor)?;
+			T::RoleManager::ensure_role(&user, Role::Customer)?;
+			ensure!(!length.is_zero(), Error::<T>::InvalidSchedule);
+
+			Self::amend_auditor_lock(&user, lock_id, Ok, length)
+		}
+
+		/// Set the fee schedule of customer transfers, or remove it to make transfers free.
+		///
+		/// Requires governance approved.
+		#[pallet::call_index(38)]
+		#[pallet::weight(T::WeightInfo::set_transfer_fee_schedule())]
+		pub fn set_transfer_fee_schedule(
+			origin: OriginFor<T>,
+			schedule: Option<FeeSchedule<T::Balance>>,
+		) -> DispatchResult {
+			// ensure governance
+			T::EnsureGovernance::ensure_origin(origin)?;
+			if let Some(FeeSchedule::Tiered(tiers)) = &schedule {
+				ensure!(
+					!tiers.is_empty() && tiers.windows(2).all(|pair| pair[0].0 < pair[1].0),
+					Error::<T>::InvalidFeeSchedule
+				);
+			}
+
+			TransferFeeSchedule::<T>::set(schedule.clone());
+			Self::deposit_event(Event::<T>::TransferFeeScheduleSet { schedule });
+			Ok(())
+		}
+
+		/// Manager set the default daily and weekly outgoing value limit of the customers, or
+		/// remove it to lift the default limit.
+		///
+		/// Requires Manager.
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::set_default_velocity_limit())]
+		pub fn set_default_velocity_limit(
+			origin: OriginFor<T>,
+			limit: Option<VelocityLimit<T::Balance>>,
+		) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&id, Role::Manager)?;
+
+			DefaultVelocityLimit::<T>::set(limit);
+			Self::deposit_event(Event::<T>::DefaultVelocityLimitSet { limit });
+			Ok(())
+		}
+
+		/// Manager set the daily and weekly outgoing value limit of a customer, or remove it so
+		/// the default limit applies.
+		///
+		/// Requires Manager.
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::set_velocity_limit())]
+		pub fn set_velocity_limit(
+			origin: OriginFor<T>,
+			user: T::AccountId,
+			limit: Option<VelocityLimit<T::Balance>>,
+		) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&id, Role::Manager)?;
+			T::RoleManager::ensure_role(&user, Role::Customer)?;
+
+			VelocityLimits::<T>::set(&user, limit);
+			Self::deposit_event(Event::<T>::VelocityLimitSet { user, limit });
+			Ok(())
+		}
+
+		/// A customer created a joint account with other holders, registered as a customer. The
+		/// account Id is derived from the holders and the threshold.
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::create_joint_account(holders.len() as u32))]
+		pub fn create_joint_account(
+			origin: OriginFor<T>,
+			mut holders: Vec<T::AccountId>,
+			threshold: u32,
+		) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			holders.sort();
+			holders.dedup();
+			ensure!(holders.binary_search(&id).is_ok(), Error::<T>::NotJointHolder);
+			ensure!(
+				holders.len() >= 2 &&
+					holders.len() as u32 <= T::MaxJointHolders::get() &&
+					threshold >= 1 && threshold <= holders.len() as u32,
+				Error::<T>::InvalidJointAccount
+			);
+			for holder in holders.iter() {
+				T::RoleManager::ensure_role(holder, Role::Customer)?;
+			}
+
+			let joint_account = Self::joint_account_id(&holders, threshold);
+			ensure!(
+				!JointAccounts::<T>::contains_key(&joint_account) &&
+					!Accounts::<T>::contains_key(&joint_account),
+				Error::<T>::AccountIdAlreadyTaken
+			);
+			T::RoleManager::register_role(&joint_account, Role::Customer)?;
+			JointAccounts::<T>::insert(
+				&joint_account,
+				JointAccount { holders: BoundedVec::truncate_from(holders.clone()), threshold },
+			);
+
+			Self::deposit_event(Event::<T>::JointAccountCreated {
+				joint_account,
+				holders,
+				threshold,
+			});
+			Ok(())
+		}
+
+		/// A holder proposed to transfer `amount` out of a joint account. The proposer approves
+		/// the transfer, which runs once `threshold` holders approve it. The transfer can be voted
+		/// on for `JointTransferPeriod` blocks.
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::propose_joint_transfer())]
+		pub fn propose_joint_transfer(
+			origin: OriginFor<T>,
+			joint_account: T::AccountId,
+			to: T::AccountId,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			let joint = Self::ensure_joint_holder(&joint_account, &id)?;
+			T::RoleManager::ensure_role(&to, Role::Customer)?;
+			ensure!(amount >= T::MinimumAmount::get(), Error::<T>::AmountTooSmall);
+
+			let proposal_id = Self::next_joint_transfer_id();
+			let expiry =
+				frame_system::Pallet::<T>::current_block_number() + T::JointTransferPeriod::get();
+			Self::deposit_event(Event::<T>::JointTransferProposed {
+				proposal_id,
+				joint_account: joint_account.clone(),
+				proposer: id.clone(),
+				to: to.clone(),
+				amount,
+				expiry,
+			});
+			let transfer = JointTransfer {
+				joint_account,
+				to,
+				amount,
+				approvals: Default::default(),
+				rejections: Default::default(),
+				expiry,
+			};
+			Self::vote_on_joint_transfer(proposal_id, transfer, &joint, id, true)
+		}
+
+		/// A holder approved or rejected a transfer out of a joint account, before it expires.
+		/// An approved transfer that fails is dropped, and the failure is reported in the event.
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::vote_joint_transfer())]
+		pub fn vote_joint_transfer(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			approve: bool,
+		) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			let transfer =
+				JointTransfers::<T>::get(proposal_id).ok_or(Error::<T>::InvalidJointTransferId)?;
+			let joint = Self::ensure_joint_holder(&transfer.joint_account, &id)?;
+			ensure!(
+				frame_system::Pallet::<T>::current_block_number() < transfer.expiry,
+				Error::<T>::JointTransferExpired
+			);
+			Self::vote_on_joint_transfer(proposal_id, transfer, &joint, id, approve)
+		}
+
+		/// Allow a spender to transfer up to `amount` out of the caller's account, optionally
+		/// for a non-zero `length` of blocks. Replaces any allowance granted to the spender.
+		#[pallet::call_index(44)]
+		#[pallet::weight(T::WeightInfo::approve())]
+		pub fn approve(
+			origin: OriginFor<T>,
+			spender: T::AccountId,
+			#[pallet::compact] amount: T::Balance,
+			length: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&owner, Role::Customer)?;
+			ensure!(length.map_or(true, |length| !length.is_zero()), Error::<T>::InvalidSchedule);
+
+			let expiry =
+				length.map(|length| frame_system::Pallet::<T>::current_block_number() + length);
+			Allowances::<T>::insert(&owner, &spender, Allowance { amount, expiry });
+			Self::deposit_event(Event::<T>::AllowanceApproved { owner, spender, amount, expiry });
+			Ok(())
+		}
+
+		/// Increase the allowance of a spender by `amount`, keeping its expiry. A new allowance
+		/// does not expire. An expired allowance can't be increased, it must be approved again.
+		#[pallet::call_index(45)]
+		#[pallet::weight(T::WeightInfo::increase_allowance())]
+		pub fn increase_allowance(
+			origin: OriginFor<T>,
+			spender: T::AccountId,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&owner, Role::Customer)?;
+
+			let now = frame_system::Pallet::<T>::current_block_number();
+			let allowance = Allowances::<T>::try_mutate(&owner, &spender, |allowance| {
+				let allowance =
+					allowance.get_or_insert(Allowance { amount: Zero::zero(), expiry: None });
+				ensure!(
+					allowance.expiry.map_or(true, |expiry| now < expiry),
+					Error::<T>::AllowanceExpired
+				);
+				allowance.amount = allowance.amount.saturating_add(amount);
+				Ok::<_, DispatchError>(*allowance)
+			})?;
+			Self::deposit_event(Event::<T>::AllowanceApproved {
+				owner,
+				spender,
+				amount: allowance.amount,
+				expiry: allowance.expiry,
+			});
+			Ok(())
+		}
+
+		/// Revoke the allowance of a spender.
+		#[pallet::call_index(46)]
+		#[pallet::weight(T::WeightInfo::revoke())]
+		pub fn revoke(origin: OriginFor<T>, spender: T::AccountId) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Allowances::<T>::take(&owner, &spender).ok_or(Error::<T>::AllowanceNotFound)?;
+
+			Self::deposit_event(Event::<T>::AllowanceRevoked { owner, spender });
+			Ok(())
+		}
+
+		/// Transfer `amount` out of an owner's account, spending the allowance the owner granted
+		/// to the caller. The owner pays the transfer fee.
+		#[pallet::call_index(47)]
+		#[pallet::weight(T::WeightInfo::transfer_from())]
+		pub fn transfer_from(
+			origin: OriginFor<T>,
+			from: T::AccountId,
+			to: T::AccountId,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let spender = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&to, Role::Customer)?;
+			ensure!(amount >= T::MinimumAmount::get(), Error::<T>::AmountTooSmall);
+
+			<Self as BasicAccounting<T::AccountId, T::Balance>>::transfer_from(
+				&spender, &from, &to, amount,
+			)?;
+			Self::charge_transfer_fee(&from, amount)
+		}
+
+		/// Transfer funds from the caller to each recipient, as a batch of `transfer` legs. In
+		/// `Atomic` mode the whole batch fails if any leg fails. In `BestEffort` mode failed
+		/// legs are skipped, and the result of each leg is reported in the event. A batch has at
+		/// most `MaxBatchTransfers` legs.
+		#[pallet::call_index(48)]
+		#[pallet::weight(T::WeightInfo::batch_transfer(transfers.len() as u32))]
+		pub fn batch_transfer(
+			origin: OriginFor<T>,
+			transfers: BoundedVec<(T::AccountId, T::Balance), T::MaxBatchTransfers>,
+			mode: BatchMode,
+		) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&id, Role::Customer)?;
+
+			let mut results = Vec::with_capacity(transfers.len());
+			for (to, amount) in transfers {
+				let result = match mode {
+					BatchMode::Atomic => {
+						Self::transfer_leg(&id, &to, amount)?;
+						Ok(())
+					},
+					BatchMode::BestEffort =>
+						with_storage_layer(|| Self::transfer_leg(&id, &to, amount)),
+				};
+				results.push(result);
+			}
+
+			Self::deposit_event(Event::<T>::BatchTransferred { from: id, results });
+			Ok(())
+		}
+
+		/// Veto the scheduled interest rate before it takes effect.
+		///
+		/// Requires governance approved.
+		#[pallet::call_index(49)]
+		#[pallet::weight(T::WeightInfo::veto_interest_rate())]
+		pub fn veto_interest_rate(origin: OriginFor<T>) -> DispatchResult {
+			// ensure governance
+			T::EnsureGovernance::ensure_origin(origin)?;
+
+			let scheduled =
+				NextInterestRate::<T>::take().ok_or(Error::<T>::NoScheduledInterestRate)?;
+			Self::deposit_event(Event::<T>::InterestRateVetoed {
+				interest_rate: scheduled.interest_rate,
+				effective_at: scheduled.effective_at,
+			});
+			Ok(())
+		}
+
+		/// Set the bounds on the interest rates managers schedule, or remove them with `None`.
+		///
+		/// Requires governance approved.
+		#[pallet::call_index(50)]
+		#[pallet::weight(T::WeightInfo::set_interest_rate_bounds())]
+		pub fn set_interest_rate_bounds(
+			origin: OriginFor<T>,
+			bounds: Option<InterestRateBounds>,
+		) -> DispatchResult {
+			// ensure governance
+			T::EnsureGovernance::ensure_origin(origin)?;
+
+			if let Some(bounds) = bounds {
+				ensure!(bounds.min <= bounds.max, Error::<T>::InvalidInterestRate);
+			}
+			RateBounds::<T>::set(bounds);
+			Self::deposit_event(Event::<T>::InterestRateBoundsSet { bounds });
+			Ok(())
+		}
+
+		/// Set whether the treasury pays the transaction fees of a call, by its pallet index and
+		/// call index. For example, sponsoring `Nft::receive_pod` lets customers receive PODs
+		/// without paying fees.
+		///
+		/// Requires governance approved.
+		#[pallet::call_index(51)]
+		#[pallet::weight(T::WeightInfo::set_call_sponsorship())]
+		pub fn set_call_sponsorship(
+			origin: OriginFor<T>,
+			pallet_index: u8,
+			call_index: u8,
+			sponsored: bool,
+		) -> DispatchResult {
+			// ensure governance
+			T::EnsureGovernance::ensure_origin(origin)?;
+
+			SponsoredCalls::<T>::try_mutate(|calls| -> DispatchResult {
+				if sponsored {
+					calls
+						.try_insert((pallet_index, call_index))
+						.map_err(|_| Error::<T>::TooManySponsoredCalls)?;
+				} else {
+					calls.remove(&(pallet_index, call_index));
+				}
+				Ok(())
+			})?;
+			Self::deposit_event(Event::<T>::CallSponsorshipSet {
+				pallet_index,
+				call_index,
+				sponsored,
+			});
+			Ok(())
+		}
+
+		/// Request to withdraw `amount` of fund from the current user's "free". The funds are held
+		/// until a Manager approves or rejects the request, or released if the request is not
+		/// settled within `WithdrawalRequestPeriod` blocks.
+		#[pallet::call_index(52)]
+		#[pallet::weight(T::WeightInfo::request_withdrawal())]
+		pub fn request_withdrawal(
+			origin: OriginFor<T>,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let user = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&user, Role::Customer)?;
+			Self::ensure_not_frozen(&user)?;
+			ensure!(amount >= T::MinimumAmount::get(), Error::<T>::AmountTooSmall);
+
+			let lock_id = Self::next_lock_id();
+			Self::with_velocity_limit(&user, amount, || {
+				Self::try_mutate_account(NATIVE_ASSET_ID, &user, |account| {
+					ensure!(account.free >= amount, Error::<T>::InsufficientBalance);
+					account.free -= amount;
+					let new_locked_fund =
+						LockedFund { id: lock_id, amount, reason: LockReason::Withdrawal };
+					account
+						.locked
+						.try_push(new_locked_fund)
+						.map_err(|_| Error::<T>::TooManyLocks)?;
+					Ok(())
+				})
+			})?;
+			let length = T::WithdrawalRequestPeriod::get();
+			Self::schedule_unlock(NATIVE_ASSET_ID, &user, lock_id, length)?;
+
+			let expiry = frame_system::Pallet::<T>::current_block_number() + length;
+			WithdrawalRequests::<T>::insert(
+				lock_id,
+				WithdrawalRequest { user: user.clone(), amount, expiry },
+			);
+
+			Self::deposit_event(Event::<T>::WithdrawalRequested { lock_id, user, amount, expiry });
+			Ok(())
+		}
+
+		/// Approve a withdrawal request. The held funds are burned, and the payout reference of
+		/// the `WithdrawalApproved` event is used to pay the user out off-chain.
+		///
+		/// Requires Manager.
+		#[pallet::call_index(53)]
+		#[pallet::weight(T::WeightInfo::approve_withdrawal())]
+		pub fn approve_withdrawal(origin: OriginFor<T>, lock_id: LockId) -> DispatchResult {
+			let manager = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&manager, Role::Manager)?;
+			let request =
+				WithdrawalRequests::<T>::get(lock_id).ok_or(Error::<T>::InvalidWithdrawalId)?;
+			Self::ensure_not_frozen(&request.user)?;
+
+			Self::settle_withdrawal(lock_id, &request, true)?;
+
+			let payout_reference =
+				T::Hashing::hash_of(&(b"bank/withdrawal", lock_id, &request.user, request.amount));
+			Self::deposit_event(Event::<T>::WithdrawalApproved {
+				lock_id,
+				user: request.user,
+				manager,
+				amount: request.amount,
+				payout_reference,
+			});
+			Ok(())
+		}
+
+		/// Reject a withdrawal request, and release the held funds to the user's "free".
+		///
+		/// Requires Manager.
+		#[pallet::call_index(54)]
+		#[pallet::weight(T::WeightInfo::reject_withdrawal())]
+		pub fn reject_withdrawal(origin: OriginFor<T>, lock_id: LockId) -> DispatchResult {
+			let manager = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&manager, Role::Manager)?;
+			let request =
+				WithdrawalRequests::<T>::get(lock_id).ok_or(Error::<T>::InvalidWithdrawalId)?;
+
+			Self::settle_withdrawal(lock_id, &request, false)?;
+
+			Self::deposit_event(Event::<T>::WithdrawalRejected {
+				lock_id,
+				user: request.user,
+				amount: request.amount,
+			});
+			Ok(())
+		}
+
+		/// Transfer `amount` of fund from the current user to another user, with a memo or a
+		/// reference such as an invoice number. The memo is recorded in the event and in both
+		/// ledgers.
+		#[pallet::call_index(55)]
+		#[pallet::weight(T::WeightInfo::transfer_with_memo())]
+		pub fn transfer_with_memo(
+			origin: OriginFor<T>,
+			to_user: T::AccountId,
+			#[pallet::compact] amount: T::Balance,
+			memo: Memo,
+		) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&id, Role::Customer)?;
+			T::RoleManager::ensure_role(&to_user, Role::Customer)?;
+
+			if amount < T::MinimumAmount::get() {
+				return Err(Error::<T>::AmountTooSmall.into());
+			}
+			Self::do_transfer(&id, &to_user, amount, Some(PaymentReference::Memo(memo)))?;
+			Self::charge_transfer_fee(&id, amount)
+		}
+
+		/// Mint some fund and deposit into user's account, with a reference such as the reference
+		/// of the incoming payment on an external fiat rail. The reference is recorded in the event
+		/// and in the user's ledger.
+		///
+		/// Requires Manager.
+		#[pallet::call_index(56)]
+		#[pallet::weight(T::WeightInfo::deposit_with_reference())]
+		pub fn deposit_with_reference(
+			origin: OriginFor<T>,
+			user: T::AccountId,
+			#[pallet::compact] amount: T::Balance,
+			reference: Memo,
+		) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&id, Role::Manager)?;
+
+			if amount < T::MinimumAmount::get() {
+				return Err(Error::<T>::AmountTooSmall.into());
+			}
+			Self::do_deposit(&user, amount, Some(PaymentReference::Memo(reference)))
+		}
+
+		/// Manager schedule an interest rate in basis point, to take effect at `effective_at`.
+		/// The rate must take effect after the notice period, and be within the bounds set by
+		/// governance. It replaces the rate already scheduled, if any.
+		///
+		/// Requires Manager.
+		#[pallet::call_index(57)]
+		#[pallet::weight(T::WeightInfo::schedule_interest_rate())]
+		pub fn schedule_interest_rate(
+			origin: OriginFor<T>,
+			interest_rate_bps: u32,
+			effective_at: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			T::RoleManager::ensure_role(&id, Role::Manager)?;
+			ensure!(
+				effective_at >=
+					frame_system::Pallet::<T>::current_block_number() +
+						T::InterestRateNoticePeriod::get(),
+				Error::<T>::InterestRateNoticeTooShort
+			);
+
+			Self::do_schedule_interest_rate(id, interest_rate_bps, effective_at)
+		}
+
+		/// A holder cancelled a joint transfer that expired before enough holders voted on it.
+		#[pallet::call_index(58)]
+		#[pallet::weight(T::WeightInfo::cancel_joint_transfer())]
+		pub fn cancel_joint_transfer(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+		) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			let transfer =
+				JointTransfers::<T>::get(proposal_id).ok_or(Error::<T>::InvalidJointTransferId)?;
+			Self::ensure_joint_holder(&transfer.joint_account, &id)?;
+			ensure!(
+				frame_system::Pallet::<T>::current_block_number() >= transfer.expiry,
+				Error::<T>::JointTransferNotExpired
+			);
+
+			JointTransfers::<T>::remove(proposal_id);
+			Self::deposit_event(Event::<T>::JointTransferCancelled { proposal_id, who: id });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> BasicAccounting<T::AccountId, T::Balance> for Pallet<T> {
+	fn deposit(user: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		Self::do_deposit(user, amount, None)
+	}
+
+	/// Withdrawals are made by managers, so they don't count towards the user's velocity limit.
+	/// Customers withdraw through `request_withdrawal`, which is limited.
+	fn withdraw(user: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		Self::burn(NATIVE_ASSET_ID, user, amount)?;
+		Self::record_ledger_entry(user, LedgerEntryKind::Withdrawal, amount, None);
+		Self::deposit_event(Event::<T>::Withdrew { user: user.clone(), amount });
+		Ok(())
+	}
+
+	fn transfer(from: &T::AccountId, to: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		Self::do_transfer(from, to, amount, None)
+	}
+
+	fn transfer_with_reason(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: T::Balance,
+		reason: TransferReason,
+	) -> DispatchResult {
+		Self::do_transfer(from, to, amount, Some(PaymentReference::Reason(reason)))
+	}
+
+	fn transfer_from(
+		spender: &T::AccountId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		let now = frame_system::Pallet::<T>::current_block_number();
+		Allowances::<T>::try_mutate_exists(from, spender, |maybe_allowance| {
+			let allowance = maybe_allowance.as_mut().ok_or(Error::<T>::AllowanceNotFound)?;
+			ensure!(
+				allowance.expiry.map_or(true, |expiry| now < expiry),
+				Error::<T>::AllowanceExpired
+			);
+			ensure!(allowance.amount >= amount, Error::<T>::AllowanceExceeded);
+
+			<Self as BasicAccounting<T::AccountId, T::Balance>>::transfer(from, to, amount)?;
+			allowance.amount -= amount;
+			if allowance.amount.is_zero() {
+				*maybe_allowance = None;
+			}
+			Ok::<_, DispatchError>(())
+		})?;
+		Self::deposit_event(Event::<T>::TransferredFrom {
+			spender: spender.clone(),
+			from: from.clone(),
+			to: to.clone(),
+			amount,
+		});
+		Ok(())
+	}
+
+	fn free_balance(user: &T::AccountId) -> T::Balance {
+		Accounts::<T>::get(user).free
+	}
+}
+
+impl<T: Config> Stakable<T::AccountId, T::Balance> for Pallet<T> {
+	/// Stake funds from free to reserved
+	fn stake_funds(user: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		Self::with_velocity_limit(user, amount, || Self::stake(NATIVE_ASSET_ID, user, amount))
+	}
+
+	/// Redeem funds from reserved to free after a certain time
+	fn redeem_funds(user: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		Self::redeem(NATIVE_ASSET_ID, user, amount)
+	}
 
-		/// Auditor or client unlocked some fund from an account's "locked" to "free".
-		Unlocked { user: T::AccountId, amount: T::Balance, reason: UnlockReason },
+	fn staked(user: &T::AccountId) -> T::Balance {
+		Accounts::<T>::get(user).reserved
+	}
+}
 
-		/// Manager set the interest rate.
-		InterestRateSet {
-			manager: T::AccountId,
-			old_interest_rate: Perbill,
-			new_interest_rate: Perbill,
-		},
+impl<T: Config> MultiAssetAccounting<T::AccountId, AssetId, T::Balance> for Pallet<T> {
+	fn deposit(asset_id: AssetId, user: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		if asset_id == NATIVE_ASSET_ID {
+			return <Self as BasicAccounting<T::AccountId, T::Balance>>::deposit(user, amount);
+		}
+		Self::ensure_asset_exists(asset_id)?;
+		Self::mint(asset_id, user, amount)?;
+		Self::deposit_event(Event::<T>::AssetDeposited { asset_id, user: user.clone(), amount });
+		Ok(())
+	}
 
-		/// Manager paid total interest.
-		InterestPayed { interest_rate: Perbill, total_interest_payed: T::Balance },
+	fn withdraw(asset_id: AssetId, user: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		if asset_id == NATIVE_ASSET_ID {
+			return <Self as BasicAccounting<T::AccountId, T::Balance>>::withdraw(user, amount);
+		}
+		Self::ensure_asset_exists(asset_id)?;
+		Self::burn(asset_id, user, amount)?;
+		Self::deposit_event(Event::<T>::AssetWithdrew { asset_id, user: user.clone(), amount });
+		Ok(())
+	}
 
-		/// TreasuryAccount rotated.
-		TreasuryAccountRotated { old: Option<T::AccountId>, new: T::AccountId },
+	fn transfer(
+		asset_id: AssetId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		if asset_id == NATIVE_ASSET_ID {
+			return <Self as BasicAccounting<T::AccountId, T::Balance>>::transfer(from, to, amount);
+		}
+		Self::ensure_asset_exists(asset_id)?;
+		Self::move_funds(asset_id, from, to, amount)?;
+		Self::deposit_event(Event::<T>::AssetTransferred {
+			asset_id,
+			from: from.clone(),
+			to: to.clone(),
+			amount,
+		});
+		Ok(())
 	}
 
-	/// The balance of a token type under an account.
-	#[pallet::storage]
-	#[pallet::getter(fn accounts)]
-	pub type Accounts<T: Config> =
-		StorageMap<_, Blake2_128Concat, T::AccountId, AccountData<T::Balance>, ValueQuery>;
+	fn free_balance(asset_id: AssetId, user: &T::AccountId) -> T::Balance {
+		Self::asset_account(asset_id, user).free
+	}
+}
 
-	/// Storage item to track the total issuance of the token.
-	#[pallet::storage]
-	#[pallet::getter(fn total_issuance)]
-	pub type TotalIssuance<T: Config> = StorageValue<_, T::Balance, ValueQuery>;
+impl<T: Config> MultiAssetStakable<T::AccountId, AssetId, T::Balance> for Pallet<T> {
+	/// Stake funds of an asset from free to reserved
+	fn stake_funds(asset_id: AssetId, user: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		Self::ensure_asset_exists(asset_id)?;
+		Self::stake(asset_id, user, amount)
+	}
 
-	/// Stores the user ID that will have their fund unlocked at a block.
-	#[pallet::storage]
-	pub type AccountWithUnlockedFund<T: Config> =
-		StorageMap<_, Blake2_128Concat, BlockNumberFor<T>, Vec<(T::AccountId, LockId)>, ValueQuery>;
+	/// Redeem funds of an asset from reserved to free after a certain time
+	fn redeem_funds(asset_id: AssetId, user: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		Self::ensure_asset_exists(asset_id)?;
+		Self::redeem(asset_id, user, amount)
+	}
 
-	/// Stores the next locked ID should be.
-	#[pallet::storage]
-	pub type NextLockId<T: Config> = StorageValue<_, LockId, ValueQuery>;
+	fn staked(asset_id: AssetId, user: &T::AccountId) -> T::Balance {
+		Self::asset_account(asset_id, user).reserved
+	}
+}
 
-	/// Stores the interest rate.
-	#[pallet::storage]
-	#[pallet::getter(fn interest_rate)]
-	pub type InterestRate<T: Config> = StorageValue<_, Perbill, ValueQuery>;
+impl<T: Config> GetTreasury<T::AccountId> for Pallet<T> {
+	fn treasury() -> Result<T::AccountId, DispatchError> {
+		TreasuryAccount::<T>::get().ok_or(Error::<T>::TreasuryAccountNotSet.into())
+	}
+}
 
-	/// Stores the treasury account.
-	#[pallet::storage]
-	#[pallet::getter(fn treasury_account)]
-	pub type TreasuryAccount<T: Config> = StorageValue<_, T::AccountId>;
+/// Pays the transaction fees and tips handed over into the treasury account. They are burned if
+/// the treasury account is not set.
+pub struct FeesToTreasury<T>(PhantomData<T>);
 
-	#[pallet::genesis_config]
-	#[derive(frame_support::DefaultNoBound)]
-	pub struct GenesisConfig<T: Config> {
-		pub balances: Vec<(T::AccountId, T::Balance, T::Balance)>,
-		pub treasury: Option<T::AccountId>,
+impl<T: Config> OnUnbalanced<fungible::Credit<T::AccountId, Pallet<T>>> for FeesToTreasury<T> {
+	fn on_nonzero_unbalanced(amount: fungible::Credit<T::AccountId, Pallet<T>>) {
+		let treasury = match Pallet::<T>::treasury() {
+			Ok(treasury) => treasury,
+			Err(_) => return,
+		};
+		let fee = amount.peek();
+		if <Pallet<T> as fungible::Balanced<T::AccountId>>::resolve(&treasury, amount).is_ok() {
+			T::TreasuryIncome::record_income(IncomeSource::TransactionFee, fee);
+		}
 	}
+}
 
-	#[pallet::genesis_build]
-	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
-		fn build(&self) {
-			let total: T::Balance = self
-				.balances
-				.iter()
-				.map(|(account_id, initial_free, initial_reserved)| {
-					assert!(
-                    	*initial_free + *initial_reserved >= T::ExistentialDeposit::get(),
-                    	"the balance of any account should always be more than existential deposit.",
-                    );
-					let _ = T::RoleManager::register_role(account_id, Role::Customer);
-					Accounts::<T>::mutate(account_id, |account_data| {
-						account_data.free = *initial_free;
-						account_data.reserved = *initial_reserved;
-					});
-					*initial_free + *initial_reserved
-				})
-				.sum();
-			TotalIssuance::<T>::set(total);
-			TreasuryAccount::<T>::set(self.treasury.clone());
+impl<T: Config> fungible::Inspect<T::AccountId> for Pallet<T> {
+	type Balance = T::Balance;
+
+	fn total_issuance() -> T::Balance {
+		TotalIssuance::<T>::get()
+	}
+
+	fn minimum_balance() -> T::Balance {
+		T::ExistentialDeposit::get()
+	}
+
+	fn total_balance(who: &T::AccountId) -> T::Balance {
+		Accounts::<T>::get(who).total()
+	}
+
+	fn balance(who: &T::AccountId) -> T::Balance {
+		Accounts::<T>::get(who).free
+	}
+
+	fn reducible_balance(
+		who: &T::AccountId,
+		preservation: Preservation,
+		force: Fortitude,
+	) -> T::Balance {
+		if force == Fortitude::Polite && FrozenAccounts::<T>::contains_key(who) {
+			return Zero::zero();
+		}
+		let account = Accounts::<T>::get(who);
+		match preservation {
+			Preservation::Expendable => account.free,
+			// Keep enough funds for the account to stay above the existential deposit.
+			Preservation::Protect | Preservation::Preserve => {
+				let others = account.total().saturating_sub(account.free);
+				account.free.saturating_sub(T::ExistentialDeposit::get().saturating_sub(others))
+			},
+		}
+	}
+
+	fn can_deposit(
+		who: &T::AccountId,
+		amount: T::Balance,
+		provenance: Provenance,
+	) -> DepositConsequence {
+		if amount.is_zero() {
+			return DepositConsequence::Success;
+		}
+		if provenance == Provenance::Minted &&
+			TotalIssuance::<T>::get().checked_add(&amount).is_none()
+		{
+			return DepositConsequence::Overflow;
+		}
+		match Accounts::<T>::get(who).total().checked_add(&amount) {
+			None => DepositConsequence::Overflow,
+			Some(total) if total < T::ExistentialDeposit::get() => DepositConsequence::BelowMinimum,
+			Some(_) => DepositConsequence::Success,
+		}
+	}
+
+	fn can_withdraw(who: &T::AccountId, amount: T::Balance) -> WithdrawConsequence<T::Balance> {
+		if amount.is_zero() {
+			return WithdrawConsequence::Success;
+		}
+		if TotalIssuance::<T>::get() < amount {
+			return WithdrawConsequence::Underflow;
+		}
+		if FrozenAccounts::<T>::contains_key(who) {
+			return WithdrawConsequence::Frozen;
+		}
+		let account = Accounts::<T>::get(who);
+		let free = match account.free.checked_sub(&amount) {
+			Some(free) => free,
+			None => return WithdrawConsequence::BalanceLow,
+		};
+		let total = account.total().saturating_sub(amount);
+		if !total.is_zero() && total < T::ExistentialDeposit::get() {
+			// The account will be reaped, and its remaining free funds are lost.
+			WithdrawConsequence::ReducedToZero(free)
+		} else {
+			WithdrawConsequence::Success
+		}
+	}
+}
+
+impl<T: Config> fungible::Unbalanced<T::AccountId> for Pallet<T> {
+	fn handle_dust(dust: fungible::Dust<T::AccountId, Self>) {
+		TotalIssuance::<T>::mutate(|total| *total = total.saturating_sub(dust.0));
+	}
+
+	fn write_balance(
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> Result<Option<T::Balance>, DispatchError> {
+		// Accounts below the existential deposit are reaped into the treasury, so no dust is
+		// left here.
+		Self::mutate_account(NATIVE_ASSET_ID, who, |account| account.free = amount);
+		Ok(None)
+	}
+
+	fn set_total_issuance(amount: T::Balance) {
+		TotalIssuance::<T>::put(amount);
+	}
+}
+
+impl<T: Config> fungible::Mutate<T::AccountId> for Pallet<T> {
+	fn done_mint_into(who: &T::AccountId, amount: T::Balance) {
+		Self::record_ledger_entry(who, LedgerEntryKind::Deposit, amount, None);
+		Self::deposit_event(Event::<T>::Deposited { user: who.clone(), amount });
+	}
+
+	fn done_burn_from(who: &T::AccountId, amount: T::Balance) {
+		Self::record_ledger_entry(who, LedgerEntryKind::Withdrawal, amount, None);
+		Self::deposit_event(Event::<T>::Withdrew { user: who.clone(), amount });
+	}
+
+	fn done_transfer(source: &T::AccountId, dest: &T::AccountId, amount: T::Balance) {
+		Self::record_ledger_entry(source, LedgerEntryKind::TransferOut, amount, Some(dest.clone()));
+		Self::record_ledger_entry(dest, LedgerEntryKind::TransferIn, amount, Some(source.clone()));
+		Self::deposit_event(Event::Transferred { from: source.clone(), to: dest.clone(), amount });
+	}
+}
+
+impl<T: Config> fungible::Balanced<T::AccountId> for Pallet<T> {
+	type OnDropDebt = fungible::IncreaseIssuance<T::AccountId, Self>;
+	type OnDropCredit = fungible::DecreaseIssuance<T::AccountId, Self>;
+}
+
+impl<T: Config> fungible::InspectHold<T::AccountId> for Pallet<T> {
+	type Reason = T::RuntimeHoldReason;
+
+	fn total_balance_on_hold(who: &T::AccountId) -> T::Balance {
+		Accounts::<T>::get(who)
+			.locked
+			.iter()
+			.filter(|lock| lock.reason == LockReason::Hold)
+			.map(|lock| lock.amount)
+			.sum()
+	}
+
+	fn reducible_total_balance_on_hold(who: &T::AccountId, force: Fortitude) -> T::Balance {
+		if force == Fortitude::Polite && FrozenAccounts::<T>::contains_key(who) {
+			return Zero::zero();
+		}
+		<Self as fungible::InspectHold<T::AccountId>>::total_balance_on_hold(who)
+	}
+
+	fn balance_on_hold(reason: &T::RuntimeHoldReason, who: &T::AccountId) -> T::Balance {
+		Holds::<T>::get(who, reason)
+	}
+
+	fn hold_available(_reason: &T::RuntimeHoldReason, _who: &T::AccountId) -> bool {
+		true
+	}
+}
+
+impl<T: Config> fungible::UnbalancedHold<T::AccountId> for Pallet<T> {
+	fn set_balance_on_hold(
+		reason: &T::RuntimeHoldReason,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		let held = Holds::<T>::get(who, reason);
+		Self::try_mutate_account(NATIVE_ASSET_ID, who, |account| -> DispatchResult {
+			match account.locked.iter().position(|lock| lock.reason == LockReason::Hold) {
+				Some(index) => {
+					let lock = &mut account.locked[index];
+					lock.amount = lock.amount.saturating_sub(held).saturating_add(amount);
+					if lock.amount.is_zero() {
+						account.locked.remove(index);
+					}
+				},
+				None =>
+					if !amount.is_zero() {
+						account
+							.locked
+							.try_push(LockedFund {
+								id: Self::next_lock_id(),
+								amount,
+								reason: LockReason::Hold,
+							})
+							.map_err(|_| Error::<T>::TooManyLocks)?;
+					},
+			}
+			Ok(())
+		})?;
+		if amount.is_zero() {
+			Holds::<T>::remove(who, reason);
+		} else {
+			Holds::<T>::insert(who, reason, amount);
+		}
+		Ok(())
+	}
+}
+
+impl<T: Config> fungible::MutateHold<T::AccountId> for Pallet<T> {}
+
+impl<T: Config> fungible::BalancedHold<T::AccountId> for Pallet<T> {}
+
+impl<T: Config> Pallet<T> {
+	/// Burn some fund from a user's account.
+	fn burn(asset_id: AssetId, user: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		T::RoleManager::ensure_role(user, Role::Customer)?;
+		Self::ensure_not_frozen(user)?;
+		Self::try_mutate_account(asset_id, user, |balance| -> DispatchResult {
+			if balance.free >= amount {
+				balance.free -= amount;
+				Ok(())
+			} else {
+				Err(Error::<T>::InsufficientBalance.into())
+			}
+		})?;
+		Self::mutate_issuance(asset_id, |total| {
+			*total = total.saturating_sub(amount);
+		});
+		Ok(())
+	}
+
+	/// Mint some fund into a user's account.
+	fn mint(asset_id: AssetId, user: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		T::RoleManager::ensure_role(user, Role::Customer)?;
+
+		let repaid = Self::mutate_account(asset_id, user, |balance| balance.credit(amount));
+		Self::mutate_issuance(asset_id, |total| {
+			*total = total.saturating_add(amount).saturating_sub(repaid);
+		});
+		Self::deposit_repaid_event(user, repaid);
+		Ok(())
+	}
+
+	/// Move some fund from a user's "free" to another user's "free". Native funds above "free"
+	/// are drawn on the sender's overdraft facility, and repay the recipient's overdraft debt
+	/// first.
+	fn move_funds(
+		asset_id: AssetId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		Self::ensure_not_frozen(from)?;
+		let limit = if asset_id == NATIVE_ASSET_ID {
+			OverdraftLimits::<T>::get(from)
+		} else {
+			Zero::zero()
+		};
+		let drawn = Self::try_mutate_account(asset_id, from, |balance| {
+			let drawn = amount.saturating_sub(balance.free);
+			if !drawn.is_zero() {
+				ensure!(!limit.is_zero(), Error::<T>::InsufficientBalance);
+				ensure!(
+					balance.debt.saturating_add(drawn) <= limit,
+					Error::<T>::OverdraftLimitExceeded
+				);
+			}
+			balance.free = balance.free.saturating_sub(amount);
+			balance.debt = balance.debt.saturating_add(drawn);
+			Ok(drawn)
+		})?;
+		let repaid = Self::mutate_account(asset_id, to, |balance| balance.credit(amount));
+
+		// Overdrawn funds are minted, and repaid debts are burned.
+		Self::mutate_issuance(asset_id, |total| {
+			*total = total.saturating_add(drawn).saturating_sub(repaid);
+		});
+		if !drawn.is_zero() {
+			Self::deposit_event(Event::<T>::OverdraftDrawn { user: from.clone(), amount: drawn });
+		}
+		Self::deposit_repaid_event(to, repaid);
+		Ok(())
+	}
+
+	/// Ensure the account is not frozen, so it can move funds out.
+	fn ensure_not_frozen(who: &T::AccountId) -> DispatchResult {
+		ensure!(!FrozenAccounts::<T>::contains_key(who), Error::<T>::AccountFrozen);
+		Ok(())
+	}
+
+	/// Deposit the `OverdraftRepaid` event if some debt is repaid.
+	fn deposit_repaid_event(user: &T::AccountId, repaid: T::Balance) {
+		if !repaid.is_zero() {
+			Self::deposit_event(Event::<T>::OverdraftRepaid { user: user.clone(), amount: repaid });
+		}
+	}
+
+	/// Charge the queued overdraft interest to at most `max_accounts` accounts granted an
+	/// overdraft facility, resuming from the last account charged. Only the accounts granted an
+	/// overdraft facility can be in debt.
+	fn charge_overdraft_interest(max_accounts: u32) {
+		let mut queue = OverdraftChargeQueue::<T>::get();
+		if queue.is_empty() {
+			return;
+		}
+		// The interest is charged once the treasury is set.
+		let treasury = match Self::treasury() {
+			Ok(treasury) => treasury,
+			Err(_) => return,
+		};
+
+		let mut budget = max_accounts;
+		while let Some(charge) = queue.first_mut() {
+			if budget.is_zero() {
+				break;
+			}
+
+			let mut account_ids = match &charge.last_account {
+				Some(last_account) => OverdraftLimits::<T>::iter_keys_from(
+					OverdraftLimits::<T>::hashed_key_for(last_account),
+				),
+				None => OverdraftLimits::<T>::iter_keys(),
+			};
+			let batch = account_ids.by_ref().take(budget as usize).collect::<Vec<_>>();
+			let finished = account_ids.next().is_none();
+			budget = budget.saturating_sub(batch.len() as u32);
+
+			batch.iter().for_each(|who| Self::charge_overdraft(who, &treasury, charge.rate));
+			if let Some(who) = batch.last() {
+				charge.last_account = Some(who.clone());
+			}
+			if finished {
+				queue.remove(0);
+			}
+		}
+		OverdraftChargeQueue::<T>::put(queue);
+	}
+
+	/// Add the overdraft interest of a payout period at `rate` to the debt of an account, and pay
+	/// it to the treasury.
+	fn charge_overdraft(who: &T::AccountId, treasury: &T::AccountId, rate: FixedU128) {
+		let amount = rate.saturating_mul_int(Accounts::<T>::get(who).debt);
+		if amount.is_zero() {
+			return;
+		}
+
+		Self::mutate_account(NATIVE_ASSET_ID, who, |account| {
+			account.debt = account.debt.saturating_add(amount);
+		});
+		Self::mutate_account(NATIVE_ASSET_ID, treasury, |account| {
+			account.free = account.free.saturating_add(amount);
+		});
+		TotalIssuance::<T>::mutate(|total| {
+			*total = total.saturating_add(amount);
+		});
+		T::TreasuryIncome::record_income(IncomeSource::Interest, amount);
+		Self::deposit_event(Event::<T>::OverdraftInterestCharged { user: who.clone(), amount });
+	}
+
+	/// Lock funds from "free", they are moved to "reserved" after the stake period.
+	fn stake(asset_id: AssetId, user: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		T::RoleManager::ensure_role(user, Role::Customer)?;
+		Self::ensure_not_frozen(user)?;
+		ensure!(amount >= Self::minimum_amount(asset_id)?, Error::<T>::AmountTooSmall);
+		Self::try_mutate_account(asset_id, user, |account| -> DispatchResult {
+			ensure!(account.free >= amount, Error::<T>::InsufficientBalance);
+			account.free -= amount;
+			let new_locked_fund =
+				LockedFund { id: Self::next_lock_id(), amount, reason: LockReason::Stake };
+			account.locked.try_push(new_locked_fund).map_err(|_| Error::<T>::TooManyLocks)?;
+
+			Self::schedule_unlock(asset_id, user, new_locked_fund.id, T::StakePeriod::get())
+		})?;
+		Self::deposit_locked_event(
+			asset_id,
+			user,
+			amount,
+			T::StakePeriod::get(),
+			LockReason::Stake,
+		);
+		Ok(())
+	}
+
+	/// Lock funds from "reserved", they are moved to "free" after the redeem period.
+	fn redeem(asset_id: AssetId, user: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		T::RoleManager::ensure_role(user, Role::Customer)?;
+		Self::ensure_not_frozen(user)?;
+		ensure!(amount >= Self::minimum_amount(asset_id)?, Error::<T>::AmountTooSmall);
+
+		// Add new locked funds to user's Account Data
+		Self::try_mutate_account(asset_id, user, |account| -> DispatchResult {
+			ensure!(account.reserved >= amount, Error::<T>::InsufficientBalance);
+			account.reserved -= amount;
+			let new_locked_fund =
+				LockedFund { id: Self::next_lock_id(), amount, reason: LockReason::Redeem };
+			account.locked.try_push(new_locked_fund).map_err(|_| Error::<T>::TooManyLocks)?;
+
+			Self::schedule_unlock(asset_id, user, new_locked_fund.id, T::RedeemPeriod::get())
+		})?;
+		Self::deposit_locked_event(
+			asset_id,
+			user,
+			amount,
+			T::RedeemPeriod::get(),
+			LockReason::Redeem,
+		);
+		Ok(())
+	}
+
+	/// Add the lock to the AccountWithUnlockedFunds, so it is unlocked after `length` blocks.
+	fn schedule_unlock(
+		asset_id: AssetId,
+		user: &T::AccountId,
+		lock_id: LockId,
+		length: BlockNumberFor<T>,
+	) -> DispatchResult {
+		let unlock = frame_system::Pallet::<T>::current_block_number() + length;
+		AccountWithUnlockedFund::<T>::try_append(unlock, (user.clone(), lock_id))
+			.map_err(|_| Error::<T>::TooManyScheduled)?;
+		LockExpiries::<T>::insert(user, lock_id, unlock);
+		if asset_id != NATIVE_ASSET_ID {
+			AssetLocks::<T>::insert(lock_id, asset_id);
+		}
+		Ok(())
+	}
+
+	/// Remove the lock from the AccountWithUnlockedFunds at its stored expiry, e.g. if it is
+	/// unlocked early.
+	fn unschedule_unlock(user: &T::AccountId, lock_id: LockId) {
+		if let Some(unlock) = LockExpiries::<T>::take(user, lock_id) {
+			AccountWithUnlockedFund::<T>::mutate_exists(unlock, |maybe_accounts| {
+				if let Some(accounts) = maybe_accounts {
+					accounts.retain(|(who, id)| !(who == user && *id == lock_id));
+					if accounts.is_empty() {
+						*maybe_accounts = None;
+					}
+				}
+			});
+		}
+	}
+
+	/// Deposit the `Locked` event, or the `AssetLocked` event if the asset is not native.
+	fn deposit_locked_event(
+		asset_id: AssetId,
+		user: &T::AccountId,
+		amount: T::Balance,
+		length: BlockNumberFor<T>,
+		reason: LockReason,
+	) {
+		if asset_id == NATIVE_ASSET_ID {
+			Self::record_ledger_entry(user, LedgerEntryKind::Lock, amount, None);
+			Self::deposit_event(Event::<T>::Locked { user: user.clone(), amount, length, reason });
+		} else {
+			Self::deposit_event(Event::<T>::AssetLocked {
+				asset_id,
+				user: user.clone(),
+				amount,
+				length,
+				reason,
+			});
+		}
+	}
+
+	/// Returns the account data of a user for the given asset.
+	pub fn asset_account(asset_id: AssetId, who: &T::AccountId) -> AccountDataOf<T> {
+		if asset_id == NATIVE_ASSET_ID {
+			Accounts::<T>::get(who)
+		} else {
+			AssetAccounts::<T>::get(who, asset_id)
+		}
+	}
+
+	/// Mutate the account data of a user for the given asset. The native currency is stored in
+	/// `Accounts`, all other assets are stored in `AssetAccounts`. The accrued interest is
+	/// realised before the native currency is mutated.
+	fn mutate_account<R>(
+		asset_id: AssetId,
+		who: &T::AccountId,
+		f: impl FnOnce(&mut AccountDataOf<T>) -> R,
+	) -> R {
+		let f = |account: &mut AccountDataOf<T>| {
+			let result = f(account);
+			Self::note_dust_candidate(asset_id, who, account);
+			result
+		};
+		if asset_id == NATIVE_ASSET_ID {
+			Self::accrue_interest(who);
+			let existed = Accounts::<T>::contains_key(who);
+			let result = Accounts::<T>::mutate(who, f);
+			Self::provide_account(who, existed);
+			result
+		} else {
+			AssetAccounts::<T>::mutate(who, asset_id, f)
+		}
+	}
+
+	/// Try to mutate the account data of a user for the given asset. Nothing is changed if `f`
+	/// returns an error.
+	fn try_mutate_account<R>(
+		asset_id: AssetId,
+		who: &T::AccountId,
+		f: impl FnOnce(&mut AccountDataOf<T>) -> Result<R, DispatchError>,
+	) -> Result<R, DispatchError> {
+		let f = |account: &mut AccountDataOf<T>| {
+			let result = f(account)?;
+			Self::note_dust_candidate(asset_id, who, account);
+			Ok(result)
+		};
+		if asset_id == NATIVE_ASSET_ID {
+			Self::accrue_interest(who);
+			let existed = Accounts::<T>::contains_key(who);
+			let result = Accounts::<T>::try_mutate(who, f);
+			Self::provide_account(who, existed);
+			result
+		} else {
+			AssetAccounts::<T>::try_mutate(who, asset_id, f)
+		}
+	}
+
+	/// Give a new native account a provider reference, so it can send transactions. The reference
+	/// is dropped when the account is reaped.
+	fn provide_account(who: &T::AccountId, existed: bool) {
+		if !existed && Accounts::<T>::contains_key(who) {
+			frame_system::Pallet::<T>::inc_providers(who);
 		}
 	}
 
-	#[pallet::pallet]
-	#[pallet::without_storage_info]
-	pub struct Pallet<T>(_);
+	/// Returns the interest index at a block, including the interest accrued since the index was
+	/// last updated.
+	pub fn interest_index_at(block_number: BlockNumberFor<T>) -> FixedU128 {
+		let elapsed = block_number.saturating_sub(InterestIndexUpdatedAt::<T>::get());
+		InterestIndex::<T>::get()
+			.saturating_mul(FixedU128::one().saturating_add(Self::interest_growth(elapsed)))
+	}
 
-	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn integrity_test() {
-			// Check if the minimum deposit is greater than or equal to the existential deposit
-			assert!(T::MinimumAmount::get() >= T::ExistentialDeposit::get());
-			assert!(T::InterestPayoutPeriod::get() <= T::StakePeriod::get());
-			assert!(T::InterestPayoutPeriod::get() <= T::RedeemPeriod::get());
-			assert!(!T::TotalBlocksPerYear::get().is_zero());
+	/// Returns the interest earned per unit of fund over `length` blocks, at the current interest
+	/// rate without compounding.
+	fn interest_growth(length: BlockNumberFor<T>) -> FixedU128 {
+		// scaled_ir = ir_pa / blocks_pa * length
+		FixedU128::from_perbill(InterestRate::<T>::get()).saturating_mul(
+			FixedU128::saturating_from_rational(length, T::TotalBlocksPerYear::get()),
+		)
+	}
+
+	/// Store the interest index at a block, so interest compounds from this block onwards.
+	fn update_interest_index(block_number: BlockNumberFor<T>) {
+		if block_number > InterestIndexUpdatedAt::<T>::get() {
+			InterestIndex::<T>::set(Self::interest_index_at(block_number));
+			InterestIndexUpdatedAt::<T>::set(block_number);
 		}
+	}
 
-		fn on_finalize(block_number: BlockNumberFor<T>) {
-			// Reap accounts below ED
-			Self::reap_accounts();
+	/// Returns the interest accrued on a user's "reserved" since the last snapshot.
+	fn pending_interest(who: &T::AccountId, index: FixedU128) -> T::Balance {
+		let snapshot = InterestSnapshot::<T>::get(who);
+		index
+			.checked_div(&snapshot)
+			.unwrap_or_else(FixedU128::one)
+			.saturating_sub(FixedU128::one())
+			.saturating_mul_int(Accounts::<T>::get(who).reserved)
+	}
 
-			// Unlock funds that are due.
-			AccountWithUnlockedFund::<T>::take(block_number).into_iter().for_each(
-				|(user, lock_id)| {
-					// Ignore the unlock result - locks can be unlocked early by other means.
-					let _ = Self::unlock(&user, lock_id, UnlockReason::Expired);
-				},
-			);
+	/// Realise the interest accrued on a user's "reserved" since the last snapshot, and update the
+	/// snapshot to the current interest index. Returns the interest realised.
+	fn accrue_interest(who: &T::AccountId) -> T::Balance {
+		let index = Self::interest_index_at(frame_system::Pallet::<T>::current_block_number());
+		if InterestSnapshot::<T>::get(who) == index {
+			return Zero::zero();
+		}
 
-			// Pay interest rate.
+		let amount = Self::pending_interest(who, index);
+		if !amount.is_zero() {
+			Accounts::<T>::mutate(who, |account_data| {
+				account_data.reserved = account_data.reserved.saturating_add(amount);
+			});
+			TotalIssuance::<T>::mutate(|total| {
+				*total = total.saturating_add(amount);
+			});
+			Self::record_ledger_entry(who, LedgerEntryKind::Interest, amount, None);
+			Self::deposit_event(Event::<T>::InterestAccrued { user: who.clone(), amount });
+		}
+		InterestSnapshot::<T>::insert(who, index);
+		amount
+	}
 
-			// check if we should payout this block
-			if (block_number % T::InterestPayoutPeriod::get()).is_zero() {
-				// calculate the scaled interest rate
-				// scaled_ir = ir_pa / blocks_pa * blocks_per_payout
-				let interest_rate = InterestRate::<T>::get();
-				let ir_per_payout = interest_rate *
-					Perbill::from_rational(
-						T::InterestPayoutPeriod::get(),
-						T::TotalBlocksPerYear::get(),
-					);
-				// Pay out interest for all accounts, and tally the sum
-				let total_interest: T::Balance = Accounts::<T>::iter_keys()
-					.map(|account_id| {
-						Accounts::<T>::mutate(account_id, |account_data| {
-							let interest = ir_per_payout * account_data.reserved;
-							account_data.reserved = account_data.reserved.saturating_add(interest);
-							interest
-						})
-					})
-					.sum();
-				TotalIssuance::<T>::mutate(|total| {
-					*total = total.saturating_add(total_interest);
-				});
+	/// Realise the interest of the queued payouts for at most `max_accounts` accounts, resuming
+	/// from the last account paid. `InterestPayed` is emitted once a payout has paid all accounts.
+	/// Accounts created during a payout are paid only if they are after the last account paid.
+	fn pay_interest(max_accounts: u32) {
+		let mut queue = InterestPayoutQueue::<T>::get();
+		if queue.is_empty() {
+			return;
+		}
+
+		let mut budget = max_accounts;
+		while let Some(payout) = queue.first_mut() {
+			if budget.is_zero() {
+				break;
+			}
+
+			let mut account_ids = match &payout.last_account {
+				Some(last_account) =>
+					Accounts::<T>::iter_keys_from(Accounts::<T>::hashed_key_for(last_account)),
+				None => Accounts::<T>::iter_keys(),
+			};
+			let batch = account_ids.by_ref().take(budget as usize).collect::<Vec<_>>();
+			let finished = account_ids.next().is_none();
+			budget = budget.saturating_sub(batch.len() as u32);
+
+			// Realise the interest of the batch, and tally the sum
+			let interest: T::Balance =
+				batch.iter().map(|account_id| Self::accrue_interest(account_id)).sum();
+			payout.total_interest_payed = payout.total_interest_payed.saturating_add(interest);
+			if let Some(account_id) = batch.last() {
+				payout.last_account = Some(account_id.clone());
+			}
+
+			if finished {
+				let payout = queue.remove(0);
 				Self::deposit_event(Event::<T>::InterestPayed {
-					interest_rate,
-					total_interest_payed: total_interest,
+					interest_rate: payout.interest_rate,
+					total_interest_payed: payout.total_interest_payed,
 				});
 			}
 		}
+		InterestPayoutQueue::<T>::put(queue);
 	}
 
-	#[pallet::call]
-	impl<T: Config> Pallet<T> {
-		/// Mint some fund and deposit into user's account.
-		///
-		/// Requires Manager.
-		#[pallet::call_index(0)]
-		#[pallet::weight(T::WeightInfo::deposit())]
-		pub fn deposit(
-			origin: OriginFor<T>,
-			user: T::AccountId,
-			#[pallet::compact] amount: T::Balance,
-		) -> DispatchResult {
-			let id = ensure_signed(origin)?;
-			T::RoleManager::ensure_role(&id, Role::Manager)?;
+	/// Returns the account data of a user, including the accrued interest that is not realised
+	/// yet.
+	pub fn account_data(who: &T::AccountId) -> AccountDataOf<T> {
+		let index = Self::interest_index_at(frame_system::Pallet::<T>::current_block_number());
+		let mut account_data = Accounts::<T>::get(who);
+		account_data.reserved =
+			account_data.reserved.saturating_add(Self::pending_interest(who, index));
+		account_data
+	}
 
-			if amount < T::MinimumAmount::get() {
-				return Err(Error::<T>::AmountTooSmall.into());
-			}
-			<Self as BasicAccounting<T::AccountId, T::Balance>>::deposit(&user, amount)
-		}
+	/// Returns true if the account holds a balance below the existential deposit, including an
+	/// empty balance, and has no debt.
+	fn is_dust(asset_id: AssetId, account: &AccountDataOf<T>) -> bool {
+		// Accounts in overdraft debt are never reaped.
+		let total = account.total();
+		account.debt.is_zero() && Self::existential_deposit(asset_id).map_or(false, |ed| total < ed)
+	}
 
-		/// Withdraw from user's account and the withdrew funds are burned.
-		///
-		/// Requires Manager.
-		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::withdraw())]
-		pub fn withdraw(
-			origin: OriginFor<T>,
-			user: T::AccountId,
-			#[pallet::compact] amount: T::Balance,
-		) -> DispatchResult {
-			let id = ensure_signed(origin)?;
-			T::RoleManager::ensure_role(&id, Role::Manager)?;
+	/// Add the account to the dust candidates if its balance is below the existential deposit.
+	fn note_dust_candidate(asset_id: AssetId, who: &T::AccountId, account: &AccountDataOf<T>) {
+		if Self::is_dust(asset_id, account) {
+			DustCandidates::<T>::insert(who, asset_id, ());
+		}
+	}
 
-			if amount < T::MinimumAmount::get() {
-				return Err(Error::<T>::AmountTooSmall.into());
-			}
-			<Self as BasicAccounting<T::AccountId, T::Balance>>::withdraw(&user, amount)
+	/// Mutate the total issuance of the given asset.
+	fn mutate_issuance(asset_id: AssetId, f: impl FnOnce(&mut T::Balance)) {
+		if asset_id == NATIVE_ASSET_ID {
+			TotalIssuance::<T>::mutate(f)
+		} else {
+			AssetIssuance::<T>::mutate(asset_id, f)
 		}
+	}
 
-		/// Transfer `amount` of fund from the current user to another user.
-		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::transfer())]
-		pub fn transfer(
-			origin: OriginFor<T>,
-			to_user: T::AccountId,
-			#[pallet::compact] amount: T::Balance,
-		) -> DispatchResult {
-			let id = ensure_signed(origin)?;
-			T::RoleManager::ensure_role(&id, Role::Customer)?;
-			T::RoleManager::ensure_role(&to_user, Role::Customer)?;
+	/// Ensure the asset is the native currency or a registered asset.
+	fn ensure_asset_exists(asset_id: AssetId) -> DispatchResult {
+		ensure!(
+			asset_id == NATIVE_ASSET_ID || Assets::<T>::contains_key(asset_id),
+			Error::<T>::AssetNotFound
+		);
+		Ok(())
+	}
 
-			if amount < T::MinimumAmount::get() {
-				return Err(Error::<T>::AmountTooSmall.into());
-			}
-			<Self as BasicAccounting<T::AccountId, T::Balance>>::transfer(&id, &to_user, amount)
+	/// Returns the existential deposit of the given asset.
+	pub fn existential_deposit(asset_id: AssetId) -> Result<T::Balance, DispatchError> {
+		if asset_id == NATIVE_ASSET_ID {
+			Ok(T::ExistentialDeposit::get())
+		} else {
+			Assets::<T>::get(asset_id)
+				.map(|metadata| metadata.existential_deposit)
+				.ok_or(Error::<T>::AssetNotFound.into())
 		}
+	}
 
-		/// Stake `amount` of fund from the current user's free account to reserved account.
-		#[pallet::call_index(3)]
-		#[pallet::weight(T::WeightInfo::stake_funds())]
-		pub fn stake_funds(origin: OriginFor<T>, amount: T::Balance) -> DispatchResult {
-			let user = ensure_signed(origin)?;
-			<Self as Stakable<T::AccountId, T::Balance>>::stake_funds(&user, amount)
+	/// Returns the minimum amount that can be deposited, withdrew, transferred or staked. Assets
+	/// use their existential deposit as the minimum amount.
+	fn minimum_amount(asset_id: AssetId) -> Result<T::Balance, DispatchError> {
+		if asset_id == NATIVE_ASSET_ID {
+			Ok(T::MinimumAmount::get())
+		} else {
+			Self::existential_deposit(asset_id)
 		}
+	}
 
-		/// Redeem `amount` of fund from the current user's reserved account to locked account.
-		#[pallet::call_index(4)]
-		#[pallet::weight(T::WeightInfo::redeem_funds())]
-		pub fn redeem_funds(origin: OriginFor<T>, amount: T::Balance) -> DispatchResult {
-			let user = ensure_signed(origin)?;
-			<Self as Stakable<T::AccountId, T::Balance>>::redeem_funds(&user, amount)
+	#[cfg(any(feature = "try-runtime", test))]
+	/// Integrity check: Ensure that the sum of all funds in balances matches total_issuance.
+	fn check_total_issuance() -> bool {
+		TotalIssuance::<T>::get() == Accounts::<T>::iter().map(|(_, account)| account.total()).sum()
+	}
+
+	#[cfg(any(feature = "try-runtime", test))]
+	/// Integrity check: Ensure that the sum of all funds of an asset matches its issuance.
+	fn check_asset_issuance(asset_id: AssetId) -> bool {
+		AssetIssuance::<T>::get(asset_id) ==
+			AssetAccounts::<T>::iter()
+				.filter(|(_, id, _)| *id == asset_id)
+				.map(|(_, _, account)| account.total())
+				.sum()
+	}
+
+	/// Ensure the invariants of the pallet hold:
+	/// - The total issuance of every asset is the sum of its accounts' totals.
+	/// - Every scheduled unlock points to an existing lock, and its expiry is stored.
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+		ensure!(Self::check_total_issuance(), "The total issuance is not the sum of the totals.");
+		for asset_id in Assets::<T>::iter_keys() {
+			ensure!(
+				Self::check_asset_issuance(asset_id),
+				"The issuance of an asset is not the sum of the totals."
+			);
 		}
-		/// Auditor locked `amount` of fund from the any user's account to locked account for some
-		/// period. Funds are taken from "free" first, then from "reserved".
-		#[pallet::call_index(5)]
-		#[pallet::weight(T::WeightInfo::lock_funds_auditor())]
-		pub fn lock_funds_auditor(
-			origin: OriginFor<T>,
-			user: T::AccountId,
-			amount: T::Balance,
-			length: BlockNumberFor<T>,
-		) -> DispatchResult {
-			// Ensure the caller is the Auditor
-			let id = ensure_signed(origin)?;
-			T::RoleManager::ensure_role(&id, Role::Auditor)?;
-			// Ensure the user is a customer to be locked
-			T::RoleManager::ensure_role(&user, Role::Customer)?;
-			// Implement logic to lock funds from free and reserved
-			let unlock = frame_system::Pallet::<T>::current_block_number() + length;
 
-			Accounts::<T>::mutate(&user, |account_data| {
+		for (unlock, accounts) in AccountWithUnlockedFund::<T>::iter() {
+			for (user, lock_id) in accounts {
+				let asset_id = AssetLocks::<T>::get(lock_id).unwrap_or(NATIVE_ASSET_ID);
 				ensure!(
-					account_data.free + account_data.reserved >= amount,
-					Error::<T>::InsufficientBalance
+					Self::asset_account(asset_id, &user)
+						.locked
+						.iter()
+						.any(|lock| lock.id == lock_id),
+					"A scheduled unlock points to a lock that does not exist."
+				);
+				ensure!(
+					LockExpiries::<T>::get(&user, lock_id) == Some(unlock),
+					"A scheduled unlock does not match the stored expiry."
 				);
+			}
+		}
+		Ok(())
+	}
 
-				let mut remain = amount;
-				let free_deduction = min(account_data.free, remain);
-				account_data.free -= free_deduction;
-				remain -= free_deduction;
+	/// Reaps funds from the dust candidates that have balances below the Existential Deposit
+	/// (ED), visiting at most `max_candidates` candidates. Reaped funds are transferred to the
+	/// Treasury account. Returns the number of candidates visited, and the number of ledger
+	/// entries, allowances and lock expiries cleared from the reaped accounts.
+	fn reap_accounts(max_candidates: u32) -> (u32, u32) {
+		let treasury = match Self::treasury() {
+			Ok(treasury) => treasury,
+			Err(_) => return (0u32, 0u32),
+		};
 
-				account_data.reserved -= remain;
+		let mut cleared = 0u32;
 
-				let new_locked_fund =
-					LockedFund { id: Self::next_lock_id(), amount, reason: LockReason::Auditor };
-				account_data.locked.push(new_locked_fund);
+		let candidates = DustCandidates::<T>::iter_keys()
+			.take(max_candidates as usize)
+			.collect::<Vec<_>>();
+		candidates.iter().for_each(|(id, asset_id)| {
+			DustCandidates::<T>::remove(id, asset_id);
 
-				// Add new unlock user to the AccountWithUnlockedFunds
-				AccountWithUnlockedFund::<T>::append(unlock, (user.clone(), new_locked_fund.id));
+			// The balance may have changed since the account became a candidate, and the account
+			// may be removed already.
+			let exists = if *asset_id == NATIVE_ASSET_ID {
+				Accounts::<T>::contains_key(id)
+			} else {
+				AssetAccounts::<T>::contains_key(id, asset_id)
+			};
+			let balance = Self::asset_account(*asset_id, id);
+			if !exists || !Self::is_dust(*asset_id, &balance) {
+				return;
+			}
 
-				Self::deposit_event(Event::<T>::Locked {
-					user: user.clone(),
-					amount,
-					length,
-					reason: LockReason::Auditor,
+			let dust = balance.total();
+			if *asset_id == NATIVE_ASSET_ID {
+				// Drop the provider reference before the event, so `Reaped` is the last event.
+				let _ = frame_system::Pallet::<T>::dec_providers(id);
+				Self::deposit_event(Event::Reaped { user: id.clone(), dust });
+				// Record the dust before the account is removed.
+				Self::record_ledger_entry(id, LedgerEntryKind::Reaped, dust, None);
+				cleared = cleared.saturating_add(Self::clear_reaped_account(id));
+				if !dust.is_zero() {
+					Accounts::<T>::mutate(&treasury, |treasury_account| {
+						treasury_account.free = treasury_account.free.saturating_add(dust);
+					});
+					T::TreasuryIncome::record_income(IncomeSource::Reaping, dust);
+				}
+			} else {
+				Self::deposit_event(Event::AssetReaped {
+					asset_id: *asset_id,
+					user: id.clone(),
+					dust,
 				});
-				Ok(())
-			})
-		}
-
-		/// Auditor unlocked the LockId which free the `amount` of fund from the user locked by
-		/// auditor. Funds are returned from "locked" to "free".
-		#[pallet::call_index(6)]
-		#[pallet::weight(T::WeightInfo::unlock_funds_auditor())]
-		pub fn unlock_funds_auditor(
-			origin: OriginFor<T>,
-			user: T::AccountId,
-			lock_id: LockId,
-		) -> DispatchResult {
-			// Ensure the caller is the Auditor
-			let id = ensure_signed(origin)?;
-			T::RoleManager::ensure_role(&id, Role::Auditor)?;
-			// Ensure the user is a customer to be locked
-			T::RoleManager::ensure_role(&user, Role::Customer)?;
+				AssetAccounts::<T>::remove(id, asset_id);
+				AssetAccounts::<T>::mutate(&treasury, asset_id, |treasury_account| {
+					treasury_account.free = treasury_account.free.saturating_add(dust);
+				});
+			}
+		});
+		(candidates.len() as u32, cleared)
+	}
 
-			Self::unlock(&user, lock_id, UnlockReason::Auditor)
-		}
+	/// Remove the storage of a reaped native account. The ledger is cleared except its last
+	/// entry, which records the reaping. Returns the number of ledger entries, allowances and
+	/// lock expiries removed.
+	fn clear_reaped_account(who: &T::AccountId) -> u32 {
+		Accounts::<T>::remove(who);
+		InterestSnapshot::<T>::remove(who);
+		OverdraftLimits::<T>::remove(who);
+		VelocityLimits::<T>::remove(who);
+		OutgoingValues::<T>::remove(who);
+		// Stale entries of the freeze expiry schedule are skipped when they are due.
+		FrozenAccounts::<T>::remove(who);
+		let allowances = Allowances::<T>::clear_prefix(who, u32::MAX, None).unique;
+		let expiries = LockExpiries::<T>::clear_prefix(who, u32::MAX, None).unique;
 
-		/// Manager set interest rate in basis point
-		///
-		/// Requires Manager.
-		#[pallet::call_index(7)]
-		#[pallet::weight(T::WeightInfo::set_interest_rate())]
-		pub fn set_interest_rate(origin: OriginFor<T>, interest_rate_bps: u32) -> DispatchResult {
-			let id = ensure_signed(origin)?;
-			T::RoleManager::ensure_role(&id, Role::Manager)?;
-			ensure!(interest_rate_bps <= 10000u32, Error::<T>::InvalidInterestRate);
-			let old_interest_rate = InterestRate::<T>::get();
-			InterestRate::<T>::set(Perbill::from_rational(interest_rate_bps, 10000u32));
-
-			Self::deposit_event(Event::<T>::InterestRateSet {
-				manager: id,
-				old_interest_rate,
-				new_interest_rate: Perbill::from_rational(interest_rate_bps, 10000u32),
-			});
-			Ok(())
-		}
+		let (first, next) = LedgerIndices::<T>::get(who);
+		let last = next.wrapping_sub(1);
+		let entries = last.wrapping_sub(first);
+		(0..entries).for_each(|index| LedgerEntries::<T>::remove(who, first.wrapping_add(index)));
+		LedgerIndices::<T>::insert(who, (last, next));
 
-		/// Migrate the old treasury account to a new one.
-		///
-		/// Requires governance approved.
-		#[pallet::call_index(8)]
-		#[pallet::weight(T::WeightInfo::rotate_treasury())]
-		pub fn rotate_treasury(origin: OriginFor<T>, new_treasury: T::AccountId) -> DispatchResult {
-			// ensure governance
-			T::EnsureGovernance::ensure_origin(origin)?;
+		entries.saturating_add(allowances).saturating_add(expiries)
+	}
 
-			ensure!(!Accounts::<T>::contains_key(&new_treasury), Error::<T>::AccountIdAlreadyTaken);
-			ensure!(
-				T::RoleManager::role(&new_treasury).is_none(),
-				Error::<T>::AccountIdAlreadyTaken
-			);
+	/// Returns the account Id of the joint account of the sorted `holders` and `threshold`.
+	pub fn joint_account_id(holders: &[T::AccountId], threshold: u32) -> T::AccountId {
+		let entropy = BlakeTwo256::hash_of(&(b"bank/joint", holders, threshold));
+		Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+			.expect("infinite length input; no invalid inputs for type; qed")
+	}
 
-			let old_treasury = Self::treasury().ok();
-			if let Some(treasury) = old_treasury.clone() {
-				// Update lock expiry of old treasury to the new treasury account.
-				AccountWithUnlockedFund::<T>::iter().for_each(|(block_number, mut accounts)| {
-					accounts.iter_mut().for_each(|(user_id, _)| {
-						if *user_id == treasury {
-							*user_id = new_treasury.clone();
-						}
-					});
-					// Update the storage map with the modified accounts
-					AccountWithUnlockedFund::<T>::insert(block_number, accounts);
-				});
+	/// Ensure the user is a holder of the joint account, and return the joint account.
+	fn ensure_joint_holder(
+		joint_account: &T::AccountId,
+		who: &T::AccountId,
+	) -> Result<JointAccountOf<T>, DispatchError> {
+		let joint =
+			JointAccounts::<T>::get(joint_account).ok_or(Error::<T>::JointAccountNotFound)?;
+		ensure!(joint.holders.binary_search(who).is_ok(), Error::<T>::NotJointHolder);
+		Ok(joint)
+	}
 
-				Accounts::<T>::insert(&new_treasury, Accounts::<T>::take(&treasury));
-			}
+	/// Cast the vote of a holder on a joint transfer, and execute or drop the transfer once it is
+	/// resolved.
+	fn vote_on_joint_transfer(
+		proposal_id: ProposalId,
+		mut transfer: JointTransferOf<T>,
+		joint: &JointAccountOf<T>,
+		who: T::AccountId,
+		approve: bool,
+	) -> DispatchResult {
+		ensure!(transfer.cast_vote(who.clone(), approve), Error::<T>::AlreadyVoted);
+		Self::deposit_event(Event::<T>::JointTransferVoted { proposal_id, who, approve });
 
-			TreasuryAccount::<T>::set(Some(new_treasury.clone()));
-			Self::deposit_event(Event::<T>::TreasuryAccountRotated {
-				old: old_treasury,
-				new: new_treasury,
-			});
-			Ok(())
+		match transfer.can_resolve(joint.holders.len() as u32, joint.threshold) {
+			Some(true) => {
+				JointTransfers::<T>::remove(proposal_id);
+				// A failed transfer is dropped, so the votes are kept and the proposal does not
+				// stay stuck.
+				let result = with_storage_layer(|| {
+					<Self as BasicAccounting<T::AccountId, T::Balance>>::transfer(
+						&transfer.joint_account,
+						&transfer.to,
+						transfer.amount,
+					)?;
+					Self::charge_transfer_fee(&transfer.joint_account, transfer.amount)
+				});
+				match result {
+					Ok(()) =>
+						Self::deposit_event(Event::<T>::JointTransferExecuted { proposal_id }),
+					Err(error) =>
+						Self::deposit_event(Event::<T>::JointTransferFailed { proposal_id, error }),
+				}
+			},
+			Some(false) => {
+				JointTransfers::<T>::remove(proposal_id);
+				Self::deposit_event(Event::<T>::JointTransferRejected { proposal_id });
+			},
+			None => JointTransfers::<T>::insert(proposal_id, transfer),
 		}
+		Ok(())
+	}
 
-		/// Force transfer `amount` of fund from one user to another user.
-		/// Requires governance approved.
-		#[pallet::call_index(9)]
-		#[pallet::weight(T::WeightInfo::force_transfer())]
-		pub fn force_transfer(
-			origin: OriginFor<T>,
-			from: T::AccountId,
-			to: T::AccountId,
-			amount: T::Balance,
-		) -> DispatchResult {
-			// ensure governance
-			T::EnsureGovernance::ensure_origin(origin)?;
-			T::RoleManager::ensure_role(&from, Role::Customer)?;
-			T::RoleManager::ensure_role(&to, Role::Customer)?;
-			<Self as BasicAccounting<T::AccountId, T::Balance>>::transfer(&from, &to, amount)
+	/// Returns the velocity limit of a user: the user's own limit, or the default limit.
+	/// Only customers are limited.
+	fn velocity_limit(who: &T::AccountId) -> Option<VelocityLimit<T::Balance>> {
+		if T::RoleManager::role(who) != Some(Role::Customer) {
+			return None;
 		}
+		Self::velocity_limits(who).or_else(Self::default_velocity_limit)
 	}
-}
 
-impl<T: Config> BasicAccounting<T::AccountId, T::Balance> for Pallet<T> {
-	fn deposit(user: &T::AccountId, amount: T::Balance) -> DispatchResult {
-		Self::mint(user, amount)?;
-		Self::deposit_event(Event::<T>::Deposited { user: user.clone(), amount });
-		Ok(())
+	/// Returns the outgoing value a user sent in the current day and week.
+	fn outgoing_value(who: &T::AccountId) -> OutgoingValueOf<T> {
+		let now = frame_system::Pallet::<T>::current_block_number();
+		let day = now / T::BlocksPerDay::get();
+		let week = now / T::BlocksPerWeek::get();
+		let outgoing = OutgoingValues::<T>::get(who);
+		OutgoingValue {
+			day,
+			daily: if outgoing.day == day { outgoing.daily } else { Zero::zero() },
+			week,
+			weekly: if outgoing.week == week { outgoing.weekly } else { Zero::zero() },
+		}
 	}
 
-	fn withdraw(user: &T::AccountId, amount: T::Balance) -> DispatchResult {
-		Self::burn(user, amount)?;
-		Self::deposit_event(Event::<T>::Withdrew { user: user.clone(), amount });
+	/// Run `f` to send `amount` out of a user's account if it is within the user's velocity
+	/// limit, and count it towards the limit.
+	fn with_velocity_limit(
+		who: &T::AccountId,
+		amount: T::Balance,
+		f: impl FnOnce() -> DispatchResult,
+	) -> DispatchResult {
+		let limit = match Self::velocity_limit(who) {
+			Some(limit) => limit,
+			None => return f(),
+		};
+		let mut outgoing = Self::outgoing_value(who);
+		outgoing.daily = outgoing.daily.saturating_add(amount);
+		outgoing.weekly = outgoing.weekly.saturating_add(amount);
+		ensure!(
+			outgoing.daily <= limit.daily && outgoing.weekly <= limit.weekly,
+			Error::<T>::LimitExceeded
+		);
+
+		f()?;
+		OutgoingValues::<T>::insert(who, outgoing);
 		Ok(())
 	}
 
-	fn transfer(from: &T::AccountId, to: &T::AccountId, amount: T::Balance) -> DispatchResult {
-		Accounts::<T>::mutate(from, |balance| -> DispatchResult {
-			if balance.free >= amount {
-				balance.free -= amount;
-				Ok(())
-			} else {
-				Err(Error::<T>::InsufficientBalance.into())
+	/// Take `amount` sent at block `sent_at` back out of the outgoing value of a user, if the day
+	/// or the week it was sent in is still current.
+	fn refund_velocity(who: &T::AccountId, amount: T::Balance, sent_at: BlockNumberFor<T>) {
+		let day = sent_at / T::BlocksPerDay::get();
+		let week = sent_at / T::BlocksPerWeek::get();
+		OutgoingValues::<T>::mutate_exists(who, |maybe_outgoing| {
+			if let Some(outgoing) = maybe_outgoing {
+				if outgoing.day == day {
+					outgoing.daily = outgoing.daily.saturating_sub(amount);
+				}
+				if outgoing.week == week {
+					outgoing.weekly = outgoing.weekly.saturating_sub(amount);
+				}
 			}
-		})?;
-		Accounts::<T>::mutate(to, |balance| {
-			balance.free = balance.free.saturating_add(amount);
 		});
-		Self::deposit_event(Event::Transferred { from: from.clone(), to: to.clone(), amount });
-		Ok(())
 	}
 
-	fn free_balance(user: &T::AccountId) -> T::Balance {
-		Accounts::<T>::get(user).free
+	/// Returns the outgoing value a user can still send in the current day and week, or `None`
+	/// if the user is not limited.
+	pub fn remaining_allowance(who: &T::AccountId) -> Option<VelocityLimit<T::Balance>> {
+		let limit = Self::velocity_limit(who)?;
+		let outgoing = Self::outgoing_value(who);
+		Some(VelocityLimit {
+			daily: limit.daily.saturating_sub(outgoing.daily),
+			weekly: limit.weekly.saturating_sub(outgoing.weekly),
+		})
 	}
-}
 
-impl<T: Config> Stakable<T::AccountId, T::Balance> for Pallet<T> {
-	/// Stake funds from free to reserved
-	fn stake_funds(user: &T::AccountId, amount: T::Balance) -> DispatchResult {
-		T::RoleManager::ensure_role(user, Role::Customer)?;
-		ensure!(amount >= T::MinimumAmount::get(), Error::<T>::AmountTooSmall);
-		Accounts::<T>::mutate(user, |account| -> DispatchResult {
-			ensure!(account.free >= amount, Error::<T>::InsufficientBalance);
-			account.free -= amount;
-			let new_locked_fund =
-				LockedFund { id: Self::next_lock_id(), amount, reason: LockReason::Stake };
-			account.locked.push(new_locked_fund);
+	/// Schedule an interest rate in basis point to take effect at `effective_at`, if it is within
+	/// the bounds set by governance. It replaces the rate already scheduled, if any.
+	fn do_schedule_interest_rate(
+		manager: T::AccountId,
+		interest_rate_bps: u32,
+		effective_at: BlockNumberFor<T>,
+	) -> DispatchResult {
+		ensure!(interest_rate_bps <= 10000u32, Error::<T>::InvalidInterestRate);
 
-			let unlock = frame_system::Pallet::<T>::current_block_number() + T::StakePeriod::get();
-			AccountWithUnlockedFund::<T>::append(unlock, (user.clone(), new_locked_fund.id));
+		let interest_rate = Perbill::from_rational(interest_rate_bps, 10000u32);
+		if let Some(bounds) = RateBounds::<T>::get() {
+			let current = InterestRate::<T>::get();
+			let change = interest_rate.max(current).saturating_sub(interest_rate.min(current));
+			ensure!(
+				bounds.min <= interest_rate &&
+					interest_rate <= bounds.max &&
+					change <= bounds.max_change,
+				Error::<T>::InterestRateOutOfBounds
+			);
+		}
 
-			Ok(())
-		})?;
-		Self::deposit_event(Event::<T>::Locked {
-			user: user.clone(),
-			amount,
-			length: T::StakePeriod::get(),
-			reason: LockReason::Stake,
+		NextInterestRate::<T>::put(ScheduledInterestRate {
+			manager: manager.clone(),
+			interest_rate,
+			effective_at,
+		});
+		Self::deposit_event(Event::<T>::InterestRateScheduled {
+			manager,
+			interest_rate,
+			effective_at,
 		});
-
 		Ok(())
 	}
 
-	/// Redeem funds from reserved to free after a certain time
-	fn redeem_funds(user: &T::AccountId, amount: T::Balance) -> DispatchResult {
-		T::RoleManager::ensure_role(user, Role::Customer)?;
-		ensure!(amount >= T::MinimumAmount::get(), Error::<T>::AmountTooSmall);
+	/// Set the scheduled interest rate, after accruing the interest at the old rate up to this
+	/// block, and record it in the rate history.
+	fn apply_interest_rate(scheduled: ScheduledInterestRateOf<T>, block_number: BlockNumberFor<T>) {
+		Self::update_interest_index(block_number);
+		let old_interest_rate = InterestRate::<T>::get();
+		InterestRate::<T>::set(scheduled.interest_rate);
+		InterestRateChanges::<T>::insert(block_number, scheduled.interest_rate);
 
-		// get unlock BlockNumber
-		let unlock = frame_system::Pallet::<T>::current_block_number() + T::RedeemPeriod::get();
+		Self::deposit_event(Event::<T>::InterestRateSet {
+			manager: scheduled.manager,
+			old_interest_rate,
+			new_interest_rate: scheduled.interest_rate,
+		});
+	}
 
-		// Add new locked funds to user's Account Data
-		Accounts::<T>::mutate(user, |account| -> DispatchResult {
-			ensure!(account.reserved >= amount, Error::<T>::InsufficientBalance);
-			account.reserved -= amount;
-			let new_locked_fund =
-				LockedFund { id: Self::next_lock_id(), amount, reason: LockReason::Redeem };
-			account.locked.push(new_locked_fund);
+	/// Transfer one leg of a batch transfer to a customer, and charge its fee.
+	fn transfer_leg(from: &T::AccountId, to: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		T::RoleManager::ensure_role(to, Role::Customer)?;
 
-			// Add new unlock user to the AccountWithUnlockedFunds
-			AccountWithUnlockedFund::<T>::append(unlock, (user.clone(), new_locked_fund.id));
-			Ok(())
-		})?;
+		if amount < T::MinimumAmount::get() {
+			return Err(Error::<T>::AmountTooSmall.into());
+		}
+		<Self as BasicAccounting<T::AccountId, T::Balance>>::transfer(from, to, amount)?;
+		Self::charge_transfer_fee(from, amount)
+	}
 
-		Self::deposit_event(Event::<T>::Locked {
-			user: user.clone(),
-			amount,
-			length: T::RedeemPeriod::get(),
-			reason: LockReason::Redeem,
-		});
-		Ok(())
+	/// Returns the fee a user pays to transfer `amount`. Managers are exempt from fees.
+	pub fn transfer_fee(who: &T::AccountId, amount: T::Balance) -> T::Balance {
+		if T::RoleManager::role(who) == Some(Role::Manager) {
+			return Zero::zero();
+		}
+		match Self::transfer_fee_schedule() {
+			None => Zero::zero(),
+			Some(FeeSchedule::Flat(fee)) => fee,
+			Some(FeeSchedule::Percentage(rate)) => rate * amount,
+			Some(FeeSchedule::Tiered(tiers)) => tiers
+				.iter()
+				.rev()
+				.find(|(threshold, _)| amount >= *threshold)
+				.map(|(_, fee)| *fee)
+				.unwrap_or_default(),
+		}
 	}
 
-	fn staked(user: &T::AccountId) -> T::Balance {
-		Accounts::<T>::get(user).reserved
+	/// Returns whether the treasury pays the transaction fees of a runtime call. The first two
+	/// bytes of an encoded runtime call are its pallet index and call index.
+	pub fn is_sponsored<Call: Encode>(call: &Call) -> bool {
+		call.using_encoded(|encoded| match encoded {
+			[pallet_index, call_index, ..] =>
+				SponsoredCalls::<T>::get().contains(&(*pallet_index, *call_index)),
+			_ => false,
+		})
 	}
-}
 
-impl<T: Config> GetTreasury<T::AccountId> for Pallet<T> {
-	fn treasury() -> Result<T::AccountId, DispatchError> {
-		TreasuryAccount::<T>::get().ok_or(Error::<T>::TreasuryAccountNotSet.into())
+	/// Charge the fee of transferring `amount` from the user, and pay it to the treasury.
+	fn charge_transfer_fee(who: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		let fee = Self::transfer_fee(who, amount);
+		if fee.is_zero() {
+			return Ok(());
+		}
+		Self::move_funds(NATIVE_ASSET_ID, who, &Self::treasury()?, fee)?;
+		Self::record_ledger_entry(who, LedgerEntryKind::Fee, fee, None);
+		T::TreasuryIncome::record_income(IncomeSource::TransferFee, fee);
+		Self::deposit_event(Event::<T>::TransferFeeCharged { user: who.clone(), fee });
+		Ok(())
 	}
-}
 
-impl<T: Config> Pallet<T> {
-	/// Burn some fund from a user's account.
-	fn burn(user: &T::AccountId, amount: T::Balance) -> DispatchResult {
-		T::RoleManager::ensure_role(user, Role::Customer)?;
-		Accounts::<T>::mutate(user, |balance| -> DispatchResult {
-			if balance.free >= amount {
-				balance.free -= amount;
-				Ok(())
-			} else {
-				Err(Error::<T>::InsufficientBalance.into())
-			}
+	/// Mint some fund into a user's account, and record the reference of the deposit, if any.
+	fn do_deposit(
+		user: &T::AccountId,
+		amount: T::Balance,
+		reference: Option<PaymentReference>,
+	) -> DispatchResult {
+		Self::mint(NATIVE_ASSET_ID, user, amount)?;
+		Self::record_ledger_entry_with_reference(
+			user,
+			LedgerEntryKind::Deposit,
+			amount,
+			None,
+			reference.clone(),
+		);
+		match reference {
+			Some(reference) => Self::deposit_event(Event::<T>::DepositedWithReference {
+				user: user.clone(),
+				amount,
+				reference,
+			}),
+			None => Self::deposit_event(Event::<T>::Deposited { user: user.clone(), amount }),
+		}
+		Ok(())
+	}
+
+	/// Transfer some fund between users, and record the reference of the transfer, if any, in
+	/// both ledgers.
+	fn do_transfer(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: T::Balance,
+		reference: Option<PaymentReference>,
+	) -> DispatchResult {
+		Self::with_velocity_limit(from, amount, || {
+			Self::move_funds(NATIVE_ASSET_ID, from, to, amount)
 		})?;
-		TotalIssuance::<T>::mutate(|total| {
-			*total = total.saturating_sub(amount);
-		});
+		Self::record_ledger_entry_with_reference(
+			from,
+			LedgerEntryKind::TransferOut,
+			amount,
+			Some(to.clone()),
+			reference.clone(),
+		);
+		Self::record_ledger_entry_with_reference(
+			to,
+			LedgerEntryKind::TransferIn,
+			amount,
+			Some(from.clone()),
+			reference.clone(),
+		);
+		match reference {
+			Some(reference) => Self::deposit_event(Event::<T>::TransferredWithReference {
+				from: from.clone(),
+				to: to.clone(),
+				amount,
+				reference,
+			}),
+			None => Self::deposit_event(Event::<T>::Transferred {
+				from: from.clone(),
+				to: to.clone(),
+				amount,
+			}),
+		}
 		Ok(())
 	}
 
-	/// Mint some fund into a user's account.
-	fn mint(user: &T::AccountId, amount: T::Balance) -> DispatchResult {
-		T::RoleManager::ensure_role(user, Role::Customer)?;
+	/// Append an entry without a reference to the ledger of a user.
+	fn record_ledger_entry(
+		who: &T::AccountId,
+		kind: LedgerEntryKind,
+		amount: T::Balance,
+		counterparty: Option<T::AccountId>,
+	) {
+		Self::record_ledger_entry_with_reference(who, kind, amount, counterparty, None)
+	}
 
-		Accounts::<T>::mutate(user, |balance| {
-			balance.free = balance.free.saturating_add(amount);
-		});
-		TotalIssuance::<T>::mutate(|total| {
-			*total = total.saturating_add(amount);
+	/// Append an entry to the ledger of a user, with the user's balances after the change. The
+	/// oldest entry is pruned once the ledger holds `MaxLedgerEntries` entries.
+	fn record_ledger_entry_with_reference(
+		who: &T::AccountId,
+		kind: LedgerEntryKind,
+		amount: T::Balance,
+		counterparty: Option<T::AccountId>,
+		reference: Option<PaymentReference>,
+	) {
+		let account = Accounts::<T>::get(who);
+		LedgerIndices::<T>::mutate(who, |(first, next)| {
+			LedgerEntries::<T>::insert(
+				who,
+				*next,
+				LedgerEntry {
+					block: frame_system::Pallet::<T>::current_block_number(),
+					kind,
+					amount,
+					counterparty,
+					reference,
+					free: account.free,
+					reserved: account.reserved,
+				},
+			);
+			*next = next.wrapping_add(1);
+			if next.wrapping_sub(*first) > T::MaxLedgerEntries::get() {
+				LedgerEntries::<T>::remove(who, *first);
+				*first = first.wrapping_add(1);
+			}
 		});
-		Ok(())
 	}
 
-	#[cfg(test)]
-	/// Integrity check: Ensure that the sum of all funds in balances matches total_issuance.
-	fn check_total_issuance() -> bool {
-		TotalIssuance::<T>::get() == Accounts::<T>::iter().map(|(_, account)| account.total()).sum()
+	/// Returns a page of the ledger entries of a user recorded between `from_block` and
+	/// `to_block` inclusive, oldest first. The page skips the first `offset` entries, and holds
+	/// at most `limit` entries, capped at `MAX_STATEMENT_PAGE_SIZE`.
+	pub fn account_statement(
+		who: T::AccountId,
+		from_block: BlockNumberFor<T>,
+		to_block: BlockNumberFor<T>,
+		offset: u32,
+		limit: u32,
+	) -> Vec<LedgerEntryOf<T>> {
+		let (first, next) = LedgerIndices::<T>::get(&who);
+		(0..next.wrapping_sub(first))
+			.filter_map(|index| LedgerEntries::<T>::get(&who, first.wrapping_add(index)))
+			.filter(|entry| entry.block >= from_block && entry.block <= to_block)
+			.skip(offset as usize)
+			.take(limit.min(MAX_STATEMENT_PAGE_SIZE) as usize)
+			.collect()
 	}
 
-	/// Reaps funds from accounts that have balances below the Existential Deposit (ED).
-	/// Reaped funds are transferred to the Treasury account.
-	fn reap_accounts() {
-		if let Ok(treasury) = Self::treasury() {
-			let total_reaped_amount = Accounts::<T>::iter()
-				.filter(|(_id, balance)| balance.total() < T::ExistentialDeposit::get())
-				.map(|(id, balance)| {
-					Self::deposit_event(Event::Reaped { user: id.clone(), dust: balance.total() });
-					Accounts::<T>::remove(id);
-					balance.total()
-				})
-				.sum();
-
-			if total_reaped_amount > Zero::zero() {
-				Accounts::<T>::mutate(&treasury, |treasury_account| {
-					treasury_account.free =
-						treasury_account.free.saturating_add(total_reaped_amount);
-				});
-			}
-		}
+	/// Returns a page of the interest rates that took effect between two blocks inclusive, oldest
+	/// first, with the block they took effect at. The page skips `offset` rates, and holds at
+	/// most `limit` rates, capped at `MAX_STATEMENT_PAGE_SIZE`.
+	pub fn interest_rate_history(
+		from_block: BlockNumberFor<T>,
+		to_block: BlockNumberFor<T>,
+		offset: u32,
+		limit: u32,
+	) -> Vec<(BlockNumberFor<T>, Perbill)> {
+		let mut history = InterestRateChanges::<T>::iter()
+			.filter(|(block, _)| *block >= from_block && *block <= to_block)
+			.collect::<Vec<_>>();
+		history.sort_by_key(|(block, _)| *block);
+		history
+			.into_iter()
+			.skip(offset as usize)
+			.take(limit.min(MAX_STATEMENT_PAGE_SIZE) as usize)
+			.collect()
 	}
 
 	/// Get the lock id to store into the LockedFund.
@@ -724,13 +4763,245 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Get the asset id for the next registered asset.
+	fn next_asset_id() -> AssetId {
+		NextAssetId::<T>::mutate(|id| {
+			*id = id.wrapping_add(1);
+			*id
+		})
+	}
+
+	/// Get the product id for the next savings product.
+	fn next_product_id() -> ProductId {
+		NextProductId::<T>::mutate(|id| {
+			*id = id.wrapping_add(1);
+			*id
+		})
+	}
+
+	/// Get the proposal id for the next joint transfer.
+	fn next_joint_transfer_id() -> ProposalId {
+		NextJointTransferId::<T>::mutate(|id| {
+			*id = id.wrapping_add(1);
+			*id
+		})
+	}
+
+	/// Get the order id for the next standing order.
+	fn next_order_id() -> OrderId {
+		NextOrderId::<T>::mutate(|id| {
+			*id = id.wrapping_add(1);
+			*id
+		})
+	}
+
+	/// Ensure a standing order executes in a future block and repeats after a non-zero period.
+	fn ensure_valid_schedule(
+		next_execution: BlockNumberFor<T>,
+		period: Option<BlockNumberFor<T>>,
+	) -> DispatchResult {
+		ensure!(
+			next_execution > frame_system::Pallet::<T>::current_block_number() &&
+				period.map_or(true, |period| !period.is_zero()),
+			Error::<T>::InvalidSchedule
+		);
+		Ok(())
+	}
+
+	/// Transfer the funds of a due standing order, then schedule its next execution according to
+	/// its period and failure policy. Orders without a next execution are removed.
+	fn execute_standing_order(
+		order_id: OrderId,
+		mut order: StandingOrderOf<T>,
+		block_number: BlockNumberFor<T>,
+	) {
+		// Retries run in consecutive blocks, so this is the block the order was originally due.
+		// The next period starts after the retries, even if they overran the period.
+		let due = block_number.saturating_sub(order.retries.into());
+		let next_period = order
+			.period
+			.map(|period| due.saturating_add(period).max(block_number.saturating_add(One::one())));
+		let next_execution = match <Self as BasicAccounting<T::AccountId, T::Balance>>::transfer(
+			&order.from,
+			&order.to,
+			order.amount,
+		) {
+			Ok(()) => {
+				Self::deposit_event(Event::<T>::StandingOrderExecuted {
+					order_id,
+					from: order.from.clone(),
+					to: order.to.clone(),
+					amount: order.amount,
+				});
+				order.retries = 0u32;
+				next_period
+			},
+			Err(error) => {
+				let next_execution = if order.policy == FailurePolicy::Retry &&
+					order.retries < T::MaxStandingOrderRetries::get()
+				{
+					order.retries += 1;
+					Some(block_number.saturating_add(One::one()))
+				} else {
+					order.retries = 0u32;
+					next_period
+				};
+				Self::deposit_event(Event::<T>::StandingOrderFailed {
+					order_id,
+					error,
+					next_execution,
+				});
+				next_execution
+			},
+		};
+
+		match next_execution {
+			Some(mut next_execution) => {
+				// Postpone the order to the first block with room in the schedule.
+				while StandingOrderSchedule::<T>::try_append(next_execution, order_id).is_err() {
+					next_execution = next_execution.saturating_add(One::one());
+				}
+				order.next_execution = next_execution;
+				StandingOrders::<T>::insert(order_id, order);
+			},
+			None => {
+				StandingOrders::<T>::remove(order_id);
+				Self::deposit_event(Event::<T>::StandingOrderCompleted { order_id });
+			},
+		}
+	}
+
+	/// Ensure the caller is the arbiter of the escrow, and still an Auditor.
+	fn ensure_arbiter(id: &T::AccountId, escrow: &EscrowInfoOf<T>) -> DispatchResult {
+		ensure!(*id == escrow.arbiter, Error::<T>::NotEscrowParty);
+		T::RoleManager::ensure_role(id, Role::Auditor)
+	}
+
+	/// Remove the escrow lock from the payer, and move the funds to the payee's "free" if
+	/// `release`, or back to the payer's "free" otherwise.
+	fn settle_escrow(lock_id: LockId, escrow: EscrowInfoOf<T>, release: bool) -> DispatchResult {
+		let amount = Self::try_mutate_account(NATIVE_ASSET_ID, &escrow.payer, |account| {
+			let index = account
+				.locked
+				.iter()
+				.position(|item| item.id == lock_id)
+				.ok_or(Error::<T>::InvalidLockId)?;
+			let amount = account.locked.remove(index).amount;
+			if !release {
+				account.free = account.free.saturating_add(amount);
+			}
+			Ok(amount)
+		})?;
+		Escrows::<T>::remove(lock_id);
+		Self::unschedule_unlock(&escrow.payer, lock_id);
+
+		if release {
+			let repaid = Self::mutate_account(NATIVE_ASSET_ID, &escrow.payee, |account| {
+				account.credit(amount)
+			});
+			// Repaid debts are burned.
+			TotalIssuance::<T>::mutate(|total| {
+				*total = total.saturating_sub(repaid);
+			});
+			Self::deposit_repaid_event(&escrow.payee, repaid);
+			Self::record_ledger_entry(
+				&escrow.payer,
+				LedgerEntryKind::TransferOut,
+				amount,
+				Some(escrow.payee.clone()),
+			);
+			Self::record_ledger_entry(
+				&escrow.payee,
+				LedgerEntryKind::TransferIn,
+				amount,
+				Some(escrow.payer.clone()),
+			);
+			Self::deposit_event(Event::<T>::EscrowReleased {
+				lock_id,
+				payee: escrow.payee,
+				amount,
+			});
+		} else {
+			Self::record_ledger_entry(&escrow.payer, LedgerEntryKind::Unlock, amount, None);
+			Self::deposit_event(Event::<T>::EscrowRefunded {
+				lock_id,
+				payer: escrow.payer,
+				amount,
+			});
+		}
+		Ok(())
+	}
+
+	/// Remove the withdrawal lock from the user, and burn the funds if `approve`, or move them
+	/// back to the user's "free" and refund them to the user's velocity limit otherwise.
+	fn settle_withdrawal(
+		lock_id: LockId,
+		request: &WithdrawalRequestOf<T>,
+		approve: bool,
+	) -> DispatchResult {
+		Self::try_mutate_account(NATIVE_ASSET_ID, &request.user, |account| -> DispatchResult {
+			let index = account
+				.locked
+				.iter()
+				.position(|lock| lock.id == lock_id && lock.reason == LockReason::Withdrawal)
+				.ok_or(Error::<T>::InvalidLockId)?;
+			account.locked.remove(index);
+			if !approve {
+				account.free = account.free.saturating_add(request.amount);
+			}
+			Ok(())
+		})?;
+		WithdrawalRequests::<T>::remove(lock_id);
+		Self::unschedule_unlock(&request.user, lock_id);
+
+		if approve {
+			Self::mutate_issuance(NATIVE_ASSET_ID, |total| {
+				*total = total.saturating_sub(request.amount);
+			});
+			Self::record_ledger_entry(
+				&request.user,
+				LedgerEntryKind::Withdrawal,
+				request.amount,
+				None,
+			);
+		} else {
+			let requested_at = request.expiry.saturating_sub(T::WithdrawalRequestPeriod::get());
+			Self::refund_velocity(&request.user, request.amount, requested_at);
+		}
+		Ok(())
+	}
+
 	///Transfer locked funds to free funds
 	fn unlock(
 		account_id: &T::AccountId,
 		locked_id: LockId,
 		reason: UnlockReason,
 	) -> DispatchResult {
-		Accounts::<T>::try_mutate(account_id, |account_data| {
+		// Escrows are released to the payee at the deadline, unless disputed.
+		if reason == UnlockReason::Expired {
+			if let Some(escrow) = Escrows::<T>::get(locked_id) {
+				if escrow.disputed {
+					return Ok(());
+				}
+				return Self::settle_escrow(locked_id, escrow, true);
+			}
+			// Fixed-term product deposits are paid out with the interest at maturity.
+			if let Some(deposit) = ProductDeposits::<T>::get(locked_id) {
+				return Self::settle_product_deposit(account_id, locked_id, deposit);
+			}
+			// Withdrawal requests not settled in time are released to the user.
+			if let Some(request) = WithdrawalRequests::<T>::get(locked_id) {
+				Self::settle_withdrawal(locked_id, &request, false)?;
+				Self::deposit_event(Event::<T>::WithdrawalExpired {
+					lock_id: locked_id,
+					user: request.user,
+					amount: request.amount,
+				});
+				return Ok(());
+			}
+		}
+		let asset_id = AssetLocks::<T>::get(locked_id).unwrap_or(NATIVE_ASSET_ID);
+		let unlocked_amount = Self::try_mutate_account(asset_id, account_id, |account_data| {
 			if let Some(index) = account_data.locked.iter().position(|item| item.id == locked_id) {
 				ensure!(
 					reason != UnlockReason::Auditor ||
@@ -747,31 +5018,317 @@ impl<T: Config> Pallet<T> {
 
 				// Remove the unlocked locked fund from the vector
 				account_data.locked.remove(index);
-				Self::deposit_event(Event::Unlocked {
-					user: account_id.clone(),
-					amount: unlocked_amount,
-					reason,
-				});
-				Ok(())
+				Ok(unlocked_amount)
 			} else {
 				Err(Error::<T>::InvalidLockId.into())
 			}
-		})
+		})?;
+		AssetLocks::<T>::remove(locked_id);
+		// Expired locks are already removed from the schedule.
+		Self::unschedule_unlock(account_id, locked_id);
+
+		if asset_id == NATIVE_ASSET_ID {
+			Self::record_ledger_entry(account_id, LedgerEntryKind::Unlock, unlocked_amount, None);
+			Self::deposit_event(Event::Unlocked {
+				user: account_id.clone(),
+				amount: unlocked_amount,
+				reason,
+			});
+		} else {
+			Self::deposit_event(Event::AssetUnlocked {
+				asset_id,
+				user: account_id.clone(),
+				amount: unlocked_amount,
+				reason,
+			});
+		}
+		Ok(())
+	}
+
+	/// Set the amount of an auditor lock to `new_amount(old_amount)`, moving the difference from
+	/// or to the account, and move its scheduled expiry `extension` blocks later.
+	fn amend_auditor_lock(
+		user: &T::AccountId,
+		lock_id: LockId,
+		new_amount: impl FnOnce(T::Balance) -> Result<T::Balance, DispatchError>,
+		extension: BlockNumberFor<T>,
+	) -> DispatchResult {
+		let (old_amount, new_amount) =
+			Self::try_mutate_account(NATIVE_ASSET_ID, user, |account_data| {
+				let index = account_data
+					.locked
+					.iter()
+					.position(|item| item.id == lock_id)
+					.ok_or(Error::<T>::InvalidLockId)?;
+				ensure!(
+					account_data.locked[index].reason == LockReason::Auditor,
+					Error::<T>::UnauthorisedUnlock
+				);
+				let old_amount = account_data.locked[index].amount;
+				let new_amount = new_amount(old_amount)?;
+
+				if new_amount > old_amount {
+					ensure!(
+						account_data.free + account_data.reserved >= new_amount - old_amount,
+						Error::<T>::InsufficientBalance
+					);
+					let mut remain = new_amount - old_amount;
+					let free_deduction = min(account_data.free, remain);
+					account_data.free -= free_deduction;
+					remain -= free_deduction;
+					account_data.reserved -= remain;
+				} else {
+					account_data.free = account_data.free.saturating_add(old_amount - new_amount);
+				}
+				account_data.locked[index].amount = new_amount;
+				Ok((old_amount, new_amount))
+			})?;
+
+		if new_amount > old_amount {
+			Self::record_ledger_entry(user, LedgerEntryKind::Lock, new_amount - old_amount, None);
+		} else if new_amount < old_amount {
+			Self::record_ledger_entry(user, LedgerEntryKind::Unlock, old_amount - new_amount, None);
+		}
+
+		// Move the scheduled unlock, so the lock expires at the new block.
+		let old_expiry =
+			LockExpiries::<T>::get(user, lock_id).ok_or(Error::<T>::LockNotScheduled)?;
+		let new_expiry = old_expiry + extension;
+		if new_expiry != old_expiry {
+			Self::unschedule_unlock(user, lock_id);
+			AccountWithUnlockedFund::<T>::try_append(new_expiry, (user.clone(), lock_id))
+				.map_err(|_| Error::<T>::TooManyScheduled)?;
+			LockExpiries::<T>::insert(user, lock_id, new_expiry);
+		}
+
+		Self::deposit_event(Event::<T>::AuditorLockAmended {
+			user: user.clone(),
+			lock_id,
+			old_amount,
+			new_amount,
+			old_expiry,
+			new_expiry,
+		});
+		Ok(())
 	}
 
-	/// Return the block number the fund is unlocked at.
+	/// Return the block number the fund is unlocked at, or 0 if the lock is not scheduled.
 	pub fn fund_unlock_at(who: T::AccountId, lock_id: LockId) -> BlockNumberFor<T> {
-		let target = (who, lock_id);
-		AccountWithUnlockedFund::<T>::iter()
-			.find(|(_block_number, accounts)| accounts.contains(&target))
-			.map(|(block_number, _)| block_number)
-			.unwrap_or_default()
+		LockExpiries::<T>::get(who, lock_id).unwrap_or_default()
+	}
+
+	/// Returns the interest rate a deposit of `amount` earns in a product.
+	fn product_rate(product: &InterestProductOf<T>, amount: T::Balance) -> Perbill {
+		match &product.kind {
+			ProductKind::Tiered(tiers) => tiers
+				.iter()
+				.rev()
+				.find(|(threshold, _)| amount >= *threshold)
+				.map(|(_, rate)| *rate)
+				.unwrap_or_default(),
+			ProductKind::FixedTerm { rate, .. } => *rate,
+		}
+	}
+
+	/// Returns the simple interest a product deposit has earned up to a block.
+	fn product_interest(
+		deposit: &ProductDepositOf<T>,
+		block_number: BlockNumberFor<T>,
+	) -> T::Balance {
+		let product = match Products::<T>::get(deposit.product_id) {
+			Some(product) => product,
+			None => return Zero::zero(),
+		};
+		let end = deposit.maturity.map_or(block_number, |maturity| min(maturity, block_number));
+		FixedU128::from_perbill(Self::product_rate(&product, deposit.amount))
+			.saturating_mul(FixedU128::saturating_from_rational(
+				end.saturating_sub(deposit.start),
+				T::TotalBlocksPerYear::get(),
+			))
+			.saturating_mul_int(deposit.amount)
+	}
+
+	/// Remove the product lock from the user, and move the deposit to the user's "free". The
+	/// interest is minted, unless a fixed-term deposit is withdrawn early, in which case the
+	/// penalty is moved to the treasury instead.
+	fn settle_product_deposit(
+		user: &T::AccountId,
+		lock_id: LockId,
+		deposit: ProductDepositOf<T>,
+	) -> DispatchResult {
+		let block_number = frame_system::Pallet::<T>::current_block_number();
+		let early = deposit.maturity.map_or(false, |maturity| block_number < maturity);
+		let (interest, penalty) = if early {
+			let penalty = match Products::<T>::get(deposit.product_id).map(|product| product.kind) {
+				Some(ProductKind::FixedTerm { penalty, .. }) => penalty * deposit.amount,
+				_ => Zero::zero(),
+			};
+			(Zero::zero(), penalty)
+		} else {
+			(Self::product_interest(&deposit, block_number), Zero::zero())
+		};
+		let treasury = if penalty.is_zero() { None } else { Some(Self::treasury()?) };
+
+		Self::try_mutate_account(NATIVE_ASSET_ID, user, |account| -> DispatchResult {
+			let index = account
+				.locked
+				.iter()
+				.position(|lock| lock.id == lock_id && lock.reason == LockReason::Product)
+				.ok_or(Error::<T>::InvalidLockId)?;
+			account.locked.remove(index);
+			account.free = account
+				.free
+				.saturating_add(deposit.amount)
+				.saturating_add(interest)
+				.saturating_sub(penalty);
+			Ok(())
+		})?;
+		ProductDeposits::<T>::remove(lock_id);
+		Self::unschedule_unlock(user, lock_id);
+
+		if let Some(treasury) = treasury {
+			Self::mutate_account(NATIVE_ASSET_ID, &treasury, |account| {
+				account.free = account.free.saturating_add(penalty);
+			});
+			T::TreasuryIncome::record_income(IncomeSource::Penalty, penalty);
+		}
+		TotalIssuance::<T>::mutate(|total| {
+			*total = total.saturating_add(interest);
+		});
+
+		Self::deposit_event(Event::<T>::ProductRedeemed {
+			user: user.clone(),
+			product_id: deposit.product_id,
+			lock_id,
+			amount: deposit.amount,
+			interest,
+			penalty,
+		});
+		Ok(())
+	}
+
+	/// Accrue the borrowing interest of a loan up to the current block.
+	fn accrue_loan_interest(loan: &mut LoanOf<T>) {
+		let block_number = frame_system::Pallet::<T>::current_block_number();
+		let interest = FixedU128::from_perbill(loan.rate)
+			.saturating_mul(FixedU128::saturating_from_rational(
+				block_number.saturating_sub(loan.updated_at),
+				T::TotalBlocksPerYear::get(),
+			))
+			.saturating_mul_int(loan.principal);
+		loan.interest = loan.interest.saturating_add(interest);
+		loan.updated_at = block_number;
+	}
+
+	/// Returns true if the debt of a loan reached the liquidation threshold.
+	fn is_undercollateralised(loan: &LoanOf<T>) -> bool {
+		loan.principal.saturating_add(loan.interest) >=
+			T::LiquidationLoanToValue::get() * loan.collateral
+	}
+
+	/// Schedule the liquidation of a loan at the block its debt reaches the liquidation
+	/// threshold. Loans that do not accrue interest are never scheduled.
+	fn schedule_liquidation(lock_id: LockId, loan: &mut LoanOf<T>) {
+		let next_block = frame_system::Pallet::<T>::current_block_number() + One::one();
+		let interest_pa = loan.rate * loan.principal;
+		loan.liquidation_at = if Self::is_undercollateralised(loan) {
+			Some(next_block)
+		} else if interest_pa.is_zero() {
+			None
+		} else {
+			// The debt grows by `interest_pa` every year.
+			let headroom = (T::LiquidationLoanToValue::get() * loan.collateral)
+				.saturating_sub(loan.principal.saturating_add(loan.interest));
+			let length = FixedU128::saturating_from_rational(headroom, interest_pa)
+				.saturating_mul_int(T::TotalBlocksPerYear::get());
+			Some(max(loan.updated_at.saturating_add(length) + One::one(), next_block))
+		};
+		if let Some(mut liquidation_at) = loan.liquidation_at {
+			// Postpone the liquidation to the first block with room in the schedule.
+			while LoanLiquidationSchedule::<T>::try_append(liquidation_at, lock_id).is_err() {
+				liquidation_at = liquidation_at.saturating_add(One::one());
+			}
+			loan.liquidation_at = Some(liquidation_at);
+		}
+	}
+
+	/// Liquidate a loan that reached the liquidation threshold, moving its collateral to the
+	/// treasury.
+	fn liquidate(lock_id: LockId, mut loan: LoanOf<T>) -> DispatchResult {
+		Self::accrue_loan_interest(&mut loan);
+		ensure!(Self::is_undercollateralised(&loan), Error::<T>::LoanNotUndercollateralised);
+		let treasury = Self::treasury()?;
+
+		Self::try_mutate_account(NATIVE_ASSET_ID, &loan.borrower, |account| {
+			let index = account
+				.locked
+				.iter()
+				.position(|lock| lock.id == lock_id)
+				.ok_or(Error::<T>::InvalidLockId)?;
+			account.locked.remove(index);
+			Ok::<_, DispatchError>(())
+		})?;
+		Self::mutate_account(NATIVE_ASSET_ID, &treasury, |account| {
+			account.free = account.free.saturating_add(loan.collateral);
+		});
+		T::TreasuryIncome::record_income(IncomeSource::Penalty, loan.collateral);
+		Loans::<T>::remove(lock_id);
+
+		Self::deposit_event(Event::<T>::LoanLiquidated {
+			lock_id,
+			borrower: loan.borrower,
+			collateral: loan.collateral,
+			debt: loan.principal.saturating_add(loan.interest),
+		});
+		Ok(())
+	}
+
+	/// Returns the open loans of a user, with the interest accrued up to the current block.
+	pub fn loans_of(who: T::AccountId) -> Vec<(LockId, LoanOf<T>)> {
+		Loans::<T>::iter()
+			.filter(|(_, loan)| loan.borrower == who)
+			.map(|(lock_id, mut loan)| {
+				Self::accrue_loan_interest(&mut loan);
+				(lock_id, loan)
+			})
+			.collect()
+	}
+
+	/// Returns the product deposits of a user, with the interest earned so far.
+	pub fn product_deposits_of(
+		who: T::AccountId,
+	) -> Vec<(LockId, ProductDepositOf<T>, T::Balance)> {
+		let block_number = frame_system::Pallet::<T>::current_block_number();
+		Accounts::<T>::get(who)
+			.locked
+			.iter()
+			.filter(|lock| lock.reason == LockReason::Product)
+			.filter_map(|lock| {
+				ProductDeposits::<T>::get(lock.id).map(|deposit| {
+					let interest = Self::product_interest(&deposit, block_number);
+					(lock.id, deposit, interest)
+				})
+			})
+			.collect()
+	}
+
+	/// Returns the active standing orders paid by a user.
+	pub fn standing_orders_of(who: T::AccountId) -> Vec<(OrderId, StandingOrderOf<T>)> {
+		StandingOrders::<T>::iter().filter(|(_, order)| order.from == who).collect()
+	}
+
+	/// Returns the pending withdrawal requests of a user, or of all users if `who` is `None`.
+	pub fn withdrawal_requests_of(
+		who: Option<T::AccountId>,
+	) -> Vec<(LockId, WithdrawalRequestOf<T>)> {
+		WithdrawalRequests::<T>::iter()
+			.filter(|(_, request)| who.as_ref().map_or(true, |who| request.user == *who))
+			.collect()
 	}
 
 	/// Estimate the year interest depending on the current staked.
 	pub fn interest_pa(who: T::AccountId) -> T::Balance {
-		let initial_balance = Self::accounts(who).reserved;
-		let interest_rate_per_year = Self::interest_rate();
+		let initial_balance = Self::account_data(&who).reserved;
 
 		// Use the total blocks per year divide the interest payout period is the payout times.
 		// Switch the type of `payout_times` to usize.
@@ -782,13 +5339,25 @@ impl<T: Config> Pallet<T> {
 		.saturating_mul_int(1usize);
 
 		// Calculate the interest rate per payout time
-		let interest_rate_per_payout = interest_rate_per_year *
-			Perbill::from_rational(T::InterestPayoutPeriod::get(), T::TotalBlocksPerYear::get());
+		let interest_rate_per_payout = Self::interest_growth(T::InterestPayoutPeriod::get());
 
 		// Compounding interest formulae: A = P(1 + r / n) ^ n
-		let final_balance = (FixedU128::from_perbill(interest_rate_per_payout) + FixedU128::one())
+		let final_balance = (interest_rate_per_payout + FixedU128::one())
 			.saturating_pow(payout_times)
 			.saturating_mul_int(initial_balance);
-		final_balance - initial_balance
+
+		// Product deposits earn simple interest at the product's rate.
+		let product_interest = Accounts::<T>::get(&who)
+			.locked
+			.iter()
+			.filter(|lock| lock.reason == LockReason::Product)
+			.filter_map(|lock| ProductDeposits::<T>::get(lock.id))
+			.filter_map(|deposit| {
+				Products::<T>::get(deposit.product_id)
+					.map(|product| Self::product_rate(&product, deposit.amount) * deposit.amount)
+			})
+			.fold(Zero::zero(), |total: T::Balance, interest| total.saturating_add(interest));
+
+		final_balance - initial_balance + product_interest
 	}
 }
@@ -0,0 +1,414 @@
+//! Storage migrations of the bank pallet.
+
+use super::*;
+use frame_support::{defensive, storage_alias, traits::OnRuntimeUpgrade};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+pub mod v1 {
+	use super::*;
+
+	/// The unbounded storage of version 0.
+	mod v0 {
+		use super::*;
+
+		#[derive(Encode, Decode)]
+		pub struct AccountData<Balance> {
+			pub free: Balance,
+			pub reserved: Balance,
+			pub locked: Vec<LockedFund<Balance>>,
+		}
+
+		#[storage_alias]
+		pub type Accounts<T: Config> = StorageMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			<T as frame_system::Config>::AccountId,
+			AccountData<<T as Config>::Balance>,
+		>;
+
+		#[storage_alias]
+		pub type AssetAccounts<T: Config> = StorageDoubleMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			<T as frame_system::Config>::AccountId,
+			Blake2_128Concat,
+			AssetId,
+			AccountData<<T as Config>::Balance>,
+		>;
+
+		#[storage_alias]
+		pub type AccountWithUnlockedFund<T: Config> = StorageMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			BlockNumberFor<T>,
+			Vec<(<T as frame_system::Config>::AccountId, LockId)>,
+		>;
+
+		#[storage_alias]
+		pub type StandingOrderSchedule<T: Config> =
+			StorageMap<Pallet<T>, Blake2_128Concat, BlockNumberFor<T>, Vec<OrderId>>;
+
+		#[storage_alias]
+		pub type FreezeExpirySchedule<T: Config> = StorageMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			BlockNumberFor<T>,
+			Vec<<T as frame_system::Config>::AccountId>,
+		>;
+
+		#[storage_alias]
+		pub type LoanLiquidationSchedule<T: Config> =
+			StorageMap<Pallet<T>, Blake2_128Concat, BlockNumberFor<T>, Vec<LockId>>;
+
+		#[storage_alias]
+		pub type SponsoredCalls<T: Config> =
+			StorageValue<Pallet<T>, BTreeSet<(u8, u8)>, ValueQuery>;
+	}
+
+	/// Returns the number of items read, and whether any of them exceeds the bounds of version 1.
+	fn check_bounds<T: Config>() -> (u64, bool) {
+		let max_locks = T::MaxLocks::get() as usize;
+		let max_scheduled = T::MaxScheduledPerBlock::get() as usize;
+		let mut reads = 0u64;
+		let mut exceeded = false;
+		let mut check = |len: usize, max: usize| {
+			reads += 1;
+			exceeded |= len > max;
+		};
+
+		v0::Accounts::<T>::iter_values().for_each(|account| check(account.locked.len(), max_locks));
+		v0::AssetAccounts::<T>::iter_values()
+			.for_each(|account| check(account.locked.len(), max_locks));
+		v0::AccountWithUnlockedFund::<T>::iter_values()
+			.for_each(|scheduled| check(scheduled.len(), max_scheduled));
+		v0::StandingOrderSchedule::<T>::iter_values()
+			.for_each(|scheduled| check(scheduled.len(), max_scheduled));
+		v0::FreezeExpirySchedule::<T>::iter_values()
+			.for_each(|scheduled| check(scheduled.len(), max_scheduled));
+		v0::LoanLiquidationSchedule::<T>::iter_values()
+			.for_each(|scheduled| check(scheduled.len(), max_scheduled));
+		check(v0::SponsoredCalls::<T>::get().len(), T::MaxSponsoredCalls::get() as usize);
+
+		(reads, exceeded)
+	}
+
+	/// Returns the account without any overdraft debt.
+	fn migrate_account<T: Config>(account: v0::AccountData<T::Balance>) -> AccountDataOf<T> {
+		AccountData {
+			free: account.free,
+			reserved: account.reserved,
+			locked: BoundedVec::truncate_from(account.locked),
+			debt: Zero::zero(),
+		}
+	}
+
+	/// Migrates the storage from version 0 to 1.
+	///
+	/// The storage is bounded in version 1. The encoding of the bounded items is unchanged. The
+	/// accounts track their overdraft debt in version 1, and start without any debt.
+	///
+	/// Locks and scheduled items cannot be dropped without losing funds. If any of them exceeds
+	/// its bound, nothing is migrated and the storage version is not bumped. The upgrade logs a
+	/// defensive error, and `pre_upgrade` fails, so the bounds can be raised before the upgrade.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() != 0 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let (reads, exceeded) = check_bounds::<T>();
+			if exceeded {
+				defensive!(
+					"Some locks or scheduled items exceed their bounds, the bank storage is not \
+					 migrated to version 1."
+				);
+				return T::DbWeight::get().reads(reads + 1);
+			}
+
+			let mut translated = 0u64;
+			Accounts::<T>::translate::<v0::AccountData<T::Balance>, _>(|_, account| {
+				translated += 1;
+				Some(migrate_account::<T>(account))
+			});
+			AssetAccounts::<T>::translate::<v0::AccountData<T::Balance>, _>(|_, _, account| {
+				translated += 1;
+				Some(migrate_account::<T>(account))
+			});
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(reads + translated + 1, translated + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			ensure!(!check_bounds::<T>().1, "Some locks or scheduled items exceed their bounds.");
+			Ok(Vec::new())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(StorageVersion::get::<Pallet<T>>() == 1, "The storage version is not 1.");
+			Ok(())
+		}
+	}
+}
+
+pub mod v2 {
+	use super::*;
+
+	/// The ledger of version 1.
+	mod v1 {
+		use super::*;
+
+		#[derive(Encode, Decode)]
+		pub struct LedgerEntry<AccountId, Balance, BlockNumber> {
+			pub block: BlockNumber,
+			pub kind: LedgerEntryKind,
+			pub amount: Balance,
+			pub counterparty: Option<AccountId>,
+			pub free: Balance,
+			pub reserved: Balance,
+		}
+
+		pub type LedgerEntryOf<T> = LedgerEntry<
+			<T as frame_system::Config>::AccountId,
+			<T as Config>::Balance,
+			BlockNumberFor<T>,
+		>;
+
+		#[storage_alias]
+		pub type LedgerEntries<T: Config> = StorageDoubleMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			<T as frame_system::Config>::AccountId,
+			Twox64Concat,
+			u32,
+			LedgerEntryOf<T>,
+		>;
+	}
+
+	/// Migrates the storage from version 1 to 2.
+	///
+	/// Ledger entries record the reference of the payment in version 2. The existing entries are
+	/// kept without a reference.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() != 1 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut translated = 0u64;
+			LedgerEntries::<T>::translate::<v1::LedgerEntryOf<T>, _>(|_, _, entry| {
+				translated += 1;
+				Some(LedgerEntry {
+					block: entry.block,
+					kind: entry.kind,
+					amount: entry.amount,
+					counterparty: entry.counterparty,
+					reference: None,
+					free: entry.free,
+					reserved: entry.reserved,
+				})
+			});
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok((v1::LedgerEntries::<T>::iter().count() as u64).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(StorageVersion::get::<Pallet<T>>() == 2, "The storage version is not 2.");
+			let count = u64::decode(&mut &state[..])
+				.map_err(|_| TryRuntimeError::Other("Failed to decode the ledger size."))?;
+			ensure!(
+				LedgerEntries::<T>::iter().count() as u64 == count,
+				"Some ledger entries failed to migrate."
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v3 {
+	use super::*;
+
+	/// Migrates the storage from version 2 to 3.
+	///
+	/// The expiry of every scheduled lock is stored in `LockExpiries` in version 3, so it can be
+	/// found without searching the schedule. The expiries are copied from the schedule.
+	pub struct MigrateToV3<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() != 2 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut reads = 0u64;
+			let mut writes = 0u64;
+			AccountWithUnlockedFund::<T>::iter().for_each(|(unlock, accounts)| {
+				reads += 1;
+				accounts.into_iter().for_each(|(user, lock_id)| {
+					writes += 1;
+					LockExpiries::<T>::insert(user, lock_id, unlock);
+				});
+			});
+			StorageVersion::new(3).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(reads + 1, writes + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(StorageVersion::get::<Pallet<T>>() == 3, "The storage version is not 3.");
+			for (unlock, accounts) in AccountWithUnlockedFund::<T>::iter() {
+				for (user, lock_id) in accounts {
+					ensure!(
+						LockExpiries::<T>::get(&user, lock_id) == Some(unlock),
+						"Some lock expiries failed to migrate."
+					);
+				}
+			}
+			Ok(())
+		}
+	}
+}
+
+pub mod v4 {
+	use super::*;
+
+	/// The interest rate history of version 3.
+	mod v3 {
+		use super::*;
+
+		#[storage_alias]
+		pub type InterestRateHistory<T: Config> =
+			StorageValue<Pallet<T>, Vec<(BlockNumberFor<T>, Perbill)>, ValueQuery>;
+	}
+
+	/// Migrates the storage from version 3 to 4.
+	///
+	/// The full interest rate history is kept in `InterestRateChanges` in version 4, by the block
+	/// each rate took effect at. The rates are moved from the history kept before.
+	pub struct MigrateToV4<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() != 3 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let history = v3::InterestRateHistory::<T>::take();
+			let moved = history.len() as u64;
+			history.into_iter().for_each(|(block, interest_rate)| {
+				InterestRateChanges::<T>::insert(block, interest_rate);
+			});
+			StorageVersion::new(4).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(2, moved + 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok((v3::InterestRateHistory::<T>::decode_len().unwrap_or_default() as u64).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(StorageVersion::get::<Pallet<T>>() == 4, "The storage version is not 4.");
+			let count = u64::decode(&mut &state[..])
+				.map_err(|_| TryRuntimeError::Other("Failed to decode the history size."))?;
+			ensure!(
+				InterestRateChanges::<T>::iter().count() as u64 == count,
+				"Some interest rates failed to migrate."
+			);
+			ensure!(!v3::InterestRateHistory::<T>::exists(), "The old history is not removed.");
+			Ok(())
+		}
+	}
+}
+
+pub mod v5 {
+	use super::*;
+
+	/// The joint transfers of version 4.
+	mod v4 {
+		use super::*;
+
+		#[derive(Encode, Decode)]
+		pub struct JointTransfer<AccountId: Ord, Balance, MaxHolders: Get<u32>> {
+			pub joint_account: AccountId,
+			pub to: AccountId,
+			pub amount: Balance,
+			pub approvals: BoundedBTreeSet<AccountId, MaxHolders>,
+			pub rejections: BoundedBTreeSet<AccountId, MaxHolders>,
+		}
+
+		pub type JointTransferOf<T> = JointTransfer<
+			<T as frame_system::Config>::AccountId,
+			<T as Config>::Balance,
+			<T as Config>::MaxJointHolders,
+		>;
+	}
+
+	/// Migrates the storage from version 4 to 5.
+	///
+	/// Joint transfers expire in version 5. The pending transfers can be voted on for another
+	/// `JointTransferPeriod` blocks.
+	pub struct MigrateToV5<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV5<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() != 4 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let expiry =
+				frame_system::Pallet::<T>::current_block_number() + T::JointTransferPeriod::get();
+			let mut translated = 0u64;
+			JointTransfers::<T>::translate::<v4::JointTransferOf<T>, _>(|_, transfer| {
+				translated += 1;
+				Some(JointTransfer {
+					joint_account: transfer.joint_account,
+					to: transfer.to,
+					amount: transfer.amount,
+					approvals: transfer.approvals,
+					rejections: transfer.rejections,
+					expiry,
+				})
+			});
+			StorageVersion::new(5).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(translated + 2, translated + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok((JointTransfers::<T>::iter_keys().count() as u64).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(StorageVersion::get::<Pallet<T>>() == 5, "The storage version is not 5.");
+			let count = u64::decode(&mut &state[..]).map_err(|_| {
+				TryRuntimeError::Other("Failed to decode the joint transfer count.")
+			})?;
+			ensure!(
+				JointTransfers::<T>::iter().count() as u64 == count,
+				"Some joint transfers failed to migrate."
+			);
+			Ok(())
+		}
+	}
+}
@@ -63,6 +63,13 @@ parameter_types! {
 	pub const StakePeriod: BlockNumber = STAKE_PERIOD;
 	pub const InterestPayoutPeriod: BlockNumber = INTEREST_PAYOUT_PERIOD;
 	pub const TotalBlocksPerYear: BlockNumber = YEAR as BlockNumber;
+	pub const InterestRateNoticePeriod: BlockNumber = 10;
+	pub const WithdrawalRequestPeriod: BlockNumber = 10;
+	pub const JointTransferPeriod: BlockNumber = 10;
+	pub const BlocksPerDay: BlockNumber = 10;
+	pub const BlocksPerWeek: BlockNumber = 70;
+	pub const MaxLoanToValue: Perbill = Perbill::from_percent(50);
+	pub const LiquidationLoanToValue: Perbill = Perbill::from_percent(80);
 }
 
 impl Config for Runtime {
@@ -71,12 +78,30 @@ impl Config for Runtime {
 	type Balance = Balance;
 	type RoleManager = Roles;
 	type EnsureGovernance = traits::SuccessOrigin<Runtime>;
+	type TreasuryIncome = ();
+	type RuntimeHoldReason = ();
 	type ExistentialDeposit = ExistentialDeposit;
 	type MinimumAmount = MinimumAmount;
 	type RedeemPeriod = RedeemPeriod;
 	type StakePeriod = StakePeriod;
 	type InterestPayoutPeriod = InterestPayoutPeriod;
 	type TotalBlocksPerYear = TotalBlocksPerYear;
+	type MaxInterestPayoutsPerBlock = ConstU32<2>;
+	type InterestRateNoticePeriod = InterestRateNoticePeriod;
+	type WithdrawalRequestPeriod = WithdrawalRequestPeriod;
+	type JointTransferPeriod = JointTransferPeriod;
+	type BlocksPerDay = BlocksPerDay;
+	type BlocksPerWeek = BlocksPerWeek;
+	type MaxLoanToValue = MaxLoanToValue;
+	type LiquidationLoanToValue = LiquidationLoanToValue;
+	type MaxReapsPerBlock = ConstU32<2>;
+	type MaxStandingOrderRetries = ConstU32<2>;
+	type MaxLedgerEntries = ConstU32<5>;
+	type MaxJointHolders = ConstU32<3>;
+	type MaxBatchTransfers = ConstU32<3>;
+	type MaxLocks = ConstU32<10>;
+	type MaxScheduledPerBlock = ConstU32<10>;
+	type MaxSponsoredCalls = ConstU32<10>;
 }
 
 impl pallet_roles::Config for Runtime {
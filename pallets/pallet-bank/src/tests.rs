@@ -5,12 +5,12 @@
 use crate::{
 	mock::{
 		default_test_ext, AccountId, Balance, Bank, MockGenesisConfig, Roles, Runtime,
-		RuntimeEvent, RuntimeOrigin, StakePeriod, System, ALICE, BOB, INTEREST_PAYOUT_PERIOD,
-		REDEEM_PERIOD, STAKE_PERIOD, TREASURY,
+		RuntimeEvent, RuntimeOrigin, StakePeriod, System, ALICE, BOB, ED, INITIAL_BALANCE,
+		INTEREST_PAYOUT_PERIOD, REDEEM_PERIOD, STAKE_PERIOD, TREASURY,
 	},
 	*,
 };
-use frame_support::{assert_err, assert_noop, assert_ok};
+use frame_support::{assert_err, assert_noop, assert_ok, bounded_vec};
 use frame_system::RawOrigin;
 use primitives::YEAR;
 
@@ -175,14 +175,30 @@ fn can_reap_accounts() {
 		assert_ok!(Bank::transfer(RuntimeOrigin::signed(BOB), ALICE, 98));
 		assert_eq!(Accounts::<Runtime>::get(BOB).free, 2);
 		assert_eq!(Accounts::<Runtime>::get(ALICE).free, 98);
+		assert_ok!(Bank::approve(RuntimeOrigin::signed(BOB), ALICE, 50, None));
+		assert_ok!(Bank::set_overdraft_limit(RuntimeOrigin::signed(charlie), BOB, 50));
+		assert_ok!(Bank::set_velocity_limit(
+			RuntimeOrigin::signed(charlie),
+			BOB,
+			Some(VelocityLimit { daily: 100, weekly: 100 })
+		));
 
 		System::reset_events();
-		Bank::on_finalize(1);
+		Bank::on_initialize(1);
 		System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::Reaped {
 			user: BOB,
 			dust: 2,
 		}));
 		assert_eq!(Accounts::<Runtime>::get(BOB), Default::default());
+
+		// The storage of the account is cleared, and its ledger only records the reaping.
+		assert_eq!(Bank::allowances(BOB, ALICE), None);
+		assert_eq!(Bank::overdraft_limits(BOB), 0);
+		assert_eq!(Bank::velocity_limits(BOB), None);
+		let statement = Bank::account_statement(BOB, 0, 1_000, 0, MAX_STATEMENT_PAGE_SIZE);
+		assert_eq!(statement.len(), 1);
+		assert_eq!(statement[0].kind, LedgerEntryKind::Reaped);
+		assert_eq!(statement[0].free, 2);
 		let treasury = Bank::treasury().expect("Treasury account must be set.");
 
 		assert_eq!(Accounts::<Runtime>::get(treasury).free, 1_000_002);
@@ -203,12 +219,12 @@ fn cannot_reap_accounts_without_setting_treasury_account() {
 		assert_ok!(Bank::transfer(RuntimeOrigin::signed(BOB), ALICE, 98));
 		assert_eq!(Accounts::<Runtime>::get(BOB).free, 2);
 		// cannot reap accounts when Treasury is not set.
-		Bank::on_finalize(1);
+		Bank::on_initialize(1);
 		assert_eq!(Accounts::<Runtime>::get(BOB).free, 2);
 		// After setting TreasuryAccount, reap_account is working.
 		TreasuryAccount::<Runtime>::set(Some(TREASURY));
 		System::reset_events();
-		Bank::on_finalize(1);
+		Bank::on_initialize(1);
 		System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::Reaped {
 			user: BOB,
 			dust: 2,
@@ -221,6 +237,79 @@ fn cannot_reap_accounts_without_setting_treasury_account() {
 	});
 }
 
+#[test]
+fn reaping_is_bounded_per_block() {
+	let charlie: AccountId = 3u32;
+	let dave: AccountId = 4u32;
+	let eve: AccountId = 5u32;
+	MockGenesisConfig::default()
+		.with_balances(vec![
+			(ALICE, 1_000, 0),
+			(BOB, 1_000, 0),
+			(charlie, 1_000, 0),
+			(dave, 1_000, 0),
+		])
+		.build()
+		.execute_with(|| {
+			TreasuryAccount::<Runtime>::set(Some(TREASURY));
+			assert_ok!(Roles::register_role(&eve, Role::Customer));
+
+			// Only accounts whose balance dropped below ED become candidates.
+			assert_ok!(Bank::transfer(RuntimeOrigin::signed(ALICE), eve, 998));
+			assert_ok!(Bank::transfer(RuntimeOrigin::signed(BOB), eve, 998));
+			assert_ok!(Bank::transfer(RuntimeOrigin::signed(charlie), eve, 998));
+			assert_ok!(Bank::transfer(RuntimeOrigin::signed(dave), eve, 500));
+			assert_eq!(DustCandidates::<Runtime>::iter().count(), 3);
+
+			// A candidate topped back up above ED is dropped without being reaped.
+			assert_ok!(Bank::transfer(RuntimeOrigin::signed(eve), charlie, 100));
+			assert_eq!(DustCandidates::<Runtime>::iter().count(), 3);
+
+			// At most `MaxReapsPerBlock` candidates are visited per block.
+			Bank::on_initialize(1);
+			assert_eq!(DustCandidates::<Runtime>::iter().count(), 1);
+			Bank::on_initialize(2);
+			assert_eq!(DustCandidates::<Runtime>::iter().count(), 0);
+
+			assert_eq!(Accounts::<Runtime>::get(ALICE), Default::default());
+			assert_eq!(Accounts::<Runtime>::get(BOB), Default::default());
+			assert_eq!(Accounts::<Runtime>::get(charlie).free, 102);
+			assert_eq!(Accounts::<Runtime>::get(dave).free, 500);
+			assert_eq!(Accounts::<Runtime>::get(TREASURY).free, 1_000_004);
+			assert!(Bank::check_total_issuance());
+		});
+}
+
+#[test]
+fn can_reap_drained_accounts() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0), (BOB, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			TreasuryAccount::<Runtime>::set(Some(TREASURY));
+			assert_eq!(System::providers(&ALICE), 1);
+
+			// An account drained to zero is reaped, and its provider reference is dropped.
+			assert_ok!(Bank::transfer(RuntimeOrigin::signed(ALICE), BOB, 1_000));
+			assert!(Accounts::<Runtime>::contains_key(ALICE));
+			Bank::on_initialize(1);
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::Reaped {
+				user: ALICE,
+				dust: 0,
+			}));
+			assert!(!Accounts::<Runtime>::contains_key(ALICE));
+			assert_eq!(System::providers(&ALICE), 0);
+			assert_eq!(Accounts::<Runtime>::get(TREASURY).free, 1_000_000);
+
+			// A removed account is not reaped again.
+			DustCandidates::<Runtime>::insert(ALICE, NATIVE_ASSET_ID, ());
+			System::reset_events();
+			Bank::on_initialize(2);
+			assert!(System::events().is_empty());
+			assert!(Bank::check_total_issuance());
+		});
+}
+
 #[test]
 fn can_stake_funds() {
 	MockGenesisConfig::default()
@@ -265,7 +354,12 @@ fn can_redeem_funds() {
 				AccountData {
 					free: 0,
 					reserved: 800,
-					locked: vec![LockedFund { id: 2, amount: 200, reason: LockReason::Redeem }]
+					locked: bounded_vec![LockedFund {
+						id: 2,
+						amount: 200,
+						reason: LockReason::Redeem
+					}],
+					debt: 0
 				}
 			);
 
@@ -301,7 +395,12 @@ fn auditor_can_lock_funds() {
 				AccountData {
 					free: 0,
 					reserved: 800,
-					locked: vec![LockedFund { id: 2, amount: 200, reason: LockReason::Auditor }]
+					locked: bounded_vec![LockedFund {
+						id: 2,
+						amount: 200,
+						reason: LockReason::Auditor
+					}],
+					debt: 0
 				}
 			);
 
@@ -333,10 +432,11 @@ fn auditor_can_unlock_funds() {
 				AccountData {
 					free: 0,
 					reserved: 300,
-					locked: vec![
+					locked: bounded_vec![
 						LockedFund { id: 2, amount: 200, reason: LockReason::Auditor },
 						LockedFund { id: 3, amount: 500, reason: LockReason::Redeem }
-					]
+					],
+					debt: 0
 				}
 			);
 
@@ -358,7 +458,12 @@ fn auditor_can_unlock_funds() {
 				AccountData {
 					free: 200,
 					reserved: 300,
-					locked: vec![LockedFund { id: 3, amount: 500, reason: LockReason::Redeem }]
+					locked: bounded_vec![LockedFund {
+						id: 3,
+						amount: 500,
+						reason: LockReason::Redeem
+					}],
+					debt: 0
 				}
 			);
 			assert!(Bank::check_total_issuance());
@@ -405,7 +510,12 @@ fn incorrect_role_cannot_call_auditor_function() {
 				AccountData {
 					free: 0,
 					reserved: 800,
-					locked: vec![LockedFund { id: 2, amount: 200, reason: LockReason::Auditor },]
+					locked: bounded_vec![LockedFund {
+						id: 2,
+						amount: 200,
+						reason: LockReason::Auditor
+					},],
+					debt: 0
 				}
 			);
 			assert!(Bank::check_total_issuance());
@@ -428,25 +538,156 @@ fn auditor_cannot_unlock_invalid_id() {
 }
 
 #[test]
-fn manager_can_set_interest_rate() {
+fn manager_can_schedule_interest_rate() {
 	MockGenesisConfig::default()
 		.with_balances(vec![(ALICE, 1_000, 0)])
 		.build()
 		.execute_with(|| {
 			let charlie: AccountId = 3u32;
 			assert_ok!(Roles::register_role(&charlie, Role::Manager));
-			assert_ok!(Bank::set_interest_rate(RuntimeOrigin::signed(charlie), 500));
+			assert_noop!(
+				Bank::schedule_interest_rate(RuntimeOrigin::signed(charlie), 500, 10),
+				Error::<Runtime>::InterestRateNoticeTooShort
+			);
+			assert_ok!(Bank::schedule_interest_rate(RuntimeOrigin::signed(charlie), 500, 11));
+			System::assert_last_event(RuntimeEvent::Bank(
+				Event::<Runtime>::InterestRateScheduled {
+					manager: charlie,
+					interest_rate: Perbill::from_percent(5),
+					effective_at: 11,
+				},
+			));
+
+			// The rate takes effect at the scheduled block.
+			Bank::on_finalize(10);
+			assert!(InterestRate::<Runtime>::get().is_zero());
+			Bank::on_finalize(11);
 			assert_eq!(InterestRate::<Runtime>::get(), Perbill::from_percent(5));
+			assert_eq!(Bank::next_interest_rate(), None);
 			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::InterestRateSet {
 				manager: charlie,
 				old_interest_rate: Perbill::from_percent(0),
 				new_interest_rate: Perbill::from_percent(5),
 			}));
+			assert_eq!(
+				Bank::interest_rate_history(0, 1_000, 0, MAX_STATEMENT_PAGE_SIZE),
+				vec![(11, Perbill::from_percent(5))]
+			);
+		});
+}
+
+#[test]
+fn set_interest_rate_schedules_after_the_notice_period() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Manager));
+			assert_noop!(
+				Bank::set_interest_rate(RuntimeOrigin::signed(ALICE), 500),
+				pallet_roles::Error::<Runtime>::IncorrectRole
+			);
+			assert_noop!(
+				Bank::set_interest_rate(RuntimeOrigin::signed(charlie), 10_001),
+				Error::<Runtime>::InvalidInterestRate
+			);
+
+			assert_ok!(Bank::set_interest_rate(RuntimeOrigin::signed(charlie), 500));
+			System::assert_last_event(RuntimeEvent::Bank(
+				Event::<Runtime>::InterestRateScheduled {
+					manager: charlie,
+					interest_rate: Perbill::from_percent(5),
+					effective_at: 11,
+				},
+			));
+			// The rate is not applied before the notice period.
+			Bank::on_finalize(1);
+			assert!(InterestRate::<Runtime>::get().is_zero());
+			Bank::on_finalize(11);
+			assert_eq!(InterestRate::<Runtime>::get(), Perbill::from_percent(5));
+			assert_eq!(
+				Bank::interest_rate_history(0, 1_000, 0, MAX_STATEMENT_PAGE_SIZE),
+				vec![(11, Perbill::from_percent(5))]
+			);
+		});
+}
+
+#[test]
+fn governance_can_veto_and_bound_interest_rates() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Manager));
+			assert_noop!(
+				Bank::veto_interest_rate(RawOrigin::Root.into()),
+				Error::<Runtime>::NoScheduledInterestRate
+			);
+
+			// A vetoed rate never takes effect.
+			assert_ok!(Bank::schedule_interest_rate(RuntimeOrigin::signed(charlie), 500, 20));
+			assert_ok!(Bank::veto_interest_rate(RawOrigin::Root.into()));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::InterestRateVetoed {
+				interest_rate: Perbill::from_percent(5),
+				effective_at: 20,
+			}));
+			Bank::on_finalize(20);
+			assert!(InterestRate::<Runtime>::get().is_zero());
+			assert!(Bank::interest_rate_history(0, 1_000, 0, MAX_STATEMENT_PAGE_SIZE).is_empty());
+
+			let bounds = InterestRateBounds {
+				min: Perbill::from_percent(1),
+				max: Perbill::from_percent(8),
+				max_change: Perbill::from_percent(3),
+			};
+			assert_noop!(
+				Bank::set_interest_rate_bounds(
+					RawOrigin::Root.into(),
+					Some(InterestRateBounds { min: bounds.max, max: bounds.min, ..bounds })
+				),
+				Error::<Runtime>::InvalidInterestRate
+			);
+			assert_ok!(Bank::set_interest_rate_bounds(RawOrigin::Root.into(), Some(bounds)));
+			System::assert_last_event(RuntimeEvent::Bank(
+				Event::<Runtime>::InterestRateBoundsSet { bounds: Some(bounds) },
+			));
+
+			// The rate must be within the bounds, and move at most the maximum change.
+			assert_noop!(
+				Bank::schedule_interest_rate(RuntimeOrigin::signed(charlie), 0, 30),
+				Error::<Runtime>::InterestRateOutOfBounds
+			);
+			assert_noop!(
+				Bank::schedule_interest_rate(RuntimeOrigin::signed(charlie), 400, 30),
+				Error::<Runtime>::InterestRateOutOfBounds
+			);
+			assert_ok!(Bank::schedule_interest_rate(RuntimeOrigin::signed(charlie), 300, 30));
+			Bank::on_finalize(30);
+			assert_noop!(
+				Bank::schedule_interest_rate(RuntimeOrigin::signed(charlie), 900, 40),
+				Error::<Runtime>::InterestRateOutOfBounds
+			);
+			assert_ok!(Bank::schedule_interest_rate(RuntimeOrigin::signed(charlie), 600, 40));
+			Bank::on_finalize(40);
+
+			assert_ok!(Bank::set_interest_rate_bounds(RawOrigin::Root.into(), None));
+			assert_ok!(Bank::schedule_interest_rate(RuntimeOrigin::signed(charlie), 0, 50));
+			Bank::on_finalize(50);
+			assert_eq!(
+				Bank::interest_rate_history(0, 1_000, 0, MAX_STATEMENT_PAGE_SIZE),
+				vec![
+					(30, Perbill::from_percent(3)),
+					(40, Perbill::from_percent(6)),
+					(50, Perbill::from_percent(0)),
+				]
+			);
 		});
 }
 
 #[test]
-fn incorrect_role_cannot_set_interest_rate() {
+fn incorrect_role_cannot_schedule_interest_rate() {
 	MockGenesisConfig::default()
 		.with_balances(vec![(ALICE, 1_000, 0)])
 		.build()
@@ -454,14 +695,14 @@ fn incorrect_role_cannot_set_interest_rate() {
 			let charlie: AccountId = 3u32;
 			assert_ok!(Roles::register_role(&charlie, Role::Auditor));
 			assert_err!(
-				Bank::set_interest_rate(RuntimeOrigin::signed(charlie), 500),
+				Bank::schedule_interest_rate(RuntimeOrigin::signed(charlie), 500, 11),
 				pallet_roles::Error::<Runtime>::IncorrectRole
 			);
 			assert_err!(
-				Bank::set_interest_rate(RuntimeOrigin::signed(ALICE), 500),
+				Bank::schedule_interest_rate(RuntimeOrigin::signed(ALICE), 500, 11),
 				pallet_roles::Error::<Runtime>::IncorrectRole
 			);
-			assert!(InterestRate::<Runtime>::get().is_zero());
+			assert_eq!(Bank::next_interest_rate(), None);
 		});
 }
 
@@ -471,19 +712,104 @@ fn pay_interest() {
 		.with_balances(vec![(ALICE, 1_000_000_000, 0)])
 		.build()
 		.execute_with(|| {
-			let charlie: AccountId = 3u32;
-			assert_ok!(Roles::register_role(&charlie, Role::Manager));
-			assert_ok!(Bank::set_interest_rate(RuntimeOrigin::signed(charlie), 500));
+			InterestRate::<Runtime>::set(Perbill::from_percent(5));
 			stake(ALICE, 1_000_000_000);
-			Bank::on_finalize(INTEREST_PAYOUT_PERIOD);
+
+			System::set_block_number(1 + INTEREST_PAYOUT_PERIOD);
+			// The interest is accrued but not realised.
 			assert_eq!(
-				Accounts::<Runtime>::get(ALICE),
-				AccountData { free: 0, reserved: 1_000_000_951, locked: vec![] }
+				Bank::account_data(&ALICE),
+				AccountData { free: 0, reserved: 1_000_000_951, locked: bounded_vec![], debt: 0 }
 			);
+			assert_eq!(Accounts::<Runtime>::get(ALICE).reserved, 1_000_000_000);
+
+			// The interest is realised when the reserved is touched.
+			assert_ok!(Bank::redeem_funds(RuntimeOrigin::signed(ALICE), 951));
+			System::assert_has_event(RuntimeEvent::Bank(Event::<Runtime>::InterestAccrued {
+				user: ALICE,
+				amount: 951,
+			}));
+			assert_eq!(Accounts::<Runtime>::get(ALICE).reserved, 1_000_000_000);
+			assert_eq!(Bank::interest_snapshot(ALICE), Bank::interest_index_at(101));
+			assert!(Bank::check_total_issuance());
+		});
+}
+
+#[test]
+fn interest_accrues_pro_rata_and_compounds() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 0, 1_000_000_000)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Manager));
+			InterestRate::<Runtime>::set(Perbill::from_percent(5));
+			assert_ok!(Bank::schedule_interest_rate(RuntimeOrigin::signed(charlie), 1_000, 101));
+
+			// Interest accrues every block.
+			System::set_block_number(51);
+			assert_eq!(Bank::account_data(&ALICE).reserved, 1_000_000_475);
+
+			// Changing the interest rate does not affect the interest accrued so far.
+			System::set_block_number(101);
+			Bank::on_finalize(101);
+			assert_eq!(Bank::account_data(&ALICE).reserved, 1_000_000_951);
+
+			System::set_block_number(201);
+			assert_eq!(Bank::account_data(&ALICE).reserved, 1_000_002_853);
+
+			// The interest compounds at the end of each payout period.
+			Bank::on_finalize(200);
+			assert_eq!(InterestIndexUpdatedAt::<Runtime>::get(), 200);
+			assert_eq!(Bank::account_data(&ALICE).reserved, 1_000_002_853);
+
+			assert_ok!(Bank::redeem_funds(RuntimeOrigin::signed(ALICE), 1_000));
+			assert_eq!(Accounts::<Runtime>::get(ALICE).reserved, 1_000_001_853);
+			assert!(Bank::check_total_issuance());
+		});
+}
+
+#[test]
+fn pay_interest_over_multiple_blocks() {
+	MockGenesisConfig::default()
+		.with_balances(vec![
+			(ALICE, 0, 1_000_000_000),
+			(BOB, 0, 1_000_000_000),
+			(3, 0, 1_000_000_000),
+			(4, 0, 1_000_000_000),
+		])
+		.build()
+		.execute_with(|| {
+			InterestRate::<Runtime>::set(Perbill::from_percent(5));
+			System::set_block_number(INTEREST_PAYOUT_PERIOD);
+			System::reset_events();
+
+			// 5 accounts including the treasury, 2 accounts are paid per block.
+			Bank::on_finalize(INTEREST_PAYOUT_PERIOD);
+			assert_eq!(Bank::interest_payout_queue().len(), 1);
+			Bank::on_finalize(INTEREST_PAYOUT_PERIOD + 1);
+			assert_eq!(Bank::interest_payout_queue().len(), 1);
+			assert!(Bank::check_total_issuance());
+
+			Bank::on_finalize(INTEREST_PAYOUT_PERIOD + 2);
 			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::InterestPayed {
 				interest_rate: Perbill::from_percent(5),
-				total_interest_payed: 951,
+				total_interest_payed: 951 * 4,
 			}));
+			assert!(Bank::interest_payout_queue().is_empty());
+			[ALICE, BOB, 3, 4].into_iter().for_each(|user| {
+				assert_eq!(Accounts::<Runtime>::get(user).reserved, 1_000_000_951);
+			});
+			assert!(Bank::check_total_issuance());
+
+			// Accounts touched before the payout reaches them are not paid twice.
+			System::set_block_number(2 * INTEREST_PAYOUT_PERIOD);
+			Bank::on_finalize(2 * INTEREST_PAYOUT_PERIOD);
+			assert_ok!(Bank::redeem_funds(RuntimeOrigin::signed(ALICE), 951));
+			Bank::on_finalize(2 * INTEREST_PAYOUT_PERIOD + 1);
+			Bank::on_finalize(2 * INTEREST_PAYOUT_PERIOD + 2);
+			assert!(Bank::interest_payout_queue().is_empty());
+			assert_eq!(Accounts::<Runtime>::get(ALICE).reserved, 1_000_000_951);
 			assert!(Bank::check_total_issuance());
 		});
 }
@@ -501,10 +827,11 @@ fn can_rotate_treasury() {
 			let account_data = AccountData {
 				free: 1_000_000_000,
 				reserved: 500,
-				locked: vec![
+				locked: bounded_vec![
 					LockedFund { id: 1, amount: 1_000, reason: LockReason::Auditor },
 					LockedFund { id: 2, amount: 2_000, reason: LockReason::Redeem },
 				],
+				debt: 0,
 			};
 			Accounts::<Runtime>::insert(TREASURY, account_data.clone());
 			assert_eq!(Bank::treasury(), Ok(TREASURY));
@@ -664,16 +991,14 @@ fn can_check_fund_unlock_at() {
 			// Verify
 			assert_eq!(unlock_block, Bank::fund_unlock_at(ALICE, 1));
 
-			// Check it works with `AccountWithUnlockedFund` stores with multiple lock id.
+			// Check it works with `LockExpiries` stores with multiple lock id.
 			let charlie: AccountId = 3u32;
-			AccountWithUnlockedFund::<Runtime>::insert(
-				10,
-				vec![(ALICE, 2), (BOB, 3), (charlie, 4), (ALICE, 5), (ALICE, 6)],
-			);
-			AccountWithUnlockedFund::<Runtime>::insert(
-				20,
-				vec![(ALICE, 7), (charlie, 8), (BOB, 9), (ALICE, 10)],
-			);
+			[(ALICE, 2), (BOB, 3), (charlie, 4), (ALICE, 5), (ALICE, 6)]
+				.into_iter()
+				.for_each(|(who, lock_id)| LockExpiries::<Runtime>::insert(who, lock_id, 10));
+			[(ALICE, 7), (charlie, 8), (BOB, 9), (ALICE, 10)]
+				.into_iter()
+				.for_each(|(who, lock_id)| LockExpiries::<Runtime>::insert(who, lock_id, 20));
 
 			// Verify
 			assert_eq!(10, Bank::fund_unlock_at(ALICE, 2));
@@ -721,7 +1046,13 @@ fn calculate_interest_pa_with_interest_rate(initial_balance: Balance, interest_r
 	let payout_times = YEAR / INTEREST_PAYOUT_PERIOD as u32;
 	let interest_rate = Perbill::from_rational(interest_rate_bps, 10_000u32);
 
+	// Start from the end of a payout period, so the interest index is up to date.
+	let start = (System::block_number() / INTEREST_PAYOUT_PERIOD + 1) * INTEREST_PAYOUT_PERIOD;
+	System::set_block_number(start);
+	Bank::on_finalize(start);
+
 	Accounts::<Runtime>::mutate(ALICE, |account_data| account_data.reserved = initial_balance);
+	InterestSnapshot::<Runtime>::insert(ALICE, Bank::interest_index());
 
 	InterestRate::<Runtime>::set(interest_rate);
 
@@ -729,10 +1060,11 @@ fn calculate_interest_pa_with_interest_rate(initial_balance: Balance, interest_r
 
 	// Simulating the real interest as it is accumulated through the year
 	for _ in 0..payout_times {
-		Bank::on_finalize(INTEREST_PAYOUT_PERIOD);
+		System::set_block_number(System::block_number() + INTEREST_PAYOUT_PERIOD);
+		Bank::on_finalize(System::block_number());
 	}
 
-	let actual_interest = Bank::accounts(ALICE).reserved - initial_balance;
+	let actual_interest = Bank::account_data(&ALICE).reserved - initial_balance;
 
 	assert_eq_with_precision(expect_interest, actual_interest, 1_000_000u128);
 }
@@ -745,3 +1077,2632 @@ fn assert_eq_with_precision<N: Eq + sp_std::ops::Div<Output = N> + Debug + std::
 ) {
 	assert_eq!(a / precision, b / precision);
 }
+
+// Register an asset with the same existential deposit as the native currency.
+fn register_euro() -> AssetId {
+	assert_ok!(Bank::register_asset(
+		RawOrigin::Root.into(),
+		b"Euro".to_vec(),
+		b"EUR".to_vec(),
+		12u8,
+		ED
+	));
+	1u32
+}
+
+#[test]
+fn can_register_asset() {
+	default_test_ext().execute_with(|| {
+		let charlie: AccountId = 3u32;
+		assert_ok!(Roles::register_role(&charlie, Role::Manager));
+		assert_noop!(
+			Bank::deposit_asset(RuntimeOrigin::signed(charlie), 1u32, TREASURY, 100),
+			Error::<Runtime>::AssetNotFound
+		);
+
+		let asset_id = register_euro();
+		let metadata = AssetMetadata {
+			name: b"Euro".to_vec().try_into().unwrap(),
+			symbol: b"EUR".to_vec().try_into().unwrap(),
+			decimals: 12u8,
+			existential_deposit: ED,
+		};
+		System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::AssetRegistered {
+			asset_id,
+			metadata: metadata.clone(),
+		}));
+		assert_eq!(Bank::assets(asset_id), Some(metadata));
+		assert_eq!(Bank::existential_deposit(asset_id), Ok(ED));
+
+		assert_noop!(
+			Bank::register_asset(
+				RawOrigin::Root.into(),
+				vec![0u8; ASSET_NAME_MAXSIZE as usize + 1],
+				b"EUR".to_vec(),
+				12u8,
+				ED
+			),
+			Error::<Runtime>::AssetMetadataTooLong
+		);
+	});
+}
+
+#[test]
+fn can_deposit_withdraw_and_transfer_asset() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0), (BOB, 500, 0)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Manager));
+			let asset_id = register_euro();
+
+			assert_ok!(Bank::deposit_asset(RuntimeOrigin::signed(charlie), asset_id, ALICE, 1_000));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::AssetDeposited {
+				asset_id,
+				user: ALICE,
+				amount: 1_000,
+			}));
+			assert_ok!(Bank::withdraw_asset(RuntimeOrigin::signed(charlie), asset_id, ALICE, 100));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::AssetWithdrew {
+				asset_id,
+				user: ALICE,
+				amount: 100,
+			}));
+			assert_ok!(Bank::transfer_asset(RuntimeOrigin::signed(ALICE), asset_id, BOB, 300));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::AssetTransferred {
+				asset_id,
+				from: ALICE,
+				to: BOB,
+				amount: 300,
+			}));
+
+			assert_eq!(AssetAccounts::<Runtime>::get(ALICE, asset_id).free, 600);
+			assert_eq!(AssetAccounts::<Runtime>::get(BOB, asset_id).free, 300);
+			assert_eq!(Bank::asset_issuance(asset_id), 900);
+			// The native balances are not affected.
+			assert_eq!(Accounts::<Runtime>::get(ALICE).free, 1_000);
+			assert_eq!(Accounts::<Runtime>::get(BOB).free, 500);
+
+			assert_noop!(
+				Bank::transfer_asset(RuntimeOrigin::signed(ALICE), asset_id, BOB, 601),
+				Error::<Runtime>::InsufficientBalance
+			);
+			assert_noop!(
+				Bank::deposit_asset(RuntimeOrigin::signed(charlie), asset_id, ALICE, ED - 1),
+				Error::<Runtime>::AmountTooSmall
+			);
+			assert_noop!(
+				Bank::deposit_asset(RuntimeOrigin::signed(ALICE), asset_id, BOB, 100),
+				pallet_roles::Error::<Runtime>::IncorrectRole
+			);
+
+			// The native currency can be used through the asset calls.
+			assert_ok!(Bank::transfer_asset(
+				RuntimeOrigin::signed(ALICE),
+				NATIVE_ASSET_ID,
+				BOB,
+				100
+			));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::Transferred {
+				from: ALICE,
+				to: BOB,
+				amount: 100,
+			}));
+
+			assert!(Bank::check_asset_issuance(asset_id));
+			assert!(Bank::check_total_issuance());
+		});
+}
+
+#[test]
+fn can_stake_and_redeem_asset() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Manager));
+			let asset_id = register_euro();
+			assert_ok!(Bank::deposit_asset(RuntimeOrigin::signed(charlie), asset_id, ALICE, 1_000));
+
+			assert_ok!(Bank::stake_asset_funds(RuntimeOrigin::signed(ALICE), asset_id, 400));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::AssetLocked {
+				asset_id,
+				user: ALICE,
+				amount: 400,
+				length: STAKE_PERIOD,
+				reason: LockReason::Stake,
+			}));
+			assert_eq!(
+				AssetAccounts::<Runtime>::get(ALICE, asset_id),
+				AccountData {
+					free: 600,
+					reserved: 0,
+					locked: bounded_vec![LockedFund {
+						id: 1,
+						amount: 400,
+						reason: LockReason::Stake
+					}],
+					debt: 0
+				}
+			);
+			assert_eq!(AssetLocks::<Runtime>::get(1), Some(asset_id));
+
+			Bank::on_finalize(System::block_number() + STAKE_PERIOD);
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::AssetUnlocked {
+				asset_id,
+				user: ALICE,
+				amount: 400,
+				reason: UnlockReason::Expired,
+			}));
+			assert_eq!(
+				AssetAccounts::<Runtime>::get(ALICE, asset_id),
+				AccountData { free: 600, reserved: 400, locked: bounded_vec![], debt: 0 }
+			);
+			assert_eq!(AssetLocks::<Runtime>::get(1), None);
+
+			assert_ok!(Bank::redeem_asset_funds(RuntimeOrigin::signed(ALICE), asset_id, 100));
+			Bank::on_finalize(System::block_number() + REDEEM_PERIOD);
+			assert_eq!(
+				AssetAccounts::<Runtime>::get(ALICE, asset_id),
+				AccountData { free: 700, reserved: 300, locked: bounded_vec![], debt: 0 }
+			);
+
+			// The native account is not affected.
+			assert_eq!(
+				Accounts::<Runtime>::get(ALICE),
+				AccountData { free: 1_000, reserved: 0, locked: bounded_vec![], debt: 0 }
+			);
+			assert!(Bank::check_asset_issuance(asset_id));
+			assert!(Bank::check_total_issuance());
+		});
+}
+
+#[test]
+fn can_reap_asset_accounts() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0), (BOB, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			TreasuryAccount::<Runtime>::set(Some(TREASURY));
+			let charlie: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Manager));
+			let asset_id = register_euro();
+
+			assert_ok!(Bank::deposit_asset(RuntimeOrigin::signed(charlie), asset_id, BOB, 100));
+			assert_ok!(Bank::transfer_asset(RuntimeOrigin::signed(BOB), asset_id, ALICE, 98));
+
+			System::reset_events();
+			Bank::on_initialize(1);
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::AssetReaped {
+				asset_id,
+				user: BOB,
+				dust: 2,
+			}));
+			assert!(!AssetAccounts::<Runtime>::contains_key(BOB, asset_id));
+			assert_eq!(AssetAccounts::<Runtime>::get(TREASURY, asset_id).free, 2);
+			// The native account is not reaped.
+			assert_eq!(Accounts::<Runtime>::get(BOB).free, 1_000);
+
+			assert!(Bank::check_asset_issuance(asset_id));
+			assert!(Bank::check_total_issuance());
+		});
+}
+
+#[test]
+fn can_create_and_execute_standing_orders() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0), (BOB, 500, 0)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Bank::create_standing_order(
+				RuntimeOrigin::signed(ALICE),
+				BOB,
+				100,
+				10,
+				Some(10),
+				FailurePolicy::Skip
+			));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::StandingOrderCreated {
+				order_id: 1,
+				from: ALICE,
+				to: BOB,
+				amount: 100,
+				next_execution: 10,
+				period: Some(10),
+			}));
+			assert_eq!(StandingOrderSchedule::<Runtime>::get(10), vec![1]);
+
+			// One-off standing order.
+			assert_ok!(Bank::create_standing_order(
+				RuntimeOrigin::signed(ALICE),
+				BOB,
+				50,
+				15,
+				None,
+				FailurePolicy::Skip
+			));
+
+			Bank::on_finalize(10);
+			System::assert_last_event(RuntimeEvent::Bank(
+				Event::<Runtime>::StandingOrderExecuted {
+					order_id: 1,
+					from: ALICE,
+					to: BOB,
+					amount: 100,
+				},
+			));
+			assert_eq!(Accounts::<Runtime>::get(ALICE).free, 900);
+			assert_eq!(Accounts::<Runtime>::get(BOB).free, 600);
+			assert_eq!(Bank::standing_orders(1).map(|order| order.next_execution), Some(20));
+			assert_eq!(StandingOrderSchedule::<Runtime>::get(20), vec![1]);
+
+			Bank::on_finalize(15);
+			System::assert_last_event(RuntimeEvent::Bank(
+				Event::<Runtime>::StandingOrderCompleted { order_id: 2 },
+			));
+			assert_eq!(Accounts::<Runtime>::get(ALICE).free, 850);
+			assert_eq!(Accounts::<Runtime>::get(BOB).free, 650);
+			assert_eq!(Bank::standing_orders(2), None);
+
+			Bank::on_finalize(20);
+			assert_eq!(Accounts::<Runtime>::get(ALICE).free, 750);
+			assert_eq!(Accounts::<Runtime>::get(BOB).free, 750);
+
+			assert_eq!(
+				Bank::standing_orders_of(ALICE),
+				vec![(
+					1,
+					StandingOrder {
+						from: ALICE,
+						to: BOB,
+						amount: 100,
+						next_execution: 30,
+						period: Some(10),
+						policy: FailurePolicy::Skip,
+						retries: 0,
+					}
+				)]
+			);
+			assert_eq!(Bank::standing_orders_of(BOB), vec![]);
+			assert!(Bank::check_total_issuance());
+		});
+}
+
+#[test]
+fn cannot_create_invalid_standing_orders() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0), (BOB, 500, 0)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Bank::create_standing_order(
+					RuntimeOrigin::signed(ALICE),
+					BOB,
+					100,
+					1,
+					None,
+					FailurePolicy::Skip
+				),
+				Error::<Runtime>::InvalidSchedule
+			);
+			assert_noop!(
+				Bank::create_standing_order(
+					RuntimeOrigin::signed(ALICE),
+					BOB,
+					100,
+					10,
+					Some(0),
+					FailurePolicy::Skip
+				),
+				Error::<Runtime>::InvalidSchedule
+			);
+			assert_noop!(
+				Bank::create_standing_order(
+					RuntimeOrigin::signed(ALICE),
+					BOB,
+					4,
+					10,
+					None,
+					FailurePolicy::Skip
+				),
+				Error::<Runtime>::AmountTooSmall
+			);
+			assert_ok!(Roles::register_role(&3, Role::Manager));
+			assert_noop!(
+				Bank::create_standing_order(
+					RuntimeOrigin::signed(ALICE),
+					3,
+					100,
+					10,
+					None,
+					FailurePolicy::Skip
+				),
+				pallet_roles::Error::<Runtime>::IncorrectRole
+			);
+		});
+}
+
+#[test]
+fn standing_orders_can_skip_or_retry_failed_executions() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 150, 0), (BOB, 500, 0)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Bank::create_standing_order(
+				RuntimeOrigin::signed(ALICE),
+				BOB,
+				100,
+				10,
+				Some(10),
+				FailurePolicy::Skip
+			));
+			assert_ok!(Bank::create_standing_order(
+				RuntimeOrigin::signed(ALICE),
+				BOB,
+				100,
+				10,
+				Some(10),
+				FailurePolicy::Retry
+			));
+
+			// The first order is executed, the second one retries in the next block.
+			Bank::on_finalize(10);
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::StandingOrderFailed {
+				order_id: 2,
+				error: Error::<Runtime>::InsufficientBalance.into(),
+				next_execution: Some(11),
+			}));
+			assert_eq!(Accounts::<Runtime>::get(ALICE).free, 50);
+
+			Bank::on_finalize(11);
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::StandingOrderFailed {
+				order_id: 2,
+				error: Error::<Runtime>::InsufficientBalance.into(),
+				next_execution: Some(12),
+			}));
+
+			// Retries are exhausted, wait for the next period.
+			Bank::on_finalize(12);
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::StandingOrderFailed {
+				order_id: 2,
+				error: Error::<Runtime>::InsufficientBalance.into(),
+				next_execution: Some(20),
+			}));
+			assert_eq!(Bank::standing_orders(2).map(|order| order.retries), Some(0));
+
+			// The first order skips, the second one retries again.
+			Bank::on_finalize(20);
+			System::assert_has_event(RuntimeEvent::Bank(Event::<Runtime>::StandingOrderFailed {
+				order_id: 1,
+				error: Error::<Runtime>::InsufficientBalance.into(),
+				next_execution: Some(30),
+			}));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::StandingOrderFailed {
+				order_id: 2,
+				error: Error::<Runtime>::InsufficientBalance.into(),
+				next_execution: Some(21),
+			}));
+
+			assert_ok!(Bank::transfer(RuntimeOrigin::signed(BOB), ALICE, 100));
+			Bank::on_finalize(21);
+			System::assert_last_event(RuntimeEvent::Bank(
+				Event::<Runtime>::StandingOrderExecuted {
+					order_id: 2,
+					from: ALICE,
+					to: BOB,
+					amount: 100,
+				},
+			));
+			assert_eq!(Accounts::<Runtime>::get(ALICE).free, 50);
+			assert_eq!(
+				Bank::standing_orders(2).map(|order| (order.next_execution, order.retries)),
+				Some((30, 0))
+			);
+			assert!(Bank::check_total_issuance());
+		});
+}
+
+#[test]
+fn can_amend_and_cancel_standing_orders() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0), (BOB, 500, 0)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Bank::create_standing_order(
+				RuntimeOrigin::signed(ALICE),
+				BOB,
+				100,
+				10,
+				Some(10),
+				FailurePolicy::Skip
+			));
+
+			assert_noop!(
+				Bank::amend_standing_order(
+					RuntimeOrigin::signed(BOB),
+					1,
+					200,
+					15,
+					None,
+					FailurePolicy::Retry
+				),
+				Error::<Runtime>::NotOrderOwner
+			);
+			assert_noop!(
+				Bank::amend_standing_order(
+					RuntimeOrigin::signed(ALICE),
+					2,
+					200,
+					15,
+					None,
+					FailurePolicy::Retry
+				),
+				Error::<Runtime>::InvalidOrderId
+			);
+			assert_ok!(Bank::amend_standing_order(
+				RuntimeOrigin::signed(ALICE),
+				1,
+				200,
+				15,
+				None,
+				FailurePolicy::Retry
+			));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::StandingOrderAmended {
+				order_id: 1,
+				amount: 200,
+				next_execution: 15,
+				period: None,
+			}));
+
+			// The old schedule is ignored.
+			Bank::on_finalize(10);
+			assert_eq!(Accounts::<Runtime>::get(ALICE).free, 1_000);
+
+			Bank::on_finalize(15);
+			assert_eq!(Accounts::<Runtime>::get(ALICE).free, 800);
+			assert_eq!(Accounts::<Runtime>::get(BOB).free, 700);
+			assert_eq!(Bank::standing_orders(1), None);
+
+			assert_ok!(Bank::create_standing_order(
+				RuntimeOrigin::signed(ALICE),
+				BOB,
+				100,
+				20,
+				Some(10),
+				FailurePolicy::Skip
+			));
+			assert_noop!(
+				Bank::cancel_standing_order(RuntimeOrigin::signed(BOB), 2),
+				Error::<Runtime>::NotOrderOwner
+			);
+			assert_ok!(Bank::cancel_standing_order(RuntimeOrigin::signed(ALICE), 2));
+			System::assert_last_event(RuntimeEvent::Bank(
+				Event::<Runtime>::StandingOrderCancelled { order_id: 2 },
+			));
+			assert_noop!(
+				Bank::cancel_standing_order(RuntimeOrigin::signed(ALICE), 2),
+				Error::<Runtime>::InvalidOrderId
+			);
+
+			Bank::on_finalize(20);
+			assert_eq!(Accounts::<Runtime>::get(ALICE).free, 800);
+			assert!(Bank::check_total_issuance());
+		});
+}
+
+#[test]
+fn can_release_and_refund_escrow() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0), (BOB, 500, 0)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Auditor));
+
+			assert_ok!(Bank::create_escrow(RuntimeOrigin::signed(ALICE), BOB, charlie, 300, 20));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::EscrowCreated {
+				lock_id: 1,
+				payer: ALICE,
+				payee: BOB,
+				arbiter: charlie,
+				amount: 300,
+				deadline: 21,
+			}));
+			assert_eq!(
+				Accounts::<Runtime>::get(ALICE),
+				AccountData {
+					free: 700,
+					reserved: 0,
+					locked: bounded_vec![LockedFund {
+						id: 1,
+						amount: 300,
+						reason: LockReason::Escrow
+					}],
+					debt: 0
+				}
+			);
+			assert_eq!(Bank::fund_unlock_at(ALICE, 1), 21);
+
+			// Only the payer or the arbiter can release the escrow.
+			assert_noop!(
+				Bank::release_escrow(RuntimeOrigin::signed(BOB), 1),
+				Error::<Runtime>::NotEscrowParty
+			);
+			assert_noop!(
+				Bank::unlock_funds_auditor(RuntimeOrigin::signed(charlie), ALICE, 1),
+				Error::<Runtime>::UnauthorisedUnlock
+			);
+			assert_ok!(Bank::release_escrow(RuntimeOrigin::signed(ALICE), 1));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::EscrowReleased {
+				lock_id: 1,
+				payee: BOB,
+				amount: 300,
+			}));
+			assert_eq!(
+				Accounts::<Runtime>::get(ALICE),
+				AccountData { free: 700, reserved: 0, locked: bounded_vec![], debt: 0 }
+			);
+			assert_eq!(Accounts::<Runtime>::get(BOB).free, 800);
+			assert_eq!(Bank::escrows(1), None);
+
+			// Only the payee or the arbiter can refund the escrow.
+			assert_ok!(Bank::create_escrow(RuntimeOrigin::signed(ALICE), BOB, charlie, 200, 20));
+			assert_noop!(
+				Bank::refund_escrow(RuntimeOrigin::signed(ALICE), 2),
+				Error::<Runtime>::NotEscrowParty
+			);
+			assert_ok!(Bank::refund_escrow(RuntimeOrigin::signed(charlie), 2));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::EscrowRefunded {
+				lock_id: 2,
+				payer: ALICE,
+				amount: 200,
+			}));
+			assert_eq!(
+				Accounts::<Runtime>::get(ALICE),
+				AccountData { free: 700, reserved: 0, locked: bounded_vec![], debt: 0 }
+			);
+			assert_noop!(
+				Bank::refund_escrow(RuntimeOrigin::signed(charlie), 2),
+				Error::<Runtime>::InvalidEscrowId
+			);
+
+			// Settled escrows are ignored at the deadline.
+			Bank::on_finalize(21);
+			assert_eq!(Accounts::<Runtime>::get(ALICE).free, 700);
+			assert_eq!(Accounts::<Runtime>::get(BOB).free, 800);
+			assert!(Bank::check_total_issuance());
+		});
+}
+
+#[test]
+fn escrow_is_released_at_deadline_unless_disputed() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0), (BOB, 500, 0)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Auditor));
+
+			assert_ok!(Bank::create_escrow(RuntimeOrigin::signed(ALICE), BOB, charlie, 300, 20));
+			assert_ok!(Bank::create_escrow(RuntimeOrigin::signed(ALICE), BOB, charlie, 200, 20));
+
+			assert_noop!(
+				Bank::dispute_escrow(RuntimeOrigin::signed(charlie), 2),
+				Error::<Runtime>::NotEscrowParty
+			);
+			assert_ok!(Bank::dispute_escrow(RuntimeOrigin::signed(BOB), 2));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::EscrowDisputed {
+				lock_id: 2,
+				who: BOB,
+			}));
+			assert_noop!(
+				Bank::dispute_escrow(RuntimeOrigin::signed(ALICE), 2),
+				Error::<Runtime>::EscrowAlreadyDisputed
+			);
+
+			Bank::on_finalize(21);
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::EscrowReleased {
+				lock_id: 1,
+				payee: BOB,
+				amount: 300,
+			}));
+			assert_eq!(
+				Accounts::<Runtime>::get(ALICE),
+				AccountData {
+					free: 500,
+					reserved: 0,
+					locked: bounded_vec![LockedFund {
+						id: 2,
+						amount: 200,
+						reason: LockReason::Escrow
+					}],
+					debt: 0
+				}
+			);
+			assert_eq!(Accounts::<Runtime>::get(BOB).free, 800);
+
+			// The disputed escrow is settled by the arbiter.
+			assert_ok!(Bank::release_escrow(RuntimeOrigin::signed(charlie), 2));
+			assert_eq!(
+				Accounts::<Runtime>::get(ALICE),
+				AccountData { free: 500, reserved: 0, locked: bounded_vec![], debt: 0 }
+			);
+			assert_eq!(Accounts::<Runtime>::get(BOB).free, 1_000);
+			assert!(Bank::check_total_issuance());
+		});
+}
+
+#[test]
+fn released_escrow_repays_payee_debt() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0), (BOB, 100, 0)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			let dave: AccountId = 4u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Auditor));
+			assert_ok!(Roles::register_role(&dave, Role::Manager));
+			assert_ok!(Bank::set_overdraft_limit(RuntimeOrigin::signed(dave), BOB, 100));
+			assert_ok!(Bank::transfer(RuntimeOrigin::signed(BOB), ALICE, 150));
+
+			assert_ok!(Bank::create_escrow(RuntimeOrigin::signed(ALICE), BOB, charlie, 300, 20));
+			assert_ok!(Bank::release_escrow(RuntimeOrigin::signed(ALICE), 1));
+			System::assert_has_event(RuntimeEvent::Bank(Event::<Runtime>::OverdraftRepaid {
+				user: BOB,
+				amount: 50,
+			}));
+			assert_eq!(
+				Accounts::<Runtime>::get(BOB),
+				AccountData { free: 250, reserved: 0, locked: bounded_vec![], debt: 0 }
+			);
+			assert!(Bank::check_total_issuance());
+
+			// The escrow is recorded in the ledgers.
+			let statement = Bank::account_statement(ALICE, 0, 1_000, 0, MAX_STATEMENT_PAGE_SIZE);
+			assert_eq!(
+				statement.iter().rev().take(2).map(|entry| entry.kind).collect::<Vec<_>>(),
+				vec![LedgerEntryKind::TransferOut, LedgerEntryKind::Lock]
+			);
+			assert_eq!(statement.last().map(|entry| entry.counterparty), Some(Some(BOB)));
+			assert_eq!(
+				Bank::account_statement(BOB, 0, 1_000, 0, MAX_STATEMENT_PAGE_SIZE)
+					.last()
+					.map(|entry| entry.kind),
+				Some(LedgerEntryKind::TransferIn)
+			);
+		});
+}
+
+#[test]
+fn cannot_create_invalid_escrow() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0), (BOB, 500, 0)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Auditor));
+
+			assert_noop!(
+				Bank::create_escrow(RuntimeOrigin::signed(ALICE), BOB, BOB, 300, 20),
+				pallet_roles::Error::<Runtime>::IncorrectRole
+			);
+			assert_noop!(
+				Bank::create_escrow(RuntimeOrigin::signed(ALICE), charlie, charlie, 300, 20),
+				pallet_roles::Error::<Runtime>::IncorrectRole
+			);
+			assert_noop!(
+				Bank::create_escrow(RuntimeOrigin::signed(ALICE), BOB, charlie, 4, 20),
+				Error::<Runtime>::AmountTooSmall
+			);
+			assert_noop!(
+				Bank::create_escrow(RuntimeOrigin::signed(ALICE), BOB, charlie, 300, 0),
+				Error::<Runtime>::InvalidSchedule
+			);
+			assert_noop!(
+				Bank::create_escrow(RuntimeOrigin::signed(ALICE), BOB, charlie, 1_001, 20),
+				Error::<Runtime>::InsufficientBalance
+			);
+		});
+}
+
+#[test]
+fn can_create_and_retire_products() {
+	default_test_ext().execute_with(|| {
+		let charlie: AccountId = 3u32;
+		assert_ok!(Roles::register_role(&charlie, Role::Manager));
+		assert_ok!(Roles::register_role(&ALICE, Role::Customer));
+		let kind = ProductKind::FixedTerm {
+			term: 100,
+			rate: Perbill::from_percent(10),
+			penalty: Perbill::from_percent(2),
+		};
+
+		// Only managers can create products.
+		assert_noop!(
+			Bank::create_product(RuntimeOrigin::signed(ALICE), kind.clone()),
+			pallet_roles::Error::<Runtime>::IncorrectRole
+		);
+		assert_ok!(Bank::create_product(RuntimeOrigin::signed(charlie), kind.clone()));
+		System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::ProductCreated {
+			product_id: 1,
+			kind: kind.clone(),
+		}));
+		assert_eq!(Bank::products(1), Some(InterestProduct { kind, active: true }));
+
+		// Tiers must be sorted by threshold, and terms must be non-zero.
+		assert_noop!(
+			Bank::create_product(
+				RuntimeOrigin::signed(charlie),
+				ProductKind::Tiered(bounded_vec![])
+			),
+			Error::<Runtime>::InvalidProduct
+		);
+		assert_noop!(
+			Bank::create_product(
+				RuntimeOrigin::signed(charlie),
+				ProductKind::Tiered(bounded_vec![
+					(1_000, Perbill::from_percent(5)),
+					(0, Perbill::from_percent(1))
+				])
+			),
+			Error::<Runtime>::InvalidProduct
+		);
+		assert_noop!(
+			Bank::create_product(
+				RuntimeOrigin::signed(charlie),
+				ProductKind::FixedTerm {
+					term: 0,
+					rate: Perbill::from_percent(10),
+					penalty: Perbill::from_percent(2),
+				}
+			),
+			Error::<Runtime>::InvalidProduct
+		);
+
+		// Retired products no longer accept deposits.
+		assert_noop!(
+			Bank::retire_product(RuntimeOrigin::signed(charlie), 2),
+			Error::<Runtime>::InvalidProductId
+		);
+		assert_ok!(Bank::retire_product(RuntimeOrigin::signed(charlie), 1));
+		System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::ProductRetired {
+			product_id: 1,
+		}));
+		assert_noop!(
+			Bank::stake_funds_into_product(RuntimeOrigin::signed(ALICE), 1, 100),
+			Error::<Runtime>::ProductRetired
+		);
+		assert_noop!(
+			Bank::stake_funds_into_product(RuntimeOrigin::signed(ALICE), 2, 100),
+			Error::<Runtime>::InvalidProductId
+		);
+	});
+}
+
+#[test]
+fn fixed_term_products_pay_interest_at_maturity() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 100_000, 0)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			let term = YEAR as u64 / 10;
+			assert_ok!(Roles::register_role(&charlie, Role::Manager));
+			assert_ok!(Bank::create_product(
+				RuntimeOrigin::signed(charlie),
+				ProductKind::FixedTerm {
+					term,
+					rate: Perbill::from_percent(10),
+					penalty: Perbill::from_percent(2),
+				}
+			));
+
+			assert_ok!(Bank::stake_funds_into_product(RuntimeOrigin::signed(ALICE), 1, 100_000));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::ProductStaked {
+				user: ALICE,
+				product_id: 1,
+				lock_id: 1,
+				amount: 100_000,
+			}));
+			assert_eq!(
+				Accounts::<Runtime>::get(ALICE),
+				AccountData {
+					free: 0,
+					reserved: 0,
+					locked: bounded_vec![LockedFund {
+						id: 1,
+						amount: 100_000,
+						reason: LockReason::Product
+					}],
+					debt: 0
+				}
+			);
+			assert_eq!(Bank::fund_unlock_at(ALICE, 1), 1 + term);
+			assert_eq!(Bank::interest_pa(ALICE), 10_000);
+
+			// The interest is paid with the deposit at the end of the term.
+			System::set_block_number(1 + term);
+			assert_eq!(Bank::product_deposits_of(ALICE)[0].2, 1_000);
+			Bank::on_finalize(1 + term);
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::ProductRedeemed {
+				user: ALICE,
+				product_id: 1,
+				lock_id: 1,
+				amount: 100_000,
+				interest: 1_000,
+				penalty: 0,
+			}));
+			assert_eq!(
+				Accounts::<Runtime>::get(ALICE),
+				AccountData { free: 101_000, reserved: 0, locked: bounded_vec![], debt: 0 }
+			);
+			assert_eq!(Bank::product_deposits(1), None);
+			assert!(Bank::check_total_issuance());
+		});
+}
+
+#[test]
+fn fixed_term_products_charge_penalty_on_early_withdrawal() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 100_000, 0), (BOB, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			let term = YEAR as u64 / 10;
+			assert_ok!(Roles::register_role(&charlie, Role::Manager));
+			assert_ok!(Bank::create_product(
+				RuntimeOrigin::signed(charlie),
+				ProductKind::FixedTerm {
+					term,
+					rate: Perbill::from_percent(10),
+					penalty: Perbill::from_percent(2),
+				}
+			));
+			assert_ok!(Bank::stake_funds_into_product(RuntimeOrigin::signed(ALICE), 1, 100_000));
+
+			// The penalty requires the treasury, and only the owner can withdraw the deposit.
+			assert_noop!(
+				Bank::redeem_product_funds(RuntimeOrigin::signed(ALICE), 1),
+				Error::<Runtime>::TreasuryAccountNotSet
+			);
+			TreasuryAccount::<Runtime>::set(Some(TREASURY));
+			assert_noop!(
+				Bank::redeem_product_funds(RuntimeOrigin::signed(BOB), 1),
+				Error::<Runtime>::InvalidLockId
+			);
+
+			System::set_block_number(term);
+			assert_ok!(Bank::redeem_product_funds(RuntimeOrigin::signed(ALICE), 1));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::ProductRedeemed {
+				user: ALICE,
+				product_id: 1,
+				lock_id: 1,
+				amount: 100_000,
+				interest: 0,
+				penalty: 2_000,
+			}));
+			assert_eq!(
+				Accounts::<Runtime>::get(ALICE),
+				AccountData { free: 98_000, reserved: 0, locked: bounded_vec![], debt: 0 }
+			);
+			assert_eq!(Accounts::<Runtime>::get(TREASURY).free, 1_002_000);
+
+			// The scheduled unlock at maturity is ignored.
+			Bank::on_finalize(1 + term);
+			assert_eq!(Accounts::<Runtime>::get(ALICE).free, 98_000);
+			assert!(Bank::check_total_issuance());
+		});
+}
+
+#[test]
+fn tiered_products_pay_the_rate_of_the_balance_band() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 100_000, 0)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Manager));
+			assert_ok!(Bank::create_product(
+				RuntimeOrigin::signed(charlie),
+				ProductKind::Tiered(bounded_vec![
+					(0, Perbill::from_percent(1)),
+					(1_000, Perbill::from_percent(5))
+				])
+			));
+
+			assert_ok!(Bank::stake_funds_into_product(RuntimeOrigin::signed(ALICE), 1, 500));
+			assert_ok!(Bank::stake_funds_into_product(RuntimeOrigin::signed(ALICE), 1, 10_000));
+			assert_eq!(Bank::interest_pa(ALICE), 505);
+			// Tiered deposits are not scheduled to unlock.
+			assert_eq!(Bank::fund_unlock_at(ALICE, 2), 0);
+
+			// Tiered deposits can be withdrawn at any time with the interest earned.
+			System::set_block_number(1 + YEAR as u64 / 10);
+			assert_ok!(Bank::redeem_product_funds(RuntimeOrigin::signed(ALICE), 2));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::ProductRedeemed {
+				user: ALICE,
+				product_id: 1,
+				lock_id: 2,
+				amount: 10_000,
+				interest: 50,
+				penalty: 0,
+			}));
+			assert_eq!(
+				Accounts::<Runtime>::get(ALICE),
+				AccountData {
+					free: 99_550,
+					reserved: 0,
+					locked: bounded_vec![LockedFund {
+						id: 1,
+						amount: 500,
+						reason: LockReason::Product
+					}],
+					debt: 0
+				}
+			);
+			assert!(Bank::check_total_issuance());
+		});
+}
+
+#[test]
+fn can_borrow_and_repay_loans() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 10_000), (BOB, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			TreasuryAccount::<Runtime>::set(Some(TREASURY));
+			let charlie: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Manager));
+			assert_ok!(Bank::set_borrow_rate(RuntimeOrigin::signed(charlie), 1_000));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::BorrowRateSet {
+				manager: charlie,
+				old_rate: Perbill::zero(),
+				new_rate: Perbill::from_percent(10),
+			}));
+
+			// Loans are limited by the collateral.
+			assert_noop!(
+				Bank::borrow(RuntimeOrigin::signed(ALICE), 10_000, 5_001),
+				Error::<Runtime>::LoanToValueTooHigh
+			);
+			assert_noop!(
+				Bank::borrow(RuntimeOrigin::signed(ALICE), 20_000, 5_000),
+				Error::<Runtime>::InsufficientBalance
+			);
+
+			assert_ok!(Bank::borrow(RuntimeOrigin::signed(ALICE), 10_000, 5_000));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::LoanOpened {
+				lock_id: 1,
+				borrower: ALICE,
+				collateral: 10_000,
+				principal: 5_000,
+			}));
+			assert_eq!(
+				Accounts::<Runtime>::get(ALICE),
+				AccountData {
+					free: 6_000,
+					reserved: 0,
+					locked: bounded_vec![LockedFund {
+						id: 1,
+						amount: 10_000,
+						reason: LockReason::Collateral
+					}],
+					debt: 0
+				}
+			);
+			// The debt grows by 500 a year, and reaches 80% of the collateral in 6 years.
+			assert_eq!(
+				Bank::loans(1),
+				Some(Loan {
+					borrower: ALICE,
+					collateral: 10_000,
+					principal: 5_000,
+					interest: 0,
+					rate: Perbill::from_percent(10),
+					updated_at: 1,
+					liquidation_at: Some(2 + 6 * YEAR as u64),
+				})
+			);
+
+			// Only the borrower can repay the loan.
+			assert_noop!(
+				Bank::repay_loan(RuntimeOrigin::signed(BOB), 1, 100),
+				Error::<Runtime>::NotLoanOwner
+			);
+
+			// The interest is repaid first, to the treasury.
+			System::set_block_number(1 + YEAR as u64 / 10);
+			assert_eq!(Bank::loans_of(ALICE)[0].1.interest, 50);
+			assert_ok!(Bank::repay_loan(RuntimeOrigin::signed(ALICE), 1, 550));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::LoanRepaid {
+				lock_id: 1,
+				interest: 50,
+				principal: 500,
+			}));
+			assert_eq!(Accounts::<Runtime>::get(ALICE).free, 5_450);
+			assert_eq!(Accounts::<Runtime>::get(TREASURY).free, 1_000_050);
+
+			// The collateral is returned once the loan is fully repaid.
+			assert_ok!(Bank::repay_loan(RuntimeOrigin::signed(ALICE), 1, 10_000));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::LoanClosed {
+				lock_id: 1,
+			}));
+			assert_eq!(
+				Accounts::<Runtime>::get(ALICE),
+				AccountData { free: 950, reserved: 10_000, locked: bounded_vec![], debt: 0 }
+			);
+			assert_eq!(Bank::loans(1), None);
+			assert!(Bank::check_total_issuance());
+		});
+}
+
+#[test]
+fn loans_are_liquidated_when_undercollateralised() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 0, 10_000)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Manager));
+			assert_ok!(Bank::set_borrow_rate(RuntimeOrigin::signed(charlie), 10_000));
+			assert_ok!(Bank::borrow(RuntimeOrigin::signed(ALICE), 10_000, 5_000));
+
+			// The debt grows by 5_000 a year, and reaches 80% of the collateral in 0.6 year.
+			let liquidation_at = 2 + 3 * YEAR as u64 / 5;
+			assert_eq!(Bank::loans(1).unwrap().liquidation_at, Some(liquidation_at));
+			assert_noop!(
+				Bank::liquidate_loan(RuntimeOrigin::signed(charlie), 1),
+				Error::<Runtime>::LoanNotUndercollateralised
+			);
+
+			// The liquidation is retried in the next block if the treasury is not set.
+			System::set_block_number(liquidation_at);
+			Bank::on_finalize(liquidation_at);
+			assert_eq!(Bank::loans(1).unwrap().liquidation_at, Some(liquidation_at + 1));
+
+			TreasuryAccount::<Runtime>::set(Some(TREASURY));
+			System::set_block_number(liquidation_at + 1);
+			Bank::on_finalize(liquidation_at + 1);
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::LoanLiquidated {
+				lock_id: 1,
+				borrower: ALICE,
+				collateral: 10_000,
+				debt: 8_000,
+			}));
+			assert_eq!(
+				Accounts::<Runtime>::get(ALICE),
+				AccountData { free: 5_000, reserved: 0, locked: bounded_vec![], debt: 0 }
+			);
+			assert_eq!(Accounts::<Runtime>::get(TREASURY).free, 1_010_000);
+			assert_eq!(Bank::loans(1), None);
+			assert!(Bank::check_total_issuance());
+		});
+}
+
+#[test]
+fn can_transfer_with_overdraft() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 100, 0), (BOB, 100, 0)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Manager));
+			assert_noop!(
+				Bank::transfer(RuntimeOrigin::signed(ALICE), BOB, 150),
+				Error::<Runtime>::InsufficientBalance
+			);
+
+			// Only managers can grant an overdraft.
+			assert_noop!(
+				Bank::set_overdraft_limit(RuntimeOrigin::signed(BOB), ALICE, 100),
+				pallet_roles::Error::<Runtime>::IncorrectRole
+			);
+			assert_ok!(Bank::set_overdraft_limit(RuntimeOrigin::signed(charlie), ALICE, 100));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::OverdraftLimitSet {
+				user: ALICE,
+				limit: 100,
+			}));
+
+			// Transfers above "free" are drawn on the overdraft.
+			assert_ok!(Bank::transfer(RuntimeOrigin::signed(ALICE), BOB, 150));
+			System::assert_has_event(RuntimeEvent::Bank(Event::<Runtime>::OverdraftDrawn {
+				user: ALICE,
+				amount: 50,
+			}));
+			assert_eq!(
+				Accounts::<Runtime>::get(ALICE),
+				AccountData { free: 0, reserved: 0, locked: bounded_vec![], debt: 50 }
+			);
+			assert_eq!(Accounts::<Runtime>::get(BOB).free, 250);
+			assert!(Bank::check_total_issuance());
+
+			assert_noop!(
+				Bank::transfer(RuntimeOrigin::signed(ALICE), BOB, 51),
+				Error::<Runtime>::OverdraftLimitExceeded
+			);
+
+			// Incoming transfers repay the debt first.
+			assert_ok!(Bank::transfer(RuntimeOrigin::signed(BOB), ALICE, 80));
+			System::assert_has_event(RuntimeEvent::Bank(Event::<Runtime>::OverdraftRepaid {
+				user: ALICE,
+				amount: 50,
+			}));
+			assert_eq!(
+				Accounts::<Runtime>::get(ALICE),
+				AccountData { free: 30, reserved: 0, locked: bounded_vec![], debt: 0 }
+			);
+			assert_eq!(Accounts::<Runtime>::get(BOB).free, 170);
+			assert!(Bank::check_total_issuance());
+		});
+}
+
+#[test]
+fn overdraft_interest_is_charged_to_treasury() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 10, 0), (BOB, 10, 0)])
+		.build()
+		.execute_with(|| {
+			TreasuryAccount::<Runtime>::set(Some(TREASURY));
+			let charlie: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Manager));
+			assert_ok!(Bank::set_overdraft_rate(RuntimeOrigin::signed(charlie), 10_000));
+			assert_ok!(Bank::set_overdraft_limit(
+				RuntimeOrigin::signed(charlie),
+				ALICE,
+				10_000_000
+			));
+			assert_ok!(Bank::transfer(RuntimeOrigin::signed(ALICE), BOB, 10_000_010));
+
+			// Accounts in debt are never reaped.
+			Bank::on_initialize(2);
+			assert!(Accounts::<Runtime>::contains_key(ALICE));
+
+			// 100% a year, over 100 blocks.
+			Bank::on_finalize(INTEREST_PAYOUT_PERIOD);
+			System::assert_last_event(RuntimeEvent::Bank(
+				Event::<Runtime>::OverdraftInterestCharged { user: ALICE, amount: 190 },
+			));
+			assert_eq!(Accounts::<Runtime>::get(ALICE).debt, 10_000_190);
+			assert_eq!(Accounts::<Runtime>::get(TREASURY).free, 1_000_190);
+			assert!(Bank::check_total_issuance());
+
+			// The limit can be removed, but the debt keeps accruing interest until repaid.
+			assert_ok!(Bank::set_overdraft_limit(RuntimeOrigin::signed(charlie), ALICE, 0));
+			assert!(OverdraftLimits::<Runtime>::contains_key(ALICE));
+			assert_noop!(
+				Bank::transfer(RuntimeOrigin::signed(ALICE), BOB, 5),
+				Error::<Runtime>::InsufficientBalance
+			);
+		});
+}
+
+#[test]
+fn overdraft_interest_is_charged_over_multiple_blocks() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 10, 0), (BOB, 10, 0), (3, 10, 0), (4, 10, 0)])
+		.build()
+		.execute_with(|| {
+			TreasuryAccount::<Runtime>::set(Some(TREASURY));
+			let manager: AccountId = 5u32;
+			assert_ok!(Roles::register_role(&manager, Role::Manager));
+			assert_ok!(Bank::set_overdraft_rate(RuntimeOrigin::signed(manager), 10_000));
+			for who in [ALICE, BOB, 3] {
+				assert_ok!(Bank::set_overdraft_limit(
+					RuntimeOrigin::signed(manager),
+					who,
+					10_000_000
+				));
+				assert_ok!(Bank::transfer(RuntimeOrigin::signed(who), 4, 10_000_010));
+			}
+
+			// At most 2 overdrafts are charged a block.
+			Bank::on_finalize(INTEREST_PAYOUT_PERIOD);
+			let charged = || {
+				[ALICE, BOB, 3]
+					.into_iter()
+					.filter(|who| Accounts::<Runtime>::get(who).debt == 10_000_190)
+					.count()
+			};
+			assert_eq!(charged(), 2);
+			assert_eq!(OverdraftChargeQueue::<Runtime>::get().len(), 1);
+
+			Bank::on_finalize(INTEREST_PAYOUT_PERIOD + 1);
+			assert_eq!(charged(), 3);
+			assert!(OverdraftChargeQueue::<Runtime>::get().is_empty());
+			assert_eq!(Accounts::<Runtime>::get(TREASURY).free, 1_000_570);
+			assert!(Bank::check_total_issuance());
+
+			// Nothing is charged again until the next period ends.
+			Bank::on_finalize(INTEREST_PAYOUT_PERIOD + 2);
+			assert_eq!(Accounts::<Runtime>::get(TREASURY).free, 1_000_570);
+		});
+}
+
+#[test]
+fn auditor_can_freeze_and_unfreeze_accounts() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 100), (BOB, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			let dave: AccountId = 4u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Auditor));
+			assert_ok!(Roles::register_role(&dave, Role::Manager));
+
+			// Only auditors can freeze accounts.
+			assert_noop!(
+				Bank::freeze_account(
+					RuntimeOrigin::signed(BOB),
+					ALICE,
+					FreezeReason::Investigation,
+					None
+				),
+				pallet_roles::Error::<Runtime>::IncorrectRole
+			);
+			assert_noop!(
+				Bank::unfreeze_account(RuntimeOrigin::signed(charlie), ALICE),
+				Error::<Runtime>::AccountNotFrozen
+			);
+
+			assert_ok!(Bank::freeze_account(
+				RuntimeOrigin::signed(charlie),
+				ALICE,
+				FreezeReason::Investigation,
+				None
+			));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::AccountFrozen {
+				user: ALICE,
+				auditor: charlie,
+				reason: FreezeReason::Investigation,
+				expiry: None,
+			}));
+
+			// Frozen accounts cannot move funds out.
+			assert_noop!(
+				Bank::transfer(RuntimeOrigin::signed(ALICE), BOB, 100),
+				Error::<Runtime>::AccountFrozen
+			);
+			assert_noop!(
+				Bank::withdraw(RuntimeOrigin::signed(dave), ALICE, 100),
+				Error::<Runtime>::AccountFrozen
+			);
+			assert_noop!(
+				Bank::stake_funds(RuntimeOrigin::signed(ALICE), 100),
+				Error::<Runtime>::AccountFrozen
+			);
+			assert_noop!(
+				Bank::redeem_funds(RuntimeOrigin::signed(ALICE), 100),
+				Error::<Runtime>::AccountFrozen
+			);
+
+			// But can still receive funds.
+			assert_ok!(Bank::transfer(RuntimeOrigin::signed(BOB), ALICE, 100));
+			assert_eq!(Bank::accounts(ALICE).free, 1_100);
+
+			assert_ok!(Bank::unfreeze_account(RuntimeOrigin::signed(charlie), ALICE));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::AccountUnfrozen {
+				user: ALICE,
+			}));
+			assert_ok!(Bank::transfer(RuntimeOrigin::signed(ALICE), BOB, 100));
+			assert!(Bank::check_total_issuance());
+		});
+}
+
+#[test]
+fn account_freezes_expire() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0), (BOB, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Auditor));
+
+			// A freeze can't expire in the block it is made.
+			assert_noop!(
+				Bank::freeze_account(
+					RuntimeOrigin::signed(charlie),
+					ALICE,
+					FreezeReason::Fraud,
+					Some(0)
+				),
+				Error::<Runtime>::InvalidSchedule
+			);
+
+			assert_ok!(Bank::freeze_account(
+				RuntimeOrigin::signed(charlie),
+				ALICE,
+				FreezeReason::Fraud,
+				Some(10)
+			));
+			assert_eq!(
+				Bank::frozen_accounts(ALICE),
+				Some(FreezeInfo {
+					auditor: charlie,
+					reason: FreezeReason::Fraud,
+					expiry: Some(11)
+				})
+			);
+
+			// Refreezing replaces the expiry, the old expiry is ignored.
+			assert_ok!(Bank::freeze_account(
+				RuntimeOrigin::signed(charlie),
+				ALICE,
+				FreezeReason::Fraud,
+				Some(20)
+			));
+			Bank::on_finalize(11);
+			assert!(Bank::frozen_accounts(ALICE).is_some());
+
+			Bank::on_finalize(21);
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::AccountUnfrozen {
+				user: ALICE,
+			}));
+			assert_eq!(Bank::frozen_accounts(ALICE), None);
+			assert_ok!(Bank::transfer(RuntimeOrigin::signed(ALICE), BOB, 100));
+		});
+}
+
+#[test]
+fn auditor_can_amend_locks() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 100)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Auditor));
+			assert_ok!(Bank::lock_funds_auditor(RuntimeOrigin::signed(charlie), ALICE, 200, 20));
+			assert_eq!(Bank::fund_unlock_at(ALICE, 1), 21);
+
+			// Increasing takes from "free" first, then from "reserved".
+			assert_ok!(Bank::increase_auditor_lock(RuntimeOrigin::signed(charlie), ALICE, 1, 850));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::AuditorLockAmended {
+				user: ALICE,
+				lock_id: 1,
+				old_amount: 200,
+				new_amount: 1_050,
+				old_expiry: 21,
+				new_expiry: 21,
+			}));
+			assert_eq!(
+				Accounts::<Runtime>::get(ALICE),
+				AccountData {
+					free: 0,
+					reserved: 50,
+					locked: bounded_vec![LockedFund {
+						id: 1,
+						amount: 1_050,
+						reason: LockReason::Auditor
+					}],
+					debt: 0
+				}
+			);
+			assert_noop!(
+				Bank::increase_auditor_lock(RuntimeOrigin::signed(charlie), ALICE, 1, 51),
+				Error::<Runtime>::InsufficientBalance
+			);
+
+			// Partial unlocks must leave some funds locked.
+			assert_noop!(
+				Bank::partially_unlock_funds_auditor(
+					RuntimeOrigin::signed(charlie),
+					ALICE,
+					1,
+					1_050
+				),
+				Error::<Runtime>::InvalidUnlockAmount
+			);
+			assert_ok!(Bank::partially_unlock_funds_auditor(
+				RuntimeOrigin::signed(charlie),
+				ALICE,
+				1,
+				650
+			));
+			assert_eq!(
+				Accounts::<Runtime>::get(ALICE),
+				AccountData {
+					free: 650,
+					reserved: 50,
+					locked: bounded_vec![LockedFund {
+						id: 1,
+						amount: 400,
+						reason: LockReason::Auditor
+					}],
+					debt: 0
+				}
+			);
+
+			// Extending moves the scheduled unlock.
+			assert_noop!(
+				Bank::extend_auditor_lock(RuntimeOrigin::signed(charlie), ALICE, 1, 0),
+				Error::<Runtime>::InvalidSchedule
+			);
+			assert_ok!(Bank::extend_auditor_lock(RuntimeOrigin::signed(charlie), ALICE, 1, 30));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::AuditorLockAmended {
+				user: ALICE,
+				lock_id: 1,
+				old_amount: 400,
+				new_amount: 400,
+				old_expiry: 21,
+				new_expiry: 51,
+			}));
+			assert_eq!(Bank::fund_unlock_at(ALICE, 1), 51);
+			Bank::on_finalize(21);
+			assert_eq!(Bank::accounts(ALICE).locked.len(), 1);
+			Bank::on_finalize(51);
+			assert_eq!(
+				Accounts::<Runtime>::get(ALICE),
+				AccountData { free: 1_050, reserved: 50, locked: bounded_vec![], debt: 0 }
+			);
+			assert!(Bank::check_total_issuance());
+		});
+}
+
+#[test]
+fn only_auditor_locks_can_be_amended() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Auditor));
+			assert_ok!(Bank::stake_funds(RuntimeOrigin::signed(ALICE), 500));
+
+			assert_noop!(
+				Bank::increase_auditor_lock(RuntimeOrigin::signed(ALICE), ALICE, 1, 100),
+				pallet_roles::Error::<Runtime>::IncorrectRole
+			);
+			assert_noop!(
+				Bank::extend_auditor_lock(RuntimeOrigin::signed(charlie), ALICE, 1, 10),
+				Error::<Runtime>::UnauthorisedUnlock
+			);
+			assert_noop!(
+				Bank::extend_auditor_lock(RuntimeOrigin::signed(charlie), ALICE, 2, 10),
+				Error::<Runtime>::InvalidLockId
+			);
+		});
+}
+
+#[test]
+fn unscheduled_auditor_locks_cannot_be_extended() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Auditor));
+			assert_ok!(Bank::lock_funds_auditor(RuntimeOrigin::signed(charlie), ALICE, 200, 20));
+			assert_eq!(Bank::lock_expiries(ALICE, 1), Some(21));
+
+			// A lock without a schedule is not rescheduled at a past block.
+			AccountWithUnlockedFund::<Runtime>::remove(21);
+			LockExpiries::<Runtime>::remove(ALICE, 1);
+			assert_noop!(
+				Bank::extend_auditor_lock(RuntimeOrigin::signed(charlie), ALICE, 1, 10),
+				Error::<Runtime>::LockNotScheduled
+			);
+		});
+}
+
+#[test]
+fn ledger_records_account_statements() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0), (BOB, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Manager));
+
+			assert_ok!(Bank::deposit(RuntimeOrigin::signed(charlie), ALICE, 500));
+			System::set_block_number(10);
+			assert_ok!(Bank::transfer(RuntimeOrigin::signed(ALICE), BOB, 200));
+			System::set_block_number(20);
+			assert_ok!(Bank::withdraw(RuntimeOrigin::signed(charlie), ALICE, 100));
+			assert_ok!(Bank::stake_funds(RuntimeOrigin::signed(ALICE), 600));
+			Bank::on_finalize(20 + STAKE_PERIOD);
+
+			assert_eq!(
+				Bank::account_statement(ALICE, 0, 1_000, 0, MAX_STATEMENT_PAGE_SIZE),
+				vec![
+					LedgerEntry {
+						block: 1,
+						kind: LedgerEntryKind::Deposit,
+						amount: 500,
+						counterparty: None,
+						reference: None,
+						free: 1_500,
+						reserved: 0,
+					},
+					LedgerEntry {
+						block: 10,
+						kind: LedgerEntryKind::TransferOut,
+						amount: 200,
+						counterparty: Some(BOB),
+						reference: None,
+						free: 1_300,
+						reserved: 0,
+					},
+					LedgerEntry {
+						block: 20,
+						kind: LedgerEntryKind::Withdrawal,
+						amount: 100,
+						counterparty: None,
+						reference: None,
+						free: 1_200,
+						reserved: 0,
+					},
+					LedgerEntry {
+						block: 20,
+						kind: LedgerEntryKind::Lock,
+						amount: 600,
+						counterparty: None,
+						reference: None,
+						free: 600,
+						reserved: 0,
+					},
+					LedgerEntry {
+						block: 20,
+						kind: LedgerEntryKind::Unlock,
+						amount: 600,
+						counterparty: None,
+						reference: None,
+						free: 600,
+						reserved: 600,
+					},
+				]
+			);
+			assert_eq!(
+				Bank::account_statement(BOB, 0, 1_000, 0, MAX_STATEMENT_PAGE_SIZE),
+				vec![LedgerEntry {
+					block: 10,
+					kind: LedgerEntryKind::TransferIn,
+					amount: 200,
+					counterparty: Some(ALICE),
+					reference: None,
+					free: 1_200,
+					reserved: 0,
+				}]
+			);
+
+			// Statements are filtered by block.
+			assert_eq!(Bank::account_statement(ALICE, 5, 15, 0, MAX_STATEMENT_PAGE_SIZE).len(), 1);
+			assert_eq!(
+				Bank::account_statement(ALICE, 21, 1_000, 0, MAX_STATEMENT_PAGE_SIZE),
+				vec![]
+			);
+		});
+}
+
+#[test]
+fn account_statements_are_paginated() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0), (BOB, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			(1..=5).for_each(|block| {
+				System::set_block_number(block);
+				assert_ok!(Bank::transfer(RuntimeOrigin::signed(ALICE), BOB, 10));
+			});
+			let statement = Bank::account_statement(ALICE, 0, 1_000, 0, MAX_STATEMENT_PAGE_SIZE);
+			assert_eq!(statement.len(), 5);
+
+			// Pages follow each other without overlapping.
+			let pages = [0, 2, 4]
+				.into_iter()
+				.map(|offset| Bank::account_statement(ALICE, 0, 1_000, offset, 2))
+				.collect::<Vec<_>>();
+			assert_eq!(pages.iter().map(Vec::len).collect::<Vec<_>>(), vec![2, 2, 1]);
+			assert_eq!(pages.concat(), statement);
+			assert_eq!(Bank::account_statement(ALICE, 0, 1_000, 5, 2), vec![]);
+
+			// Pages are filtered by block before they are split.
+			assert_eq!(Bank::account_statement(ALICE, 3, 1_000, 1, 2), statement[3..5].to_vec());
+
+			// The page size is capped.
+			assert_eq!(Bank::account_statement(ALICE, 0, 1_000, 0, u32::MAX), statement);
+			assert_eq!(Bank::account_statement(ALICE, 0, 1_000, 0, 0), vec![]);
+		});
+}
+
+#[test]
+fn ledger_prunes_the_oldest_entries() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0), (BOB, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			(1..=7u64).for_each(|block| {
+				System::set_block_number(block);
+				assert_ok!(Bank::transfer(RuntimeOrigin::signed(ALICE), BOB, 10));
+			});
+
+			// Only the latest `MaxLedgerEntries` entries are kept.
+			let statement = Bank::account_statement(ALICE, 0, 1_000, 0, MAX_STATEMENT_PAGE_SIZE);
+			assert_eq!(
+				statement.iter().map(|entry| entry.block).collect::<Vec<_>>(),
+				vec![3, 4, 5, 6, 7]
+			);
+			assert_eq!(statement[4].free, 930);
+			assert_eq!(LedgerIndices::<Runtime>::get(ALICE), (2, 7));
+			assert_eq!(LedgerEntries::<Runtime>::get(ALICE, 1), None);
+		});
+}
+
+#[test]
+fn payments_can_carry_memos_and_references() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0), (BOB, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Manager));
+
+			let reference = Memo::External(bounded_vec![b'F', b'X', b'1']);
+			assert_ok!(Bank::deposit_with_reference(
+				RuntimeOrigin::signed(charlie),
+				ALICE,
+				500,
+				reference.clone()
+			));
+			System::assert_last_event(RuntimeEvent::Bank(
+				Event::<Runtime>::DepositedWithReference {
+					user: ALICE,
+					amount: 500,
+					reference: PaymentReference::Memo(reference.clone()),
+				},
+			));
+			assert_eq!(
+				Bank::account_statement(ALICE, 0, 1_000, 0, MAX_STATEMENT_PAGE_SIZE)[0].reference,
+				Some(PaymentReference::Memo(reference))
+			);
+
+			let memo = Memo::Invoice(bounded_vec![b'4', b'2']);
+			assert_ok!(Bank::transfer_with_memo(
+				RuntimeOrigin::signed(ALICE),
+				BOB,
+				200,
+				memo.clone()
+			));
+			System::assert_has_event(RuntimeEvent::Bank(
+				Event::<Runtime>::TransferredWithReference {
+					from: ALICE,
+					to: BOB,
+					amount: 200,
+					reference: PaymentReference::Memo(memo.clone()),
+				},
+			));
+			assert_eq!(
+				Bank::account_statement(ALICE, 0, 1_000, 0, MAX_STATEMENT_PAGE_SIZE)[1].reference,
+				Some(PaymentReference::Memo(memo.clone()))
+			);
+			assert_eq!(
+				Bank::account_statement(BOB, 0, 1_000, 0, MAX_STATEMENT_PAGE_SIZE)[0].reference,
+				Some(PaymentReference::Memo(memo))
+			);
+
+			// Other pallets tag their transfers with a reason.
+			assert_ok!(<Bank as BasicAccounting<AccountId, Balance>>::transfer_with_reason(
+				&BOB,
+				&ALICE,
+				100,
+				TransferReason::LotteryTicket
+			));
+			assert_eq!(
+				Bank::account_statement(BOB, 0, 1_000, 0, MAX_STATEMENT_PAGE_SIZE)[1].reference,
+				Some(PaymentReference::Reason(TransferReason::LotteryTicket))
+			);
+
+			// Memos are only accepted from a customer to another customer, and deposits with a
+			// reference require a manager.
+			assert_noop!(
+				Bank::transfer_with_memo(
+					RuntimeOrigin::signed(ALICE),
+					charlie,
+					100,
+					Memo::Text(bounded_vec![])
+				),
+				pallet_roles::Error::<Runtime>::IncorrectRole
+			);
+			assert_noop!(
+				Bank::deposit_with_reference(
+					RuntimeOrigin::signed(ALICE),
+					ALICE,
+					100,
+					Memo::Text(bounded_vec![])
+				),
+				pallet_roles::Error::<Runtime>::IncorrectRole
+			);
+		});
+}
+
+#[test]
+fn transfer_fees_are_paid_to_treasury() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 10_000, 0), (BOB, 10_000, 0)])
+		.build()
+		.execute_with(|| {
+			TreasuryAccount::<Runtime>::set(Some(TREASURY));
+			let charlie: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Manager));
+
+			// Transfers are free without a fee schedule.
+			assert_eq!(Bank::transfer_fee(&ALICE, 1_000), 0);
+			assert_ok!(Bank::transfer(RuntimeOrigin::signed(ALICE), BOB, 1_000));
+			assert_eq!(Bank::accounts(ALICE).free, 9_000);
+
+			assert_noop!(
+				Bank::set_transfer_fee_schedule(
+					RawOrigin::Root.into(),
+					Some(FeeSchedule::Tiered(bounded_vec![(100, 5), (100, 10)]))
+				),
+				Error::<Runtime>::InvalidFeeSchedule
+			);
+			let schedule = FeeSchedule::Tiered(bounded_vec![(0, 5), (1_000, 10)]);
+			assert_ok!(Bank::set_transfer_fee_schedule(
+				RawOrigin::Root.into(),
+				Some(schedule.clone())
+			));
+			System::assert_last_event(RuntimeEvent::Bank(
+				Event::<Runtime>::TransferFeeScheduleSet { schedule: Some(schedule) },
+			));
+			assert_eq!(Bank::transfer_fee(&ALICE, 999), 5);
+			assert_eq!(Bank::transfer_fee(&ALICE, 1_000), 10);
+			// Managers are exempt.
+			assert_eq!(Bank::transfer_fee(&charlie, 1_000), 0);
+
+			assert_ok!(Bank::transfer(RuntimeOrigin::signed(ALICE), BOB, 1_000));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::TransferFeeCharged {
+				user: ALICE,
+				fee: 10,
+			}));
+			assert_eq!(Bank::accounts(ALICE).free, 7_990);
+			assert_eq!(Bank::accounts(BOB).free, 12_000);
+			assert_eq!(Bank::accounts(TREASURY).free, 1_000_010);
+
+			// The fee must be affordable on top of the amount.
+			assert_noop!(
+				Bank::transfer(RuntimeOrigin::signed(ALICE), BOB, 7_985),
+				Error::<Runtime>::InsufficientBalance
+			);
+
+			assert_ok!(Bank::set_transfer_fee_schedule(
+				RawOrigin::Root.into(),
+				Some(FeeSchedule::Percentage(Perbill::from_percent(1)))
+			));
+			assert_eq!(Bank::transfer_fee(&ALICE, 2_000), 20);
+			assert_ok!(Bank::set_transfer_fee_schedule(RawOrigin::Root.into(), None));
+			assert_eq!(Bank::transfer_fee(&ALICE, 2_000), 0);
+			assert!(Bank::check_total_issuance());
+		});
+}
+
+#[test]
+fn velocity_limits_roll_over_by_day_and_week() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0), (BOB, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Manager));
+			assert_eq!(Bank::remaining_allowance(&ALICE), None);
+
+			let limit = VelocityLimit { daily: 100, weekly: 250 };
+			assert_noop!(
+				Bank::set_default_velocity_limit(RuntimeOrigin::signed(ALICE), Some(limit)),
+				pallet_roles::Error::<Runtime>::IncorrectRole
+			);
+			assert_ok!(Bank::set_default_velocity_limit(
+				RuntimeOrigin::signed(charlie),
+				Some(limit)
+			));
+			System::assert_last_event(RuntimeEvent::Bank(
+				Event::<Runtime>::DefaultVelocityLimitSet { limit: Some(limit) },
+			));
+
+			assert_ok!(Bank::transfer(RuntimeOrigin::signed(ALICE), BOB, 60));
+			assert_noop!(
+				Bank::transfer(RuntimeOrigin::signed(ALICE), BOB, 50),
+				Error::<Runtime>::LimitExceeded
+			);
+			// Staking counts towards the limit.
+			assert_ok!(Bank::stake_funds(RuntimeOrigin::signed(ALICE), 40));
+			assert_eq!(
+				Bank::remaining_allowance(&ALICE),
+				Some(VelocityLimit { daily: 0, weekly: 150 })
+			);
+
+			// The daily limit rolls over the next day.
+			System::set_block_number(10);
+			assert_ok!(Bank::transfer(RuntimeOrigin::signed(ALICE), BOB, 100));
+			System::set_block_number(20);
+			assert_noop!(
+				Bank::transfer(RuntimeOrigin::signed(ALICE), BOB, 60),
+				Error::<Runtime>::LimitExceeded
+			);
+			assert_ok!(Bank::transfer(RuntimeOrigin::signed(ALICE), BOB, 50));
+			assert_eq!(
+				Bank::remaining_allowance(&ALICE),
+				Some(VelocityLimit { daily: 50, weekly: 0 })
+			);
+
+			// The weekly limit rolls over the next week.
+			System::set_block_number(70);
+			assert_eq!(Bank::remaining_allowance(&ALICE), Some(limit));
+
+			// Withdrawals made by a manager don't count towards the limit.
+			assert_ok!(Bank::withdraw(RuntimeOrigin::signed(charlie), ALICE, 200));
+			assert_eq!(Bank::remaining_allowance(&ALICE), Some(limit));
+		});
+}
+
+#[test]
+fn escrows_products_and_loans_count_towards_velocity_limits() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 1_000), (BOB, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			let dave: AccountId = 4u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Manager));
+			assert_ok!(Roles::register_role(&dave, Role::Auditor));
+			let kind = ProductKind::FixedTerm {
+				term: 100,
+				rate: Perbill::from_percent(10),
+				penalty: Perbill::from_percent(2),
+			};
+			assert_ok!(Bank::create_product(RuntimeOrigin::signed(charlie), kind));
+			assert_ok!(Bank::set_default_velocity_limit(
+				RuntimeOrigin::signed(charlie),
+				Some(VelocityLimit { daily: 300, weekly: 1_000 })
+			));
+
+			assert_noop!(
+				Bank::create_escrow(RuntimeOrigin::signed(ALICE), BOB, dave, 301, 20),
+				Error::<Runtime>::LimitExceeded
+			);
+			assert_ok!(Bank::create_escrow(RuntimeOrigin::signed(ALICE), BOB, dave, 100, 20));
+			assert_eq!(
+				Bank::remaining_allowance(&ALICE),
+				Some(VelocityLimit { daily: 200, weekly: 900 })
+			);
+
+			assert_noop!(
+				Bank::stake_funds_into_product(RuntimeOrigin::signed(ALICE), 1, 201),
+				Error::<Runtime>::LimitExceeded
+			);
+			assert_ok!(Bank::stake_funds_into_product(RuntimeOrigin::signed(ALICE), 1, 100));
+
+			assert_noop!(
+				Bank::borrow(RuntimeOrigin::signed(ALICE), 200, 100),
+				Error::<Runtime>::LimitExceeded
+			);
+			assert_ok!(Bank::borrow(RuntimeOrigin::signed(ALICE), 100, 50));
+			assert_eq!(
+				Bank::remaining_allowance(&ALICE),
+				Some(VelocityLimit { daily: 0, weekly: 700 })
+			);
+		});
+}
+
+#[test]
+fn managers_can_override_velocity_limits() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0), (BOB, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Manager));
+			assert_ok!(Bank::set_default_velocity_limit(
+				RuntimeOrigin::signed(charlie),
+				Some(VelocityLimit { daily: 100, weekly: 250 })
+			));
+
+			let limit = VelocityLimit { daily: 500, weekly: 500 };
+			assert_ok!(Bank::set_velocity_limit(RuntimeOrigin::signed(charlie), BOB, Some(limit)));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::VelocityLimitSet {
+				user: BOB,
+				limit: Some(limit),
+			}));
+			assert_ok!(Bank::transfer(RuntimeOrigin::signed(BOB), ALICE, 500));
+			assert_noop!(
+				Bank::transfer(RuntimeOrigin::signed(ALICE), BOB, 500),
+				Error::<Runtime>::LimitExceeded
+			);
+
+			// Removing the override applies the default limit again.
+			assert_ok!(Bank::set_velocity_limit(RuntimeOrigin::signed(charlie), BOB, None));
+			assert_eq!(
+				Bank::remaining_allowance(&BOB),
+				Some(VelocityLimit { daily: 0, weekly: 0 })
+			);
+			assert_eq!(Bank::remaining_allowance(&charlie), None);
+		});
+}
+
+#[test]
+fn joint_accounts_need_holder_approval_to_transfer() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0), (BOB, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Customer));
+
+			assert_noop!(
+				Bank::create_joint_account(RuntimeOrigin::signed(ALICE), vec![BOB, charlie], 2),
+				Error::<Runtime>::NotJointHolder
+			);
+			assert_noop!(
+				Bank::create_joint_account(RuntimeOrigin::signed(ALICE), vec![ALICE, BOB], 3),
+				Error::<Runtime>::InvalidJointAccount
+			);
+
+			// Holders are sorted, so the order does not change the account Id.
+			assert_ok!(Bank::create_joint_account(
+				RuntimeOrigin::signed(ALICE),
+				vec![charlie, ALICE, BOB, ALICE],
+				2
+			));
+			let joint = Bank::joint_account_id(&[ALICE, BOB, charlie], 2);
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::JointAccountCreated {
+				joint_account: joint,
+				holders: vec![ALICE, BOB, charlie],
+				threshold: 2,
+			}));
+			assert_eq!(Roles::role(&joint), Some(Role::Customer));
+			assert_noop!(
+				Bank::create_joint_account(
+					RuntimeOrigin::signed(BOB),
+					vec![ALICE, BOB, charlie],
+					2
+				),
+				Error::<Runtime>::AccountIdAlreadyTaken
+			);
+			assert_ok!(Bank::transfer(RuntimeOrigin::signed(ALICE), joint, 500));
+
+			// Transfers run once enough holders approve them.
+			assert_noop!(
+				Bank::propose_joint_transfer(RuntimeOrigin::signed(joint), joint, BOB, 100),
+				Error::<Runtime>::NotJointHolder
+			);
+			assert_ok!(Bank::propose_joint_transfer(RuntimeOrigin::signed(ALICE), joint, BOB, 100));
+			System::assert_has_event(RuntimeEvent::Bank(Event::<Runtime>::JointTransferProposed {
+				proposal_id: 1,
+				joint_account: joint,
+				proposer: ALICE,
+				to: BOB,
+				amount: 100,
+				expiry: 11,
+			}));
+			assert_eq!(Bank::accounts(joint).free, 500);
+			assert_noop!(
+				Bank::vote_joint_transfer(RuntimeOrigin::signed(ALICE), 1, true),
+				Error::<Runtime>::AlreadyVoted
+			);
+			assert_ok!(Bank::vote_joint_transfer(RuntimeOrigin::signed(charlie), 1, true));
+			System::assert_last_event(RuntimeEvent::Bank(
+				Event::<Runtime>::JointTransferExecuted { proposal_id: 1 },
+			));
+			assert_eq!(Bank::accounts(joint).free, 400);
+			assert_eq!(Bank::accounts(BOB).free, 1_100);
+			assert_eq!(Bank::joint_transfers(1), None);
+
+			// Transfers are dropped once too many holders reject them.
+			assert_ok!(Bank::propose_joint_transfer(RuntimeOrigin::signed(ALICE), joint, BOB, 100));
+			assert_ok!(Bank::vote_joint_transfer(RuntimeOrigin::signed(BOB), 2, false));
+			assert!(Bank::joint_transfers(2).is_some());
+			assert_ok!(Bank::vote_joint_transfer(RuntimeOrigin::signed(charlie), 2, false));
+			System::assert_last_event(RuntimeEvent::Bank(
+				Event::<Runtime>::JointTransferRejected { proposal_id: 2 },
+			));
+			assert_eq!(Bank::joint_transfers(2), None);
+			assert_eq!(Bank::accounts(joint).free, 400);
+			assert_noop!(
+				Bank::vote_joint_transfer(RuntimeOrigin::signed(BOB), 2, true),
+				Error::<Runtime>::InvalidJointTransferId
+			);
+			assert!(Bank::check_total_issuance());
+		});
+}
+
+#[test]
+fn joint_transfers_expire_and_failed_ones_are_dropped() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0), (BOB, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Customer));
+			assert_ok!(Bank::create_joint_account(
+				RuntimeOrigin::signed(ALICE),
+				vec![ALICE, BOB, charlie],
+				2
+			));
+			let joint = Bank::joint_account_id(&[ALICE, BOB, charlie], 2);
+			assert_ok!(Bank::transfer(RuntimeOrigin::signed(ALICE), joint, 500));
+
+			// Expired transfers can't be voted on, and are cancelled by a holder.
+			assert_ok!(Bank::propose_joint_transfer(RuntimeOrigin::signed(ALICE), joint, BOB, 100));
+			assert_eq!(Bank::joint_transfers(1).map(|transfer| transfer.expiry), Some(11));
+			assert_noop!(
+				Bank::cancel_joint_transfer(RuntimeOrigin::signed(BOB), 1),
+				Error::<Runtime>::JointTransferNotExpired
+			);
+			System::set_block_number(11);
+			assert_noop!(
+				Bank::vote_joint_transfer(RuntimeOrigin::signed(BOB), 1, true),
+				Error::<Runtime>::JointTransferExpired
+			);
+			assert_noop!(
+				Bank::cancel_joint_transfer(RuntimeOrigin::signed(TREASURY), 1),
+				Error::<Runtime>::NotJointHolder
+			);
+			assert_ok!(Bank::cancel_joint_transfer(RuntimeOrigin::signed(BOB), 1));
+			System::assert_last_event(RuntimeEvent::Bank(
+				Event::<Runtime>::JointTransferCancelled { proposal_id: 1, who: BOB },
+			));
+			assert_eq!(Bank::joint_transfers(1), None);
+			assert_eq!(Bank::accounts(joint).free, 500);
+
+			// An approved transfer that fails is dropped with the votes kept.
+			assert_ok!(Bank::propose_joint_transfer(RuntimeOrigin::signed(ALICE), joint, BOB, 600));
+			assert_ok!(Bank::vote_joint_transfer(RuntimeOrigin::signed(charlie), 2, true));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::JointTransferFailed {
+				proposal_id: 2,
+				error: Error::<Runtime>::InsufficientBalance.into(),
+			}));
+			assert_eq!(Bank::joint_transfers(2), None);
+			assert_eq!(Bank::accounts(joint).free, 500);
+			assert_eq!(Bank::accounts(BOB).free, 1_000);
+			assert!(Bank::check_total_issuance());
+		});
+}
+
+#[test]
+fn spenders_can_transfer_within_allowances() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0), (BOB, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Customer));
+			assert_noop!(
+				Bank::transfer_from(RuntimeOrigin::signed(BOB), ALICE, charlie, 100),
+				Error::<Runtime>::AllowanceNotFound
+			);
+
+			assert_noop!(
+				Bank::approve(RuntimeOrigin::signed(ALICE), BOB, 150, Some(0)),
+				Error::<Runtime>::InvalidSchedule
+			);
+			assert_ok!(Bank::approve(RuntimeOrigin::signed(ALICE), BOB, 150, Some(10)));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::AllowanceApproved {
+				owner: ALICE,
+				spender: BOB,
+				amount: 150,
+				expiry: Some(11),
+			}));
+
+			assert_ok!(Bank::transfer_from(RuntimeOrigin::signed(BOB), ALICE, charlie, 100));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::TransferredFrom {
+				spender: BOB,
+				from: ALICE,
+				to: charlie,
+				amount: 100,
+			}));
+			assert_eq!(Bank::accounts(ALICE).free, 900);
+			assert_eq!(Bank::accounts(charlie).free, 100);
+			assert_eq!(
+				Bank::allowances(ALICE, BOB),
+				Some(Allowance { amount: 50, expiry: Some(11) })
+			);
+			assert_noop!(
+				Bank::transfer_from(RuntimeOrigin::signed(BOB), ALICE, charlie, 51),
+				Error::<Runtime>::AllowanceExceeded
+			);
+
+			// Increasing keeps the expiry.
+			assert_ok!(Bank::increase_allowance(RuntimeOrigin::signed(ALICE), BOB, 50));
+			assert_eq!(
+				Bank::allowances(ALICE, BOB),
+				Some(Allowance { amount: 100, expiry: Some(11) })
+			);
+			System::set_block_number(11);
+			assert_noop!(
+				Bank::transfer_from(RuntimeOrigin::signed(BOB), ALICE, charlie, 50),
+				Error::<Runtime>::AllowanceExpired
+			);
+			// Expired allowances are not revived by increasing them.
+			assert_noop!(
+				Bank::increase_allowance(RuntimeOrigin::signed(ALICE), BOB, 50),
+				Error::<Runtime>::AllowanceExpired
+			);
+
+			assert_ok!(Bank::revoke(RuntimeOrigin::signed(ALICE), BOB));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::AllowanceRevoked {
+				owner: ALICE,
+				spender: BOB,
+			}));
+			assert_noop!(
+				Bank::revoke(RuntimeOrigin::signed(ALICE), BOB),
+				Error::<Runtime>::AllowanceNotFound
+			);
+
+			// Spending the whole allowance removes it.
+			assert_ok!(Bank::increase_allowance(RuntimeOrigin::signed(ALICE), BOB, 100));
+			assert_ok!(Bank::transfer_from(RuntimeOrigin::signed(BOB), ALICE, BOB, 100));
+			assert_eq!(Bank::allowances(ALICE, BOB), None);
+			assert!(Bank::check_total_issuance());
+		});
+}
+
+#[test]
+fn batch_transfers_are_atomic_or_best_effort() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0), (BOB, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			let dave: AccountId = 4u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Customer));
+			assert_ok!(Roles::register_role(&dave, Role::Manager));
+
+			// Batches are bounded by `MaxBatchTransfers`.
+			assert!(BoundedVec::<_, <Runtime as Config>::MaxBatchTransfers>::try_from(vec![
+				(BOB, 10),
+				(charlie, 10),
+				(BOB, 10),
+				(charlie, 10)
+			])
+			.is_err());
+
+			// A failed leg fails the whole batch.
+			assert_noop!(
+				Bank::batch_transfer(
+					RuntimeOrigin::signed(ALICE),
+					bounded_vec![(BOB, 100), (dave, 100)],
+					BatchMode::Atomic
+				),
+				pallet_roles::Error::<Runtime>::IncorrectRole
+			);
+			assert_noop!(
+				Bank::batch_transfer(
+					RuntimeOrigin::signed(ALICE),
+					bounded_vec![(BOB, 100), (charlie, 1)],
+					BatchMode::Atomic
+				),
+				Error::<Runtime>::AmountTooSmall
+			);
+
+			assert_ok!(Bank::batch_transfer(
+				RuntimeOrigin::signed(ALICE),
+				bounded_vec![(BOB, 100), (charlie, 200)],
+				BatchMode::Atomic
+			));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::BatchTransferred {
+				from: ALICE,
+				results: vec![Ok(()), Ok(())],
+			}));
+			assert_eq!(Bank::accounts(ALICE).free, 700);
+			assert_eq!(Bank::accounts(BOB).free, 1_100);
+			assert_eq!(Bank::accounts(charlie).free, 200);
+
+			// Failed legs are skipped and reported.
+			assert_ok!(Bank::batch_transfer(
+				RuntimeOrigin::signed(ALICE),
+				bounded_vec![(dave, 100), (charlie, 1_000), (BOB, 100)],
+				BatchMode::BestEffort
+			));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::BatchTransferred {
+				from: ALICE,
+				results: vec![
+					Err(pallet_roles::Error::<Runtime>::IncorrectRole.into()),
+					Err(Error::<Runtime>::InsufficientBalance.into()),
+					Ok(()),
+				],
+			}));
+			assert_eq!(Bank::accounts(ALICE).free, 600);
+			assert_eq!(Bank::accounts(BOB).free, 1_200);
+			assert_eq!(Bank::accounts(charlie).free, 200);
+			assert!(Bank::check_total_issuance());
+		});
+}
+
+#[test]
+fn fungible_traits_use_bank_funds() {
+	use frame_support::traits::{
+		fungible::{Inspect, InspectHold, Mutate, MutateHold},
+		tokens::{Fortitude, Precision, Preservation},
+	};
+
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 100), (BOB, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			// Bank accounts are provided for, so they can send transactions.
+			assert_eq!(System::providers(&ALICE), 1);
+
+			assert_eq!(<Bank as Inspect<AccountId>>::total_issuance(), 2_100);
+			assert_eq!(<Bank as Inspect<AccountId>>::minimum_balance(), ED);
+			assert_eq!(<Bank as Inspect<AccountId>>::balance(&ALICE), 1_000);
+			assert_eq!(<Bank as Inspect<AccountId>>::total_balance(&ALICE), 1_100);
+			assert_eq!(
+				<Bank as Inspect<AccountId>>::reducible_balance(
+					&BOB,
+					Preservation::Preserve,
+					Fortitude::Polite
+				),
+				1_000 - ED
+			);
+			assert_eq!(
+				<Bank as Inspect<AccountId>>::reducible_balance(
+					&BOB,
+					Preservation::Expendable,
+					Fortitude::Polite
+				),
+				1_000
+			);
+
+			assert_ok!(<Bank as Mutate<AccountId>>::mint_into(&BOB, 100));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::Deposited {
+				user: BOB,
+				amount: 100,
+			}));
+			assert_eq!(Bank::accounts(BOB).free, 1_100);
+			assert_eq!(Bank::total_issuance(), 2_200);
+
+			assert_ok!(<Bank as Mutate<AccountId>>::transfer(
+				&ALICE,
+				&BOB,
+				100,
+				Preservation::Preserve
+			));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::Transferred {
+				from: ALICE,
+				to: BOB,
+				amount: 100,
+			}));
+			assert_eq!(Bank::accounts(ALICE).free, 900);
+			assert_eq!(Bank::accounts(BOB).free, 1_200);
+			assert!(<Bank as Mutate<AccountId>>::transfer(
+				&BOB,
+				&ALICE,
+				1_200,
+				Preservation::Preserve
+			)
+			.is_err());
+
+			// Held funds are kept in a single lock.
+			assert_ok!(<Bank as MutateHold<AccountId>>::hold(&(), &ALICE, 300));
+			assert_ok!(<Bank as MutateHold<AccountId>>::hold(&(), &ALICE, 100));
+			assert_eq!(Bank::accounts(ALICE).free, 500);
+			assert_eq!(<Bank as InspectHold<AccountId>>::balance_on_hold(&(), &ALICE), 400);
+			assert_eq!(<Bank as InspectHold<AccountId>>::total_balance_on_hold(&ALICE), 400);
+			assert_eq!(
+				Bank::accounts(ALICE).locked,
+				vec![LockedFund { id: 1, amount: 400, reason: LockReason::Hold }]
+			);
+			assert_eq!(<Bank as Inspect<AccountId>>::total_balance(&ALICE), 1_000);
+
+			assert_ok!(<Bank as MutateHold<AccountId>>::release(
+				&(),
+				&ALICE,
+				400,
+				Precision::Exact
+			));
+			assert_eq!(Bank::accounts(ALICE).free, 900);
+			assert!(Bank::accounts(ALICE).locked.is_empty());
+			assert_eq!(<Bank as InspectHold<AccountId>>::balance_on_hold(&(), &ALICE), 0);
+			assert!(Bank::check_total_issuance());
+		});
+}
+
+#[test]
+fn governance_can_sponsor_calls_and_fees_go_to_treasury() {
+	use frame_support::traits::{
+		fungible::Balanced,
+		tokens::{Fortitude, Precision, Preservation},
+		OnUnbalanced,
+	};
+
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			let call = (1u8, 2u8, 0u8);
+			assert!(!Bank::is_sponsored(&call));
+			assert_noop!(
+				Bank::set_call_sponsorship(RuntimeOrigin::signed(ALICE), 1, 2, true),
+				sp_runtime::traits::BadOrigin
+			);
+
+			assert_ok!(Bank::set_call_sponsorship(RawOrigin::Root.into(), 1, 2, true));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::CallSponsorshipSet {
+				pallet_index: 1,
+				call_index: 2,
+				sponsored: true,
+			}));
+			assert!(Bank::is_sponsored(&call));
+			assert!(!Bank::is_sponsored(&(1u8, 3u8)));
+
+			assert_ok!(Bank::set_call_sponsorship(RawOrigin::Root.into(), 1, 2, false));
+			assert!(!Bank::is_sponsored(&call));
+			assert!(Bank::sponsored_calls().is_empty());
+
+			// Fees are burned while there is no treasury.
+			let fee = <Bank as Balanced<AccountId>>::withdraw(
+				&ALICE,
+				100,
+				Precision::Exact,
+				Preservation::Preserve,
+				Fortitude::Polite,
+			)
+			.unwrap();
+			FeesToTreasury::<Runtime>::on_unbalanced(fee);
+			assert_eq!(Bank::free_balance(&ALICE), 900);
+			assert_eq!(Bank::free_balance(&TREASURY), INITIAL_BALANCE);
+
+			// Fees are paid into the treasury.
+			TreasuryAccount::<Runtime>::put(TREASURY);
+			let fee = <Bank as Balanced<AccountId>>::withdraw(
+				&ALICE,
+				100,
+				Precision::Exact,
+				Preservation::Preserve,
+				Fortitude::Polite,
+			)
+			.unwrap();
+			FeesToTreasury::<Runtime>::on_unbalanced(fee);
+			assert_eq!(Bank::free_balance(&ALICE), 800);
+			assert_eq!(Bank::free_balance(&TREASURY), INITIAL_BALANCE + 100);
+			assert!(Bank::check_total_issuance());
+		});
+}
+
+#[test]
+fn cannot_exceed_storage_bounds() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0), (BOB, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			// An account has at most `MaxLocks` locks.
+			(0..10).for_each(|_| assert_ok!(Bank::stake_funds(RuntimeOrigin::signed(ALICE), 10)));
+			assert_eq!(Bank::accounts(ALICE).locked.len(), 10);
+			assert_noop!(
+				Bank::stake_funds(RuntimeOrigin::signed(ALICE), 10),
+				Error::<Runtime>::TooManyLocks
+			);
+
+			// At most `MaxScheduledPerBlock` unlocks are scheduled at a block.
+			assert_noop!(
+				Bank::stake_funds(RuntimeOrigin::signed(BOB), 10),
+				Error::<Runtime>::TooManyScheduled
+			);
+
+			// At most `MaxSponsoredCalls` calls are sponsored.
+			(0..10u8).for_each(|call_index| {
+				assert_ok!(Bank::set_call_sponsorship(RawOrigin::Root.into(), 1, call_index, true))
+			});
+			assert_noop!(
+				Bank::set_call_sponsorship(RawOrigin::Root.into(), 1, 10, true),
+				Error::<Runtime>::TooManySponsoredCalls
+			);
+		});
+}
+
+#[test]
+fn interest_rate_history_is_kept_in_full() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			let charlie: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&charlie, Role::Manager));
+			[(100, 20), (200, 30), (300, 40), (400, 50)]
+				.into_iter()
+				.for_each(|(rate, block)| {
+					assert_ok!(Bank::schedule_interest_rate(
+						RuntimeOrigin::signed(charlie),
+						rate,
+						block
+					));
+					Bank::on_finalize(block);
+				});
+			assert_eq!(
+				Bank::interest_rate_history(0, 1_000, 0, MAX_STATEMENT_PAGE_SIZE),
+				vec![
+					(20, Perbill::from_percent(1)),
+					(30, Perbill::from_percent(2)),
+					(40, Perbill::from_percent(3)),
+					(50, Perbill::from_percent(4)),
+				]
+			);
+			assert_eq!(Bank::interest_rate_changes(20), Some(Perbill::from_percent(1)));
+			assert_eq!(
+				Bank::interest_rate_history(0, 1_000, 1, 2),
+				vec![(30, Perbill::from_percent(2)), (40, Perbill::from_percent(3))]
+			);
+			assert_eq!(
+				Bank::interest_rate_history(35, 1_000, 0, MAX_STATEMENT_PAGE_SIZE),
+				vec![(40, Perbill::from_percent(3)), (50, Perbill::from_percent(4))]
+			);
+		});
+}
+
+#[test]
+fn try_state_checks_invariants() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			let auditor: AccountId = 3u32;
+			assert_ok!(Roles::register_role(&auditor, Role::Auditor));
+			assert_ok!(Bank::lock_funds_auditor(RuntimeOrigin::signed(auditor), ALICE, 200, 100));
+			assert_eq!(AccountWithUnlockedFund::<Runtime>::get(101), vec![(ALICE, 1)]);
+			assert_ok!(Bank::do_try_state());
+
+			// Locks unlocked early are removed from the schedule.
+			assert_ok!(Bank::unlock_funds_auditor(RuntimeOrigin::signed(auditor), ALICE, 1));
+			assert!(!AccountWithUnlockedFund::<Runtime>::contains_key(101));
+			assert_ok!(Bank::do_try_state());
+
+			// Every scheduled unlock points to an existing lock.
+			AccountWithUnlockedFund::<Runtime>::insert(200, bounded_vec![(ALICE, 1)]);
+			assert_err!(
+				Bank::do_try_state(),
+				"A scheduled unlock points to a lock that does not exist."
+			);
+			AccountWithUnlockedFund::<Runtime>::remove(200);
+
+			// The total issuance is the sum of the totals.
+			TotalIssuance::<Runtime>::mutate(|total| *total += 1);
+			assert_err!(Bank::do_try_state(), "The total issuance is not the sum of the totals.");
+		});
+}
+
+#[test]
+fn managers_can_approve_and_reject_withdrawal_requests() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(BOB, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Roles::register_role(&ALICE, Role::Manager));
+
+			assert_ok!(Bank::request_withdrawal(RuntimeOrigin::signed(BOB), 300));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::WithdrawalRequested {
+				lock_id: 1,
+				user: BOB,
+				amount: 300,
+				expiry: 11,
+			}));
+			assert_ok!(Bank::request_withdrawal(RuntimeOrigin::signed(BOB), 200));
+			assert_eq!(
+				Accounts::<Runtime>::get(BOB),
+				AccountData {
+					free: 500,
+					reserved: 0,
+					locked: bounded_vec![
+						LockedFund { id: 1, amount: 300, reason: LockReason::Withdrawal },
+						LockedFund { id: 2, amount: 200, reason: LockReason::Withdrawal }
+					],
+					debt: 0
+				}
+			);
+			assert_eq!(Bank::fund_unlock_at(BOB, 1), 11);
+			assert_eq!(Bank::withdrawal_requests_of(Some(BOB)).len(), 2);
+			assert_eq!(Bank::withdrawal_requests_of(Some(ALICE)), vec![]);
+
+			// Only managers can settle the requests.
+			assert_noop!(
+				Bank::approve_withdrawal(RuntimeOrigin::signed(BOB), 1),
+				pallet_roles::Error::<Runtime>::IncorrectRole
+			);
+			assert_ok!(Bank::approve_withdrawal(RuntimeOrigin::signed(ALICE), 1));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::WithdrawalApproved {
+				lock_id: 1,
+				user: BOB,
+				manager: ALICE,
+				amount: 300,
+				payout_reference: BlakeTwo256::hash_of(&(b"bank/withdrawal", 1u64, BOB, 300u128)),
+			}));
+			assert_eq!(TotalIssuance::<Runtime>::get(), 700);
+			assert_eq!(
+				Bank::account_statement(BOB, 0, 1_000, 0, MAX_STATEMENT_PAGE_SIZE)
+					.last()
+					.map(|entry| (entry.kind, entry.amount)),
+				Some((LedgerEntryKind::Withdrawal, 300))
+			);
+			assert_noop!(
+				Bank::approve_withdrawal(RuntimeOrigin::signed(ALICE), 1),
+				Error::<Runtime>::InvalidWithdrawalId
+			);
+
+			assert_ok!(Bank::reject_withdrawal(RuntimeOrigin::signed(ALICE), 2));
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::WithdrawalRejected {
+				lock_id: 2,
+				user: BOB,
+				amount: 200,
+			}));
+			assert_eq!(
+				Accounts::<Runtime>::get(BOB),
+				AccountData { free: 700, reserved: 0, locked: bounded_vec![], debt: 0 }
+			);
+			assert!(!AccountWithUnlockedFund::<Runtime>::contains_key(11));
+			assert_eq!(Bank::withdrawal_requests_of(None), vec![]);
+			assert!(Bank::check_total_issuance());
+		});
+}
+
+#[test]
+fn unsettled_withdrawal_requests_are_refunded_to_the_velocity_limit() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(BOB, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Roles::register_role(&ALICE, Role::Manager));
+			let limit = VelocityLimit { daily: 500, weekly: 800 };
+			assert_ok!(Bank::set_default_velocity_limit(RuntimeOrigin::signed(ALICE), Some(limit)));
+
+			// Rejected requests are refunded.
+			assert_ok!(Bank::request_withdrawal(RuntimeOrigin::signed(BOB), 300));
+			assert_eq!(
+				Bank::remaining_allowance(&BOB),
+				Some(VelocityLimit { daily: 200, weekly: 500 })
+			);
+			assert_ok!(Bank::reject_withdrawal(RuntimeOrigin::signed(ALICE), 1));
+			assert_eq!(Bank::remaining_allowance(&BOB), Some(limit));
+
+			// Expired requests are refunded to the week they were requested in.
+			assert_ok!(Bank::request_withdrawal(RuntimeOrigin::signed(BOB), 300));
+			System::set_block_number(11);
+			Bank::on_finalize(11);
+			assert_eq!(Bank::remaining_allowance(&BOB), Some(limit));
+
+			// Approved requests are not refunded.
+			assert_ok!(Bank::request_withdrawal(RuntimeOrigin::signed(BOB), 300));
+			assert_ok!(Bank::approve_withdrawal(RuntimeOrigin::signed(ALICE), 3));
+			assert_eq!(
+				Bank::remaining_allowance(&BOB),
+				Some(VelocityLimit { daily: 200, weekly: 500 })
+			);
+		});
+}
+
+#[test]
+fn withdrawal_requests_expire() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000, 0), (BOB, 1_000, 0)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Bank::request_withdrawal(RuntimeOrigin::signed(BOB), 4),
+				Error::<Runtime>::AmountTooSmall
+			);
+			assert_noop!(
+				Bank::request_withdrawal(RuntimeOrigin::signed(BOB), 1_001),
+				Error::<Runtime>::InsufficientBalance
+			);
+
+			assert_ok!(Bank::request_withdrawal(RuntimeOrigin::signed(ALICE), 300));
+			assert_ok!(Bank::request_withdrawal(RuntimeOrigin::signed(BOB), 200));
+			assert_eq!(Bank::withdrawal_requests_of(None).len(), 2);
+
+			// The held funds cannot be moved out.
+			assert_noop!(
+				Bank::transfer(RuntimeOrigin::signed(BOB), ALICE, 900),
+				Error::<Runtime>::InsufficientBalance
+			);
+
+			Bank::on_finalize(11);
+			System::assert_last_event(RuntimeEvent::Bank(Event::<Runtime>::WithdrawalExpired {
+				lock_id: 2,
+				user: BOB,
+				amount: 200,
+			}));
+			assert_eq!(Accounts::<Runtime>::get(ALICE).free, 1_000);
+			assert_eq!(Accounts::<Runtime>::get(BOB).free, 1_000);
+			assert!(Accounts::<Runtime>::get(BOB).locked.is_empty());
+			assert_eq!(Bank::withdrawal_requests_of(None), vec![]);
+			assert!(Bank::check_total_issuance());
+		});
+}
@@ -20,7 +20,7 @@ mod benchmarks {
 		);
 
 		let first_proposal = 1u32;
-		CurrentAuthorities::<T>::set(BTreeSet::from([caller.clone()]));
+		CurrentAuthorities::<T>::set(BTreeSet::from([caller.clone()]).try_into().unwrap());
 
 		#[extrinsic_call]
 		initiate_proposal(RawOrigin::Signed(caller), call);
@@ -37,7 +37,9 @@ mod benchmarks {
 		let call = Box::new(frame_system::Call::<T>::remark { remark: vec![] }.into());
 		let first_proposal = 1u32;
 
-		CurrentAuthorities::<T>::set(BTreeSet::from([caller.clone(), caller_2.clone()]));
+		CurrentAuthorities::<T>::set(
+			BTreeSet::from([caller.clone(), caller_2.clone()]).try_into().unwrap(),
+		);
 		assert_ok!(Pallet::<T>::initiate_proposal(RawOrigin::Signed(caller.clone()).into(), call));
 
 		#[extrinsic_call]
@@ -46,7 +48,10 @@ mod benchmarks {
 		// Verify
 		assert_eq!(
 			Votes::<T>::get(first_proposal),
-			CastedVotes { yays: BTreeSet::from([caller, caller_2]), nays: Default::default() }
+			CastedVotes {
+				yays: BTreeSet::from([caller, caller_2]).try_into().unwrap(),
+				nays: Default::default()
+			}
 		);
 	}
 
@@ -11,11 +11,12 @@ use frame_support::{
 use frame_system::pallet_prelude::*;
 use primitives::ProposalId;
 use sp_runtime::{traits::Saturating, DispatchResult, Percent, TransactionOutcome};
-use sp_std::{collections::btree_set::BTreeSet, prelude::*, vec::Vec};
+use sp_std::{collections::btree_set::BTreeSet, fmt::Debug, prelude::*, vec::Vec};
 
 mod mock;
 mod tests;
 
+pub mod migrations;
 pub mod weights;
 pub use weights::*;
 
@@ -29,13 +30,26 @@ pub enum RejectReason {
 }
 
 /// Stores casted votes.
-#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
-pub struct CastedVotes<AccountId> {
-	pub yays: BTreeSet<AccountId>,
-	pub nays: BTreeSet<AccountId>,
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	MaxEncodedLen,
+	RuntimeDebugNoBound,
+	TypeInfo,
+)]
+#[codec(mel_bound(AccountId: MaxEncodedLen))]
+#[scale_info(skip_type_params(MaxVoters))]
+pub struct CastedVotes<AccountId: Ord + Clone + PartialEq + Debug, MaxVoters: Get<u32>> {
+	pub yays: BoundedBTreeSet<AccountId, MaxVoters>,
+	pub nays: BoundedBTreeSet<AccountId, MaxVoters>,
 }
 
-impl<AccountId: sp_std::cmp::Ord + Clone> CastedVotes<AccountId> {
+impl<AccountId: Ord + Clone + PartialEq + Debug, MaxVoters: Get<u32>>
+	CastedVotes<AccountId, MaxVoters>
+{
 	/// Checks if the account with the given ID has already voted on the proposal.
 	pub fn has_voted(&self, id: &AccountId) -> bool {
 		self.yays.contains(id) || self.nays.contains(id)
@@ -43,18 +57,15 @@ impl<AccountId: sp_std::cmp::Ord + Clone> CastedVotes<AccountId> {
 
 	/// Casts a vote on the proposal for the specified account.
 	///
-	/// If the account has already voted, returns false.
+	/// If the account has already voted, or there are more voters than the bound, returns false.
 	/// Otherwise, if the vote is cast successfully, returns true.
 	pub fn cast_vote(&mut self, who: AccountId, approve: bool) -> bool {
 		if self.has_voted(&who) {
 			false
+		} else if approve {
+			self.yays.try_insert(who).unwrap_or(false)
 		} else {
-			if approve {
-				self.yays.insert(who.clone());
-			} else {
-				self.nays.insert(who);
-			}
-			true
+			self.nays.try_insert(who).unwrap_or(false)
 		}
 	}
 
@@ -83,12 +94,20 @@ impl<AccountId: sp_std::cmp::Ord + Clone> CastedVotes<AccountId> {
 	/// Remove all votes that are not in the `retain` set.
 	pub fn cull_votes(&mut self, retain: BTreeSet<AccountId>) {
 		// Retain the votes for members included in the new authorities
-		self.yays.retain(|member| retain.contains(member));
-		self.nays.retain(|member| retain.contains(member));
+		let cull = |votes: &mut BoundedBTreeSet<AccountId, MaxVoters>| {
+			let mut retained = sp_std::mem::take(votes).into_inner();
+			retained.retain(|member| retain.contains(member));
+			// Removing votes keeps the set within the bound.
+			*votes = BoundedBTreeSet::try_from(retained).unwrap_or_default();
+		};
+		cull(&mut self.yays);
+		cull(&mut self.nays);
 	}
 }
 
-impl<AccountId> Default for CastedVotes<AccountId> {
+impl<AccountId: Ord + Clone + PartialEq + Debug, MaxVoters: Get<u32>> Default
+	for CastedVotes<AccountId, MaxVoters>
+{
 	fn default() -> Self {
 		Self { yays: Default::default(), nays: Default::default() }
 	}
@@ -101,7 +120,10 @@ pub mod module {
 	use super::*;
 	use sp_runtime::traits::BlockNumberProvider;
 
-	type EncodedCall = Vec<u8>;
+	type EncodedCall<T> = BoundedVec<u8, <T as Config>::MaxProposalLength>;
+
+	pub type CastedVotesOf<T> =
+		CastedVotes<<T as frame_system::Config>::AccountId, <T as Config>::MaxAuthorities>;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -129,6 +151,18 @@ pub mod module {
 
 		#[pallet::constant]
 		type MajorityThreshold: Get<Percent>;
+
+		/// The maximum number of council members.
+		#[pallet::constant]
+		type MaxAuthorities: Get<u32>;
+
+		/// The maximum number of proposals waiting for the votes of the council.
+		#[pallet::constant]
+		type MaxProposals: Get<u32>;
+
+		/// The maximum length of the encoded call of a proposal.
+		#[pallet::constant]
+		type MaxProposalLength: Get<u32>;
 	}
 
 	#[pallet::error]
@@ -139,6 +173,12 @@ pub mod module {
 		InvalidProposalId,
 		/// The user has voted.
 		AlreadyVoted,
+		/// There are more council members than allowed.
+		TooManyAuthorities,
+		/// There are too many proposals waiting for votes.
+		TooManyProposals,
+		/// The encoded call of the proposal is too long.
+		ProposalTooLong,
 	}
 
 	#[pallet::event]
@@ -172,31 +212,38 @@ pub mod module {
 	/// Use the increased proposalId, stores the encoded extrinsic call.
 	#[pallet::storage]
 	#[pallet::getter(fn proposals)]
-	pub type Proposals<T: Config> = StorageMap<_, Blake2_128Concat, ProposalId, EncodedCall>;
+	pub type Proposals<T: Config> =
+		CountedStorageMap<_, Blake2_128Concat, ProposalId, EncodedCall<T>>;
 
 	/// Stores the proposal IDs that are set to expire at a specific block number.
 	#[pallet::storage]
 	#[pallet::getter(fn expiry)]
-	pub type Expiry<T: Config> =
-		StorageMap<_, Blake2_128Concat, BlockNumberFor<T>, BTreeSet<ProposalId>, ValueQuery>;
+	pub type Expiry<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>,
+		BoundedBTreeSet<ProposalId, T::MaxProposals>,
+		ValueQuery,
+	>;
 
 	/// Stores the vote results of the proposal id.
 	#[pallet::storage]
 	#[pallet::getter(fn votes)]
 	pub type Votes<T: Config> =
-		StorageMap<_, Blake2_128Concat, ProposalId, CastedVotes<T::AccountId>, ValueQuery>;
+		StorageMap<_, Blake2_128Concat, ProposalId, CastedVotesOf<T>, ValueQuery>;
 
 	/// Stores the current council members.
 	#[pallet::storage]
 	#[pallet::getter(fn authorities)]
-	pub type CurrentAuthorities<T: Config> = StorageValue<_, BTreeSet<T::AccountId>, ValueQuery>;
+	pub type CurrentAuthorities<T: Config> =
+		StorageValue<_, BoundedBTreeSet<T::AccountId, T::MaxAuthorities>, ValueQuery>;
 
 	/// Proposals that can be resolved are stored here, until the next block's on_finalize,
 	/// where the the proposal is accepted (call dispatched) or rejected.
 	#[pallet::storage]
 	#[pallet::getter(fn resolve)]
 	pub type ProposalsToResolve<T: Config> =
-		StorageValue<_, BTreeSet<(ProposalId, bool)>, ValueQuery>;
+		StorageValue<_, BoundedBTreeSet<(ProposalId, bool), T::MaxProposals>, ValueQuery>;
 
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
@@ -208,7 +255,8 @@ pub mod module {
 	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
 		fn build(&self) {
 			// Set CurrentAuthorities storage with initial_authorities.
-			Pallet::<T>::do_rotate_authorities(self.initial_authorities.clone());
+			Pallet::<T>::do_rotate_authorities(self.initial_authorities.clone())
+				.expect("The initial authorities should not be more than MaxAuthorities.");
 		}
 	}
 
@@ -221,8 +269,10 @@ pub mod module {
 		GovernanceApproval,
 	}
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
-	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -236,7 +286,7 @@ pub mod module {
 			ProposalsToResolve::<T>::take().into_iter().for_each(|(proposal, approved)| {
 				// Decode the proposal call if it exists.
 				if let Some(Ok(call)) = Proposals::<T>::take(proposal)
-					.map(|encoded| <T as Config>::RuntimeCall::decode(&mut &(*encoded)))
+					.map(|encoded| <T as Config>::RuntimeCall::decode(&mut &encoded[..]))
 				{
 					// if approved that dispatch the governance call and record the result.
 					if approved {
@@ -287,19 +337,22 @@ pub mod module {
 		) -> DispatchResult {
 			let id = ensure_signed(origin)?;
 			ensure!(CurrentAuthorities::<T>::get().contains(&id), Error::<T>::Unauthorized);
+			ensure!(Proposals::<T>::count() < T::MaxProposals::get(), Error::<T>::TooManyProposals);
+			let encoded_call: EncodedCall<T> =
+				call.encode().try_into().map_err(|_| Error::<T>::ProposalTooLong)?;
 			let proposal_id = Self::next_proposal_id();
 			let expired_block =
 				frame_system::Pallet::<T>::current_block_number() + T::ExpiryPeriod::get();
 
 			// add call to storage
-			Proposals::<T>::insert(proposal_id, call.encode());
+			Proposals::<T>::insert(proposal_id, encoded_call);
 
 			// add proposal id to expiry.
 			// Get the BTreeSet for the block number and add the proposal id into the set.
-			Expiry::<T>::mutate(expired_block, |expiry_set| {
+			Expiry::<T>::try_mutate(expired_block, |expiry_set| {
 				// Insert the proposal id into the set
-				expiry_set.insert(proposal_id);
-			});
+				expiry_set.try_insert(proposal_id).map_err(|_| Error::<T>::TooManyProposals)
+			})?;
 
 			// add vote to Votes.
 			let _ = Self::do_vote(id.clone(), proposal_id, true);
@@ -340,8 +393,7 @@ pub mod module {
 		) -> DispatchResult {
 			// ensure root
 			ensure_root(origin)?;
-			Self::do_rotate_authorities(new_members);
-			Ok(())
+			Self::do_rotate_authorities(new_members)
 		}
 
 		/// Requires Governance origin - sets the current authorities
@@ -354,8 +406,7 @@ pub mod module {
 			// ensure governance
 			T::EnsureGovernance::ensure_origin(origin)?;
 
-			Self::do_rotate_authorities(new_members);
-			Ok(())
+			Self::do_rotate_authorities(new_members)
 		}
 	}
 
@@ -410,13 +461,17 @@ pub mod module {
 		/// Orchestrates the rotation of authorities by setting new authorities.
 		/// Resetting expiry blocks, clearing votes for current proposals, and emitting an event to
 		/// notify interested parties about the authority rotation and the new council members.
-		fn do_rotate_authorities(authorities: Vec<T::AccountId>) {
-			// Identify old members
-			let old_members = CurrentAuthorities::<T>::take();
-
+		fn do_rotate_authorities(authorities: Vec<T::AccountId>) -> DispatchResult {
 			// Sets the new authority
 			let unique_members: BTreeSet<_> = authorities.into_iter().collect();
-			CurrentAuthorities::<T>::set(unique_members.clone());
+			let bounded_members = BoundedBTreeSet::<T::AccountId, T::MaxAuthorities>::try_from(
+				unique_members.clone(),
+			)
+			.map_err(|_| Error::<T>::TooManyAuthorities)?;
+
+			// Identify old members
+			let old_members = CurrentAuthorities::<T>::get();
+			CurrentAuthorities::<T>::set(bounded_members);
 
 			// Identify members to retain votes for
 			let members_to_retain: BTreeSet<_> =
@@ -437,17 +492,22 @@ pub mod module {
 			// Merge the current expiry set with the all_proposals set and clean up the Expiry
 			// storage.
 			let all_proposals =
-				Expiry::<T>::drain().fold(BTreeSet::new(), |mut init, (_, mut expiry_set)| {
-					init.append(&mut expiry_set);
+				Expiry::<T>::drain().fold(BTreeSet::new(), |mut init, (_, expiry_set)| {
+					init.append(&mut expiry_set.into_inner());
 					init
 				});
 			let expired_block = frame_system::Pallet::<T>::current_block_number()
 				.saturating_add(T::ExpiryPeriod::get());
 
-			Expiry::<T>::insert(expired_block, all_proposals);
+			// There are no more proposals than `MaxProposals`, so they fit in a single block.
+			Expiry::<T>::insert(
+				expired_block,
+				BoundedBTreeSet::try_from(all_proposals).unwrap_or_default(),
+			);
 
 			// Emit event: AuthorityRotated
 			Self::deposit_event(Event::<T>::AuthorityRotated { new_council: unique_members });
+			Ok(())
 		}
 
 		fn do_vote(id: T::AccountId, proposal: ProposalId, approve: bool) -> DispatchResult {
@@ -460,9 +520,11 @@ pub mod module {
 					CurrentAuthorities::<T>::get().len() as u32,
 					T::MajorityThreshold::get(),
 				) {
-					ProposalsToResolve::<T>::mutate(|proposals| {
-						proposals.insert((proposal, bool));
-					});
+					ProposalsToResolve::<T>::try_mutate(|proposals| {
+						proposals
+							.try_insert((proposal, bool))
+							.map_err(|_| Error::<T>::TooManyProposals)
+					})?;
 				}
 
 				// Emit Event: VoteCasted
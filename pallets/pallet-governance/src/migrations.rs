@@ -0,0 +1,55 @@
+//! Storage migrations of the governance pallet.
+
+use super::*;
+use frame_support::traits::OnRuntimeUpgrade;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+pub mod v1 {
+	use super::*;
+
+	/// Migrates the storage from version 0 to 1.
+	///
+	/// The storage is bounded in version 1, and `Proposals` is a counted map. The encoding of the
+	/// bounded items is unchanged, so only the counter of `Proposals` is initialised.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() != 0 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let proposals = Proposals::<T>::initialize_counter();
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(proposals as u64 + 1, 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let authorities: BTreeSet<T::AccountId> =
+				frame_support::storage::unhashed::get(&CurrentAuthorities::<T>::hashed_key())
+					.unwrap_or_default();
+			ensure!(
+				authorities.len() as u32 <= T::MaxAuthorities::get(),
+				"There are more authorities than MaxAuthorities."
+			);
+			ensure!(
+				Proposals::<T>::iter_keys().count() as u32 <= T::MaxProposals::get(),
+				"There are more proposals than MaxProposals."
+			);
+			Ok(Vec::new())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(StorageVersion::get::<Pallet<T>>() == 1, "The storage version is not 1.");
+			ensure!(
+				Proposals::<T>::count() as usize == Proposals::<T>::iter_keys().count(),
+				"The proposals are not counted."
+			);
+			Ok(())
+		}
+	}
+}
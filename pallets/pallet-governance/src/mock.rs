@@ -58,6 +58,9 @@ impl Config for Runtime {
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeCall = RuntimeCall;
 	type EnsureGovernance = crate::EnsureGovernance;
+	type MaxAuthorities = ConstU32<100>;
+	type MaxProposals = ConstU32<100>;
+	type MaxProposalLength = ConstU32<1_024>;
 }
 
 construct_runtime!(
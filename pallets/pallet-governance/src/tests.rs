@@ -4,6 +4,10 @@ use crate::{mock::*, *};
 
 use frame_support::{assert_noop, assert_ok};
 
+fn encoded_call(call: &RuntimeCall) -> BoundedVec<u8, <Runtime as Config>::MaxProposalLength> {
+	call.encode().try_into().unwrap()
+}
+
 fn assert_storage_cleaned_up(first_proposal: ProposalId) {
 	assert!(!Votes::<Runtime>::contains_key(first_proposal),);
 	assert_eq!(ProposalsToResolve::<Runtime>::get(), Default::default());
@@ -33,7 +37,7 @@ fn can_initiate_proposal() {
 			));
 
 			// Verify
-			assert_eq!(Proposals::<Runtime>::get(first_proposal), Some(call.encode()));
+			assert_eq!(Proposals::<Runtime>::get(first_proposal), Some(encoded_call(&call)));
 
 			assert_eq!(
 				Expiry::<Runtime>::get(first_block + EXPIRY_PERIOD),
@@ -43,7 +47,7 @@ fn can_initiate_proposal() {
 			assert_eq!(
 				Votes::<Runtime>::get(first_proposal),
 				CastedVotes {
-					yays: BTreeSet::from_iter(vec![authority_member]),
+					yays: BTreeSet::from_iter(vec![authority_member]).try_into().unwrap(),
 					nays: Default::default()
 				}
 			);
@@ -94,7 +98,7 @@ fn can_vote() {
 			let authority_member_3: AccountId = 11;
 
 			let first_proposal = 1u32;
-			Proposals::<Runtime>::set(first_proposal, Some(call.encode()));
+			Proposals::<Runtime>::insert(first_proposal, encoded_call(&call));
 
 			// The MajorityThreshold is 80%, in this case,
 			// which needs 9 people vote pass or 2 people vote reject to resolve the proposal.
@@ -102,7 +106,10 @@ fn can_vote() {
 			// reject would not resolve the proposal until the number reached 9 pass or 2 reject.
 			Votes::<Runtime>::set(
 				first_proposal,
-				CastedVotes { yays: (14..21).collect::<BTreeSet<_>>(), nays: Default::default() },
+				CastedVotes {
+					yays: (14..21).collect::<BTreeSet<_>>().try_into().unwrap(),
+					nays: Default::default(),
+				},
 			);
 
 			// Test the function vote works.
@@ -115,7 +122,10 @@ fn can_vote() {
 			// Verify
 			assert_eq!(
 				Votes::<Runtime>::get(first_proposal),
-				CastedVotes { yays: (13..21).collect::<BTreeSet<_>>(), nays: Default::default() }
+				CastedVotes {
+					yays: (13..21).collect::<BTreeSet<_>>().try_into().unwrap(),
+					nays: Default::default()
+				}
 			);
 			assert_eq!(ProposalsToResolve::<Runtime>::get(), Default::default());
 
@@ -135,7 +145,10 @@ fn can_vote() {
 			// Verify
 			assert_eq!(
 				Votes::<Runtime>::get(first_proposal),
-				CastedVotes { yays: (12..21).collect::<BTreeSet<_>>(), nays: Default::default() }
+				CastedVotes {
+					yays: (12..21).collect::<BTreeSet<_>>().try_into().unwrap(),
+					nays: Default::default()
+				}
 			);
 			assert_eq!(
 				ProposalsToResolve::<Runtime>::get(),
@@ -159,8 +172,8 @@ fn can_vote() {
 			assert_eq!(
 				Votes::<Runtime>::get(first_proposal),
 				CastedVotes {
-					yays: (12..21).collect::<BTreeSet<_>>(),
-					nays: BTreeSet::from_iter(vec![11])
+					yays: (12..21).collect::<BTreeSet<_>>().try_into().unwrap(),
+					nays: BTreeSet::from_iter(vec![11]).try_into().unwrap()
 				}
 			);
 			assert_eq!(
@@ -188,7 +201,7 @@ fn cannot_vote_with_invalid_authority() {
 			}));
 			let invaild_authority_member: AccountId = 10;
 			let first_proposal = 1u32;
-			Proposals::<Runtime>::set(first_proposal, Some(call.encode()));
+			Proposals::<Runtime>::insert(first_proposal, encoded_call(&call));
 
 			// Ensures that an unauthorized member cannot vote on a proposal.
 			assert_noop!(
@@ -215,7 +228,7 @@ fn cannot_vote_with_invalid_proposal() {
 			let authority_member: AccountId = 11;
 			let first_proposal = 1u32;
 			let invaild_proposal = 2u32;
-			Proposals::<Runtime>::set(first_proposal, Some(call.encode()));
+			Proposals::<Runtime>::insert(first_proposal, encoded_call(&call));
 
 			// Ensures that an unauthorized member cannot vote on a proposal.
 			assert_noop!(
@@ -238,11 +251,14 @@ fn cannot_vote_twice() {
 			let has_voted_pass_member: AccountId = 11;
 			let has_voted_reject_member: AccountId = 16;
 			let first_proposal = 1u32;
-			Proposals::<Runtime>::set(first_proposal, Some(call.encode()));
+			Proposals::<Runtime>::insert(first_proposal, encoded_call(&call));
 
 			Votes::<Runtime>::set(
 				first_proposal,
-				CastedVotes { yays: (11..15).collect::<BTreeSet<_>>(), nays: BTreeSet::from([16]) },
+				CastedVotes {
+					yays: (11..15).collect::<BTreeSet<_>>().try_into().unwrap(),
+					nays: BTreeSet::from([16]).try_into().unwrap(),
+				},
 			);
 
 			// Ensures that the authorized member cannot vote twice on one proposal.
@@ -278,10 +294,12 @@ fn cannot_vote_after_proposal_resolved() {
 			}));
 			let authority_member: AccountId = 21;
 			let first_proposal = 1u32;
-			Proposals::<Runtime>::set(first_proposal, Some(call.encode()));
+			Proposals::<Runtime>::insert(first_proposal, encoded_call(&call));
 
 			// Case1: proposal passed
-			ProposalsToResolve::<Runtime>::set(BTreeSet::from([(first_proposal, true)]));
+			ProposalsToResolve::<Runtime>::set(
+				BTreeSet::from([(first_proposal, true)]).try_into().unwrap(),
+			);
 
 			// dispatch
 			Governance::on_finalize(System::block_number());
@@ -293,7 +311,9 @@ fn cannot_vote_after_proposal_resolved() {
 			);
 
 			// Case2: proposal rejected
-			ProposalsToResolve::<Runtime>::set(BTreeSet::from([(first_proposal, false)]));
+			ProposalsToResolve::<Runtime>::set(
+				BTreeSet::from([(first_proposal, false)]).try_into().unwrap(),
+			);
 
 			// dispatch
 			Governance::on_finalize(System::block_number());
@@ -305,7 +325,10 @@ fn cannot_vote_after_proposal_resolved() {
 			);
 
 			// Case3: proposal expired
-			Expiry::<Runtime>::set(System::block_number(), BTreeSet::from([first_proposal]));
+			Expiry::<Runtime>::set(
+				System::block_number(),
+				BTreeSet::from([first_proposal]).try_into().unwrap(),
+			);
 
 			// dispatch
 			Governance::on_finalize(System::block_number());
@@ -532,7 +555,10 @@ fn rotate_authorities_can_retain_votes() {
 			// Verify the votes.
 			assert_eq!(
 				Votes::<Runtime>::get(first_proposal),
-				CastedVotes { yays: (11..19).collect::<BTreeSet<_>>(), nays: BTreeSet::from([19]) }
+				CastedVotes {
+					yays: (11..19).collect::<BTreeSet<_>>().try_into().unwrap(),
+					nays: BTreeSet::from([19]).try_into().unwrap()
+				}
 			);
 
 			// Force rotate authorities which contains some old authority members
@@ -547,7 +573,10 @@ fn rotate_authorities_can_retain_votes() {
 			// The votes retained by who is still the new council member.
 			assert_eq!(
 				Votes::<Runtime>::get(first_proposal),
-				CastedVotes { yays: (15..19).collect::<BTreeSet<_>>(), nays: BTreeSet::from([19]) }
+				CastedVotes {
+					yays: (15..19).collect::<BTreeSet<_>>().try_into().unwrap(),
+					nays: BTreeSet::from([19]).try_into().unwrap()
+				}
 			);
 
 			// Vote 5 tickets passed which includes 19-24 from new council.
@@ -577,3 +606,40 @@ fn rotate_authorities_can_retain_votes() {
 			}) if id == first_proposal && call == call );
 		});
 }
+
+#[test]
+fn cannot_exceed_governance_bounds() {
+	MockGenesisConfig::default()
+		.with_authorities((11..21).collect::<Vec<_>>())
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Governance::force_rotate_authorities(
+					RuntimeOrigin::root(),
+					(100..201).collect::<Vec<_>>()
+				),
+				Error::<Runtime>::TooManyAuthorities
+			);
+
+			let long_call =
+				Box::new(RuntimeCall::Governance(crate::Call::council_rotate_authorities {
+					new_members: (0..300).collect::<Vec<_>>(),
+				}));
+			assert_noop!(
+				Governance::initiate_proposal(RuntimeOrigin::signed(11), long_call),
+				Error::<Runtime>::ProposalTooLong
+			);
+
+			let call = Box::new(RuntimeCall::Governance(crate::Call::council_rotate_authorities {
+				new_members: (21..31).collect::<Vec<_>>(),
+			}));
+			for _ in 0..100 {
+				assert_ok!(Governance::initiate_proposal(RuntimeOrigin::signed(11), call.clone()));
+			}
+			assert_eq!(Proposals::<Runtime>::count(), 100);
+			assert_noop!(
+				Governance::initiate_proposal(RuntimeOrigin::signed(11), call),
+				Error::<Runtime>::TooManyProposals
+			);
+		});
+}
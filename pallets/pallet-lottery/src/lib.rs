@@ -13,8 +13,8 @@ use sp_runtime::{
 };
 use sp_std::{fmt::Debug, marker::PhantomData, prelude::*, vec::Vec};
 
-use primitives::Role;
-use traits::{BasicAccounting, GetTreasury, ManageRoles};
+use primitives::{IncomeSource, Role, TransferReason};
+use traits::{BasicAccounting, GetTreasury, ManageRoles, RecordTreasuryIncome};
 
 mod mock;
 mod tests;
@@ -57,6 +57,9 @@ pub mod module {
 
 		type EnsureGovernance: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
 
+		/// Records the income paid into the treasury account.
+		type TreasuryIncome: RecordTreasuryIncome<Self::Balance>;
+
 		type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
 
 		#[pallet::constant]
@@ -67,6 +70,10 @@ pub mod module {
 
 		#[pallet::constant]
 		type TaxRate: Get<Percent>;
+
+		/// The maximum number of winners, that is the length of the prize split.
+		#[pallet::constant]
+		type MaxWinners: Get<u32>;
 	}
 
 	#[pallet::error]
@@ -77,6 +84,8 @@ pub mod module {
 		TicketPriceNotSet,
 		/// Tax rate must be between 0% - 100%.
 		InvalidTaxRate,
+		/// The prize split has more winners than allowed.
+		TooManyWinners,
 	}
 
 	#[pallet::event]
@@ -94,7 +103,8 @@ pub mod module {
 
 	#[pallet::storage]
 	#[pallet::getter(fn prize_split)]
-	pub type PrizeSplit<T: Config> = StorageValue<_, Vec<Percent>, ValueQuery>;
+	pub type PrizeSplit<T: Config> =
+		StorageValue<_, BoundedVec<Percent, T::MaxWinners>, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn start_block)]
@@ -122,12 +132,14 @@ pub mod module {
 	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
 		fn build(&self) {
 			// Set PrizeSplit storage with Percent::one()
-			PrizeSplit::<T>::put(vec![Percent::one()]);
+			PrizeSplit::<T>::put(BoundedVec::truncate_from(vec![Percent::one()]));
 		}
 	}
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
 	#[pallet::pallet]
-	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -158,7 +170,9 @@ pub mod module {
 			ensure!(Self::check_split_valid(&prize_split), Error::<T>::InvalidPrizeSplitTotal);
 
 			// Set the PrizeSplit storage
-			PrizeSplit::<T>::put(prize_split.clone());
+			let bounded_split = BoundedVec::<Percent, T::MaxWinners>::try_from(prize_split.clone())
+				.map_err(|_| Error::<T>::TooManyWinners)?;
+			PrizeSplit::<T>::put(bounded_split);
 
 			Self::deposit_event(Event::<T>::PrizeSplitUpdated { split: prize_split });
 			Ok(())
@@ -197,7 +211,12 @@ pub mod module {
 			let total_price = TicketPrice::<T>::get().saturating_mul(number_of_tickets.into());
 
 			// Transfer total_price from the customer to the PrizePoolAccount.
-			T::Bank::transfer(&id, &T::PrizePoolAccount::get(), total_price)?;
+			T::Bank::transfer_with_reason(
+				&id,
+				&T::PrizePoolAccount::get(),
+				total_price,
+				TransferReason::LotteryTicket,
+			)?;
 
 			TicketsBought::<T>::mutate(id.clone(), |tickets| {
 				*tickets = tickets.saturating_add(number_of_tickets)
@@ -271,8 +290,22 @@ pub mod module {
 
 			for (i, user) in winners.into_iter().enumerate() {
 				// Payout the prize to each winner and put the tax into treasury account.
-				let _ = T::Bank::transfer(&T::PrizePoolAccount::get(), &user, prize[i]);
-				let _ = T::Bank::transfer(&T::PrizePoolAccount::get(), &treasury, tax[i]);
+				let _ = T::Bank::transfer_with_reason(
+					&T::PrizePoolAccount::get(),
+					&user,
+					prize[i],
+					TransferReason::LotteryPrize,
+				);
+				if T::Bank::transfer_with_reason(
+					&T::PrizePoolAccount::get(),
+					&treasury,
+					tax[i],
+					TransferReason::LotteryTax,
+				)
+				.is_ok()
+				{
+					T::TreasuryIncome::record_income(IncomeSource::LotteryTax, tax[i]);
+				}
 
 				// Added an event for each winner.
 				Self::deposit_event(Event::<T>::LotteryWon {
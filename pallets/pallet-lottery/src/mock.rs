@@ -7,7 +7,7 @@ use frame_support::{
 };
 
 use primitives::{Balance, DOLLAR, YEAR};
-use sp_runtime::{testing::H256, traits::IdentityLookup, BuildStorage};
+use sp_runtime::{testing::H256, traits::IdentityLookup, BuildStorage, Perbill};
 
 use crate as pallet_lottery;
 
@@ -88,10 +88,12 @@ impl Config for Runtime {
 	type RoleManager = Roles;
 	type EnsureGovernance = traits::SuccessOrigin<Runtime>;
 	type Bank = Bank;
+	type TreasuryIncome = ();
 	type Randomness = MockRandom;
 	type LotteryPayoutPeriod = LotteryPayoutPeriod;
 	type PrizePoolAccount = PrizePoolAccount;
 	type TaxRate = TaxRate;
+	type MaxWinners = ConstU32<10>;
 }
 
 parameter_types! {
@@ -101,6 +103,13 @@ parameter_types! {
 	pub const StakePeriod: BlockNumber = STAKE_PERIOD;
 	pub const InterestPayoutPeriod: BlockNumber = INTEREST_PAYOUT_PERIOD;
 	pub const TotalBlocksPerYear: BlockNumber = YEAR as BlockNumber;
+	pub const InterestRateNoticePeriod: BlockNumber = 10;
+	pub const WithdrawalRequestPeriod: BlockNumber = 10;
+	pub const JointTransferPeriod: BlockNumber = 10;
+	pub const BlocksPerDay: BlockNumber = 10;
+	pub const BlocksPerWeek: BlockNumber = 70;
+	pub const MaxLoanToValue: Perbill = Perbill::from_percent(50);
+	pub const LiquidationLoanToValue: Perbill = Perbill::from_percent(80);
 }
 
 impl pallet_bank::Config for Runtime {
@@ -109,12 +118,30 @@ impl pallet_bank::Config for Runtime {
 	type Balance = Balance;
 	type RoleManager = Roles;
 	type EnsureGovernance = traits::SuccessOrigin<Runtime>;
+	type TreasuryIncome = ();
+	type RuntimeHoldReason = ();
 	type ExistentialDeposit = ExistentialDeposit;
 	type MinimumAmount = MinimumAmount;
 	type RedeemPeriod = RedeemPeriod;
 	type StakePeriod = StakePeriod;
 	type InterestPayoutPeriod = InterestPayoutPeriod;
 	type TotalBlocksPerYear = TotalBlocksPerYear;
+	type MaxInterestPayoutsPerBlock = ConstU32<100>;
+	type InterestRateNoticePeriod = InterestRateNoticePeriod;
+	type WithdrawalRequestPeriod = WithdrawalRequestPeriod;
+	type JointTransferPeriod = JointTransferPeriod;
+	type BlocksPerDay = BlocksPerDay;
+	type BlocksPerWeek = BlocksPerWeek;
+	type MaxLoanToValue = MaxLoanToValue;
+	type LiquidationLoanToValue = LiquidationLoanToValue;
+	type MaxReapsPerBlock = ConstU32<2>;
+	type MaxStandingOrderRetries = ConstU32<2>;
+	type MaxLedgerEntries = ConstU32<5>;
+	type MaxJointHolders = ConstU32<3>;
+	type MaxBatchTransfers = ConstU32<3>;
+	type MaxLocks = ConstU32<10>;
+	type MaxScheduledPerBlock = ConstU32<10>;
+	type MaxSponsoredCalls = ConstU32<10>;
 }
 
 impl pallet_roles::Config for Runtime {
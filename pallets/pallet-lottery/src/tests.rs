@@ -52,6 +52,10 @@ fn cannot_set_prize_split_total_not_equal_to_one() {
 			Lottery::set_prize_split(RuntimeOrigin::root(), split_less_than_one),
 			Error::<Runtime>::InvalidPrizeSplitTotal
 		);
+		assert_noop!(
+			Lottery::set_prize_split(RuntimeOrigin::root(), vec![Percent::from_percent(5); 20]),
+			Error::<Runtime>::TooManyWinners
+		);
 	});
 }
 
@@ -4,7 +4,7 @@
 use super::*;
 
 use frame_benchmarking::v2::*;
-use frame_support::{assert_ok, traits::UnfilteredDispatchable};
+use frame_support::{assert_ok, bounded_vec, traits::UnfilteredDispatchable};
 use frame_system::RawOrigin;
 use primitives::DOLLAR;
 use sp_runtime::traits::BlockNumberProvider;
@@ -33,8 +33,8 @@ mod benchmarks {
 		Nfts::<T>::insert(
 			1u32,
 			NftData {
-				data: vec![0x4E, 0x46, 0x54],
-				file_name: vec![0x46, 0x49, 0x4C, 0x45],
+				data: bounded_vec![0x4E, 0x46, 0x54],
+				file_name: bounded_vec![0x46, 0x49, 0x4C, 0x45],
 				state: NftState::Free,
 			},
 		);
@@ -58,8 +58,8 @@ mod benchmarks {
 		Nfts::<T>::insert(
 			1u32,
 			NftData {
-				data: vec![0x4E, 0x46, 0x54],
-				file_name: vec![0x46, 0x49, 0x4C, 0x45],
+				data: bounded_vec![0x4E, 0x46, 0x54],
+				file_name: bounded_vec![0x46, 0x49, 0x4C, 0x45],
 				state: NftState::Free,
 			},
 		);
@@ -83,8 +83,8 @@ mod benchmarks {
 			1u32,
 			(
 				NftData {
-					data: vec![0x4E, 0x46, 0x54],
-					file_name: vec![0x46, 0x49, 0x4C, 0x45],
+					data: bounded_vec![0x4E, 0x46, 0x54],
+					file_name: bounded_vec![0x46, 0x49, 0x4C, 0x45],
 					state: NftState::Free,
 				},
 				owner.clone(),
@@ -106,8 +106,8 @@ mod benchmarks {
 		Nfts::<T>::insert(
 			1u32,
 			NftData {
-				data: vec![0x4E, 0x46, 0x54],
-				file_name: vec![0x46, 0x49, 0x4C, 0x45],
+				data: bounded_vec![0x4E, 0x46, 0x54],
+				file_name: bounded_vec![0x46, 0x49, 0x4C, 0x45],
 				state: NftState::Free,
 			},
 		);
@@ -138,8 +138,8 @@ mod benchmarks {
 		Nfts::<T>::insert(
 			nft_id_1,
 			NftData {
-				data: vec![0x4E, 0x46, 0x54],
-				file_name: vec![0x46, 0x49, 0x4C, 0x45],
+				data: bounded_vec![0x4E, 0x46, 0x54],
+				file_name: bounded_vec![0x46, 0x49, 0x4C, 0x45],
 				state: NftState::POD(pod_id_1),
 			},
 		);
@@ -159,8 +159,8 @@ mod benchmarks {
 		Nfts::<T>::insert(
 			nft_id_2,
 			NftData {
-				data: vec![0x4E, 0x46, 0x54],
-				file_name: vec![0x46, 0x49, 0x4C, 0x45],
+				data: bounded_vec![0x4E, 0x46, 0x54],
+				file_name: bounded_vec![0x46, 0x49, 0x4C, 0x45],
 				state: NftState::Free,
 			},
 		);
@@ -224,8 +224,8 @@ mod benchmarks {
 		assert_eq!(
 			Nfts::<T>::get(1),
 			Some(NftData {
-				data: vec![0x4E, 0x46, 0x54],
-				file_name: vec![0x46, 0x49, 0x4C, 0x45],
+				data: bounded_vec![0x4E, 0x46, 0x54],
+				file_name: bounded_vec![0x46, 0x49, 0x4C, 0x45],
 				state: NftState::Free
 			})
 		);
@@ -17,8 +17,12 @@ use sp_runtime::{
 };
 use sp_std::{fmt::Debug, prelude::*, vec::Vec};
 
-use primitives::{NftId, NftState, PodId, Response, Role, FILENAME_MAXSIZE};
-use traits::{BasicAccounting, GetTreasury, ManageAuctions, ManageNfts, ManageRoles};
+use primitives::{
+	IncomeSource, NftId, NftState, PodId, Response, Role, TransferReason, FILENAME_MAXSIZE,
+};
+use traits::{
+	BasicAccounting, GetTreasury, ManageAuctions, ManageNfts, ManageRoles, RecordTreasuryIncome,
+};
 
 mod mock;
 mod tests;
@@ -30,11 +34,23 @@ pub use weights::*;
 mod benchmarking;
 
 /// Represents NFT data including its raw data and file name.
-#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	MaxEncodedLen,
+	RuntimeDebugNoBound,
+	TypeInfo,
+)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-pub struct NftData {
-	pub data: Vec<u8>,
-	pub file_name: Vec<u8>,
+#[cfg_attr(feature = "std", serde(bound = ""))]
+#[codec(mel_bound())]
+#[scale_info(skip_type_params(MaxSize))]
+pub struct NftData<MaxSize: Get<u32>> {
+	pub data: BoundedVec<u8, MaxSize>,
+	pub file_name: BoundedVec<u8, ConstU32<FILENAME_MAXSIZE>>,
 	pub state: NftState,
 }
 
@@ -61,6 +77,8 @@ pub mod module {
 
 	use super::*;
 
+	pub type NftDataOf<T> = NftData<<T as Config>::MaxSize>;
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -87,6 +105,9 @@ pub mod module {
 
 		type EnsureGovernance: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
 
+		/// Records the income paid into the treasury account.
+		type TreasuryIncome: RecordTreasuryIncome<Self::Balance>;
+
 		#[pallet::constant]
 		type MaxSize: Get<u32>;
 
@@ -95,6 +116,10 @@ pub mod module {
 
 		#[pallet::constant]
 		type NftLockedPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of NFTs on POD that expire at a block.
+		#[pallet::constant]
+		type MaxPodsPerBlock: Get<u32>;
 	}
 
 	#[pallet::error]
@@ -116,6 +141,8 @@ pub mod module {
 		NftStateNotMatch,
 		/// The nft state is not Free.
 		NftStateNotFree,
+		/// Too many NFTs on POD expire at the same block.
+		TooManyPodsExpiring,
 	}
 
 	#[pallet::event]
@@ -159,14 +186,14 @@ pub mod module {
 	#[pallet::storage]
 	#[pallet::getter(fn pending_nft)]
 	pub type PendingNft<T: Config> =
-		StorageMap<_, Blake2_128Concat, NftId, (NftData, T::AccountId)>;
+		StorageMap<_, Blake2_128Concat, NftId, (NftDataOf<T>, T::AccountId)>;
 	#[pallet::storage]
 	#[pallet::getter(fn owners)]
 	pub type Owners<T: Config> = StorageMap<_, Blake2_128Concat, NftId, T::AccountId>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn nfts)]
-	pub type Nfts<T: Config> = StorageMap<_, Blake2_128Concat, NftId, NftData>;
+	pub type Nfts<T: Config> = StorageMap<_, Blake2_128Concat, NftId, NftDataOf<T>>;
 
 	/// Stores the pricing information for NFTs awaiting trade.
 	#[pallet::storage]
@@ -175,11 +202,18 @@ pub mod module {
 
 	/// Stores the users' nft ids that expiry for POD pending delivery at a block.
 	#[pallet::storage]
-	pub type PodExpiry<T: Config> =
-		StorageMap<_, Blake2_128Concat, BlockNumberFor<T>, Vec<(PodId, NftId)>, ValueQuery>;
+	pub type PodExpiry<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(PodId, NftId), T::MaxPodsPerBlock>,
+		ValueQuery,
+	>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
 
 	#[pallet::pallet]
-	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -191,6 +225,11 @@ pub mod module {
 				let _ = Self::cancel_nft_pod(pod_id, nft_id, CancelReason::Expired);
 			});
 		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_block_number: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
 	}
 
 	#[pallet::call]
@@ -211,15 +250,17 @@ pub mod module {
 			T::RoleManager::ensure_not_role(&id, Role::Auditor)?;
 
 			// Checks if the size of the NFT data is within the allowed maximum limit.
-			ensure!(data.len() as u32 <= T::MaxSize::get(), Error::<T>::DataTooLarge);
-			ensure!(file_name.len() as u32 <= FILENAME_MAXSIZE, Error::<T>::FileNameTooLarge);
+			let data: BoundedVec<u8, T::MaxSize> =
+				data.try_into().map_err(|_| Error::<T>::DataTooLarge)?;
+			let bounded_file_name: BoundedVec<u8, ConstU32<FILENAME_MAXSIZE>> =
+				file_name.clone().try_into().map_err(|_| Error::<T>::FileNameTooLarge)?;
 
 			// Set the Nfts storage
 			let nft_id = Self::next_nft_id();
 
 			PendingNft::<T>::insert(
 				nft_id,
-				(NftData { data, file_name: file_name.clone(), state: NftState::Free }, id.clone()),
+				(NftData { data, file_name: bounded_file_name, state: NftState::Free }, id.clone()),
 			);
 
 			Self::deposit_event(Event::<T>::NFTPending { nft_id, file_name });
@@ -357,7 +398,8 @@ pub mod module {
 			// Added the block number that the nft processing will be expired.
 			let expired_at =
 				frame_system::Pallet::<T>::current_block_number() + T::NftLockedPeriod::get();
-			PodExpiry::<T>::append(expired_at, (pod_id, nft_id));
+			PodExpiry::<T>::try_append(expired_at, (pod_id, nft_id))
+				.map_err(|_| Error::<T>::TooManyPodsExpiring)?;
 
 			// Add the price and target user to the storage.
 			PendingPodNfts::<T>::insert(
@@ -372,7 +414,13 @@ pub mod module {
 
 			// Managers do not pay fee.
 			if T::RoleManager::role(&id) == Some(Role::Customer) {
-				T::Bank::transfer(&id, &treasury, T::PodFee::get())?;
+				T::Bank::transfer_with_reason(
+					&id,
+					&treasury,
+					T::PodFee::get(),
+					TransferReason::PodFee,
+				)?;
+				T::TreasuryIncome::record_income(IncomeSource::PodFee, T::PodFee::get());
 			}
 
 			Self::deposit_event(Event::<T>::NftPodCreated {
@@ -410,12 +458,20 @@ pub mod module {
 				let seller = Self::nft_transfer(pod_info.nft_id, &buyer)?;
 
 				// Pay fee to Treasury account.
-				let final_seller = match T::RoleManager::role(&seller) {
-					Some(Role::Manager) => T::Bank::treasury()?,
-					_ => seller,
+				let (final_seller, is_treasury) = match T::RoleManager::role(&seller) {
+					Some(Role::Manager) => (T::Bank::treasury()?, true),
+					_ => (seller, false),
 				};
 
-				T::Bank::transfer(&buyer, &final_seller, final_amount)?;
+				T::Bank::transfer_with_reason(
+					&buyer,
+					&final_seller,
+					final_amount,
+					TransferReason::PodPayment,
+				)?;
+				if is_treasury {
+					T::TreasuryIncome::record_income(IncomeSource::PodFee, final_amount);
+				}
 
 				Self::deposit_event(Event::<T>::NftDelivered {
 					seller: final_seller,
@@ -480,6 +536,25 @@ pub mod module {
 
 			Ok(())
 		}
+
+		/// Checks that every NFT in POD or auction has a matching POD or auction.
+		#[cfg(any(feature = "try-runtime", test))]
+		pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+			for (nft_id, nft) in Nfts::<T>::iter() {
+				match nft.state {
+					NftState::POD(pod_id) => ensure!(
+						PendingPodNfts::<T>::get(pod_id).map(|pod| pod.nft_id) == Some(nft_id),
+						"An NFT in POD has no matching POD."
+					),
+					NftState::Auction(auction_id) => ensure!(
+						T::AuctionManager::auction_nft(auction_id) == Some(nft_id),
+						"An NFT in auction has no matching auction."
+					),
+					NftState::Free => {},
+				}
+			}
+			Ok(())
+		}
 	}
 
 	impl<T: Config> ManageNfts<T::AccountId> for Pallet<T> {
@@ -536,7 +611,14 @@ pub mod module {
 
 		#[cfg(feature = "runtime-benchmarks")]
 		fn insert_nft(nft_id: NftId, owner: T::AccountId, file_name: Vec<u8>, data: Vec<u8>) {
-			Nfts::<T>::insert(nft_id, NftData { data, file_name, state: NftState::Free });
+			Nfts::<T>::insert(
+				nft_id,
+				NftData {
+					data: BoundedVec::truncate_from(data),
+					file_name: BoundedVec::truncate_from(file_name),
+					state: NftState::Free,
+				},
+			);
 			Owners::<T>::insert(nft_id, owner);
 		}
 	}
@@ -8,7 +8,7 @@ use frame_support::{
 
 use sp_runtime::{testing::H256, traits::IdentityLookup, BuildStorage};
 
-use primitives::{AuctionId, Balance};
+use primitives::{AuctionId, Balance, TransferReason};
 
 use crate as pallet_nft;
 
@@ -70,6 +70,22 @@ impl BasicAccounting<AccountId, Balance> for MockBank {
 		TransferHistory::set(history);
 		Ok(())
 	}
+	fn transfer_with_reason(
+		from: &AccountId,
+		to: &AccountId,
+		amount: Balance,
+		_reason: TransferReason,
+	) -> DispatchResult {
+		Self::transfer(from, to, amount)
+	}
+	fn transfer_from(
+		_spender: &AccountId,
+		_from: &AccountId,
+		_to: &AccountId,
+		_amount: Balance,
+	) -> DispatchResult {
+		unimplemented!();
+	}
 	fn free_balance(_user: &AccountId) -> Balance {
 		unimplemented!();
 	}
@@ -89,6 +105,10 @@ impl ManageAuctions<AccountId> for MockAuction {
 		AuctionCanceled::set(auction_id);
 		Ok(())
 	}
+
+	fn auction_nft(_auction_id: AuctionId) -> Option<NftId> {
+		None
+	}
 }
 
 parameter_types! {
@@ -106,9 +126,11 @@ impl Config for Runtime {
 	type Bank = MockBank;
 	type AuctionManager = MockAuction;
 	type EnsureGovernance = traits::SuccessOrigin<Runtime>;
+	type TreasuryIncome = ();
 	type MaxSize = MaxSize;
 	type PodFee = Fee;
 	type NftLockedPeriod = NftLockedPeriod;
+	type MaxPodsPerBlock = ConstU32<100>;
 }
 
 impl pallet_roles::Config for Runtime {
@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use crate::{mock::*, *};
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, bounded_vec};
 use primitives::{NftId, DOLLAR};
 
 /// Set up an Nft to each storage which is easier to test burn, transfer and audit functionality.
@@ -13,8 +13,8 @@ fn set_up_nfts() {
 	Nfts::<Runtime>::insert(
 		nft_id_1,
 		NftData {
-			data: vec![0x4E, 0x46, 0x54],
-			file_name: vec![0x46, 0x49, 0x4C, 0x45],
+			data: bounded_vec![0x4E, 0x46, 0x54],
+			file_name: bounded_vec![0x46, 0x49, 0x4C, 0x45],
 			state: NftState::Free,
 		},
 	);
@@ -23,8 +23,8 @@ fn set_up_nfts() {
 		nft_id_2,
 		(
 			NftData {
-				data: vec![0x4E, 0x46, 0x54],
-				file_name: vec![0x46, 0x49, 0x4C, 0x45],
+				data: bounded_vec![0x4E, 0x46, 0x54],
+				file_name: bounded_vec![0x46, 0x49, 0x4C, 0x45],
 				state: NftState::Free,
 			},
 			BOB,
@@ -34,8 +34,8 @@ fn set_up_nfts() {
 	Nfts::<Runtime>::insert(
 		nft_id_3,
 		NftData {
-			data: vec![0x4E, 0x46, 0x54],
-			file_name: vec![0x46, 0x49, 0x4C, 0x45],
+			data: bounded_vec![0x4E, 0x46, 0x54],
+			file_name: bounded_vec![0x46, 0x49, 0x4C, 0x45],
 			state: NftState::Auction(1u32),
 		},
 	);
@@ -257,16 +257,16 @@ fn test_ensure_nft_state_is_free() {
 		Nfts::<Runtime>::insert(
 			1u32,
 			NftData {
-				data: vec![0x4E, 0x46, 0x54],
-				file_name: vec![0x46, 0x49, 0x4C, 0x45],
+				data: bounded_vec![0x4E, 0x46, 0x54],
+				file_name: bounded_vec![0x46, 0x49, 0x4C, 0x45],
 				state: NftState::Free,
 			},
 		);
 		Nfts::<Runtime>::insert(
 			2u32,
 			NftData {
-				data: vec![0x4E, 0x46, 0x54],
-				file_name: vec![0x46, 0x49, 0x4C, 0x45],
+				data: bounded_vec![0x4E, 0x46, 0x54],
+				file_name: bounded_vec![0x46, 0x49, 0x4C, 0x45],
 				state: NftState::POD(20),
 			},
 		);
@@ -288,8 +288,8 @@ fn can_change_nft_state() {
 		Nfts::<Runtime>::insert(
 			1u32,
 			NftData {
-				data: vec![0x4E, 0x46, 0x54],
-				file_name: vec![0x46, 0x49, 0x4C, 0x45],
+				data: bounded_vec![0x4E, 0x46, 0x54],
+				file_name: bounded_vec![0x46, 0x49, 0x4C, 0x45],
 				state: NftState::Free,
 			},
 		);
@@ -334,13 +334,13 @@ fn set_up_pod(pod_id: PodId, nft_id: NftId) {
 	Nfts::<Runtime>::insert(
 		nft_id,
 		NftData {
-			data: vec![0x4E, 0x46, 0x54],
-			file_name: vec![0x46, 0x49, 0x4C, 0x45],
+			data: bounded_vec![0x4E, 0x46, 0x54],
+			file_name: bounded_vec![0x46, 0x49, 0x4C, 0x45],
 			state: NftState::POD(pod_id),
 		},
 	);
 	Owners::<Runtime>::insert(nft_id, ALICE);
-	PodExpiry::<Runtime>::insert(expire_at, vec![(pod_id, nft_id)]);
+	PodExpiry::<Runtime>::insert(expire_at, bounded_vec![(pod_id, nft_id)]);
 	PendingPodNfts::<Runtime>::insert(
 		pod_id,
 		PodInfo { nft_id, to_user: BOB, price: DOLLAR, expiry_block: expire_at },
@@ -411,8 +411,8 @@ fn can_seller_cancel_pod() {
 		assert_eq!(
 			Nfts::<Runtime>::get(nft_id),
 			Some(NftData {
-				data: vec![0x4E, 0x46, 0x54],
-				file_name: vec![0x46, 0x49, 0x4C, 0x45],
+				data: bounded_vec![0x4E, 0x46, 0x54],
+				file_name: bounded_vec![0x46, 0x49, 0x4C, 0x45],
 				state: NftState::Free
 			})
 		);
@@ -495,3 +495,24 @@ fn can_nft_pod_expire() {
 		}));
 	});
 }
+
+#[test]
+fn try_state_checks_nft_states() {
+	default_test_ext().execute_with(|| {
+		let pod_id = Nft::next_pod_id();
+		let nft_id = Nft::next_nft_id();
+		set_up_pod(pod_id, nft_id);
+		assert_ok!(Nft::do_try_state());
+
+		PendingPodNfts::<Runtime>::remove(pod_id);
+		assert!(Nft::do_try_state().is_err());
+
+		// The mock auction manager has no auctions.
+		Nfts::<Runtime>::mutate(nft_id, |nft| {
+			if let Some(nft) = nft {
+				nft.state = NftState::Auction(1);
+			}
+		});
+		assert!(Nft::do_try_state().is_err());
+	});
+}
@@ -0,0 +1,63 @@
+//! Benchmarking setup for pallet-treasury
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+use frame_benchmarking::v2::*;
+use frame_support::{assert_ok, traits::UnfilteredDispatchable};
+use primitives::DOLLAR;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn spend() {
+		let treasury = T::Bank::treasury().unwrap();
+		let beneficiary: T::AccountId = whitelisted_caller();
+		assert_ok!(T::Bank::deposit(&treasury, (DOLLAR * 1_000).into()));
+		Budget::<T>::put(T::Balance::from(DOLLAR * 100));
+		let call =
+			Call::<T>::spend { beneficiary: beneficiary.clone(), amount: (DOLLAR * 10).into() };
+		let origin = T::EnsureGovernance::try_successful_origin().unwrap();
+
+		#[block]
+		{
+			assert_ok!(call.dispatch_bypass_filter(origin));
+		}
+
+		// Verify
+		assert_eq!(T::Bank::free_balance(&beneficiary), (DOLLAR * 10).into());
+	}
+
+	#[benchmark]
+	fn set_budget() {
+		let budget = Some((DOLLAR * 100).into());
+		let call = Call::<T>::set_budget { budget };
+		let origin = T::EnsureGovernance::try_successful_origin().unwrap();
+
+		#[block]
+		{
+			assert_ok!(call.dispatch_bypass_filter(origin));
+		}
+
+		// Verify
+		assert_eq!(Budget::<T>::get(), budget);
+	}
+
+	#[benchmark]
+	fn set_burn_rate() {
+		let burn_rate = Permill::from_percent(10);
+		let call = Call::<T>::set_burn_rate { burn_rate };
+		let origin = T::EnsureGovernance::try_successful_origin().unwrap();
+
+		#[block]
+		{
+			assert_ok!(call.dispatch_bypass_filter(origin));
+		}
+
+		// Verify
+		assert_eq!(BurnRate::<T>::get(), burn_rate);
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::default_test_ext(), crate::mock::Runtime);
+}
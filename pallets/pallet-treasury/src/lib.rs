@@ -0,0 +1,315 @@
+//! # Treasury Pallet
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+use serde::{Deserialize, Serialize};
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, BlockNumberProvider, CheckedAdd, Saturating, Zero},
+	DispatchResult, Permill,
+};
+use sp_std::{fmt::Debug, prelude::*, vec::Vec};
+
+use primitives::{IncomeSource, TransferReason};
+use traits::{BasicAccounting, GetTreasury, RecordTreasuryIncome};
+
+mod mock;
+mod tests;
+
+pub mod migrations;
+pub mod weights;
+pub use weights::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+/// The maximum number of income sources in a report, more than the variants of `IncomeSource`.
+pub type MaxIncomeSources = ConstU32<32>;
+
+/// The income and spending of the treasury in a budget period.
+#[derive(
+	Encode,
+	Decode,
+	Clone,
+	PartialEq,
+	Eq,
+	Default,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub struct TreasuryReport<Balance> {
+	/// The income received from each source.
+	pub income: BoundedVec<(IncomeSource, Balance), MaxIncomeSources>,
+	/// The funds paid out by spends approved by governance.
+	pub spent: Balance,
+	/// The transaction fees of the calls sponsored by governance, paid by the treasury.
+	pub sponsored: Balance,
+	/// The unspent budget of the previous period, burned at the start of the period.
+	pub burned: Balance,
+}
+
+impl<Balance: Saturating + Copy> TreasuryReport<Balance> {
+	/// Add the income received from a source.
+	fn add_income(&mut self, source: IncomeSource, amount: Balance) {
+		match self.income.iter_mut().find(|(income_source, _)| *income_source == source) {
+			Some((_, total)) => *total = total.saturating_add(amount),
+			None => {
+				// There are fewer sources than the bound.
+				let _ = self.income.try_push((source, amount));
+			},
+		}
+	}
+}
+
+pub use module::*;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	pub type TreasuryReportOf<T> = TreasuryReport<<T as Config>::Balance>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Type representing the weight of this pallet
+		type WeightInfo: WeightInfo;
+
+		/// The balance type
+		type Balance: Member
+			+ Parameter
+			+ MaxEncodedLen
+			+ AtLeast32BitUnsigned
+			+ Default
+			+ Copy
+			+ MaybeSerializeDeserialize
+			+ Debug
+			+ From<u128>
+			+ sp_std::iter::Sum;
+
+		type Bank: BasicAccounting<Self::AccountId, Self::Balance> + GetTreasury<Self::AccountId>;
+
+		type EnsureGovernance: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+		/// The length of a budget period. Budgets, burns and reports are per period.
+		#[pallet::constant]
+		type BudgetPeriod: Get<BlockNumberFor<Self>>;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The amount to spend is zero.
+		InvalidAmount,
+		/// The spend exceeds the budget left in this period.
+		BudgetExceeded,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Governance approved a spend from the treasury.
+		Spent { beneficiary: T::AccountId, amount: T::Balance },
+		/// Governance set the budget of each period.
+		BudgetSet { budget: Option<T::Balance> },
+		/// Governance set the proportion of the unspent budget burned at the end of each period.
+		BurnRateSet { burn_rate: Permill },
+		/// The unspent budget of the treasury is burned.
+		Burned { amount: T::Balance },
+	}
+
+	/// Stores the budget of each period, shared by the spends and the fees of sponsored calls.
+	/// Spending is not limited if it is not set, but no calls are sponsored.
+	#[pallet::storage]
+	#[pallet::getter(fn budget)]
+	pub type Budget<T: Config> = StorageValue<_, T::Balance>;
+
+	/// Stores the proportion of the unspent budget burned at the end of each period.
+	#[pallet::storage]
+	#[pallet::getter(fn burn_rate)]
+	pub type BurnRate<T: Config> = StorageValue<_, Permill, ValueQuery>;
+
+	/// Stores the income and spending of each period, by the block the period starts at.
+	#[pallet::storage]
+	#[pallet::getter(fn reports)]
+	pub type Reports<T: Config> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, TreasuryReportOf<T>, ValueQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn integrity_test() {
+			assert!(!T::BudgetPeriod::get().is_zero());
+		}
+
+		fn on_finalize(block_number: BlockNumberFor<T>) {
+			// Burn the unspent budget of the previous period at the start of every period.
+			if (block_number % T::BudgetPeriod::get()).is_zero() {
+				Self::burn_unspent(block_number);
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Pay `amount` from the treasury to the beneficiary, within the budget left in this
+		/// period.
+		///
+		/// Requires governance approved.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::spend())]
+		pub fn spend(
+			origin: OriginFor<T>,
+			beneficiary: T::AccountId,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			// ensure governance
+			T::EnsureGovernance::ensure_origin(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+			let treasury = T::Bank::treasury()?;
+
+			let period = Self::period_start(frame_system::Pallet::<T>::current_block_number());
+			Reports::<T>::try_mutate(period, |report| -> DispatchResult {
+				let spent = report.spent.saturating_add(amount);
+				if let Some(budget) = Budget::<T>::get() {
+					ensure!(
+						spent.saturating_add(report.sponsored) <= budget,
+						Error::<T>::BudgetExceeded
+					);
+				}
+				T::Bank::transfer_with_reason(
+					&treasury,
+					&beneficiary,
+					amount,
+					TransferReason::TreasurySpend,
+				)?;
+				report.spent = spent;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::Spent { beneficiary, amount });
+			Ok(())
+		}
+
+		/// Set the budget of each period, or remove it with `None`.
+		///
+		/// Requires governance approved.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::set_budget())]
+		pub fn set_budget(origin: OriginFor<T>, budget: Option<T::Balance>) -> DispatchResult {
+			// ensure governance
+			T::EnsureGovernance::ensure_origin(origin)?;
+
+			Budget::<T>::set(budget);
+			Self::deposit_event(Event::<T>::BudgetSet { budget });
+			Ok(())
+		}
+
+		/// Set the proportion of the unspent budget burned at the end of each period. Nothing is
+		/// burned without a budget.
+		///
+		/// Requires governance approved.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::set_burn_rate())]
+		pub fn set_burn_rate(origin: OriginFor<T>, burn_rate: Permill) -> DispatchResult {
+			// ensure governance
+			T::EnsureGovernance::ensure_origin(origin)?;
+
+			BurnRate::<T>::put(burn_rate);
+			Self::deposit_event(Event::<T>::BurnRateSet { burn_rate });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Returns the block the budget period of a block starts at.
+		fn period_start(block_number: BlockNumberFor<T>) -> BlockNumberFor<T> {
+			block_number - block_number % T::BudgetPeriod::get()
+		}
+
+		/// Burn the burn rate of the budget left unspent in the previous period, and record it in
+		/// the report of the period starting at `block_number`.
+		fn burn_unspent(block_number: BlockNumberFor<T>) {
+			let burn_rate = BurnRate::<T>::get();
+			if burn_rate.is_zero() || block_number < T::BudgetPeriod::get() {
+				return;
+			}
+			let budget = match Budget::<T>::get() {
+				Some(budget) => budget,
+				None => return,
+			};
+			let treasury = match T::Bank::treasury() {
+				Ok(treasury) => treasury,
+				Err(_) => return,
+			};
+
+			let report = Reports::<T>::get(block_number - T::BudgetPeriod::get());
+			let unspent = budget.saturating_sub(report.spent).saturating_sub(report.sponsored);
+			let amount = (burn_rate * unspent).min(T::Bank::free_balance(&treasury));
+			if amount.is_zero() || T::Bank::withdraw(&treasury, amount).is_err() {
+				return;
+			}
+			Reports::<T>::mutate(Self::period_start(block_number), |report| {
+				report.burned = report.burned.saturating_add(amount);
+			});
+			Self::deposit_event(Event::<T>::Burned { amount });
+		}
+
+		/// Returns whether the treasury can pay a transaction fee of a sponsored call, within the
+		/// budget left in this period.
+		pub fn can_sponsor(fee: T::Balance) -> bool {
+			let period = Self::period_start(frame_system::Pallet::<T>::current_block_number());
+			let report = Reports::<T>::get(period);
+			Budget::<T>::get().map_or(false, |budget| {
+				report.spent.saturating_add(report.sponsored).saturating_add(fee) <= budget
+			})
+		}
+
+		/// Record the transaction fee of a sponsored call paid by the treasury in this period.
+		pub fn record_sponsored(fee: T::Balance) {
+			let period = Self::period_start(frame_system::Pallet::<T>::current_block_number());
+			Reports::<T>::mutate(period, |report| {
+				report.sponsored = report.sponsored.saturating_add(fee);
+			});
+		}
+
+		/// Returns the reports of the periods between two blocks inclusive, by the block each
+		/// period starts at.
+		pub fn reports_between(
+			from_block: BlockNumberFor<T>,
+			to_block: BlockNumberFor<T>,
+		) -> Vec<(BlockNumberFor<T>, TreasuryReportOf<T>)> {
+			let mut reports = Vec::new();
+			let mut start = Self::period_start(from_block);
+			while start <= to_block {
+				if let Ok(report) = Reports::<T>::try_get(start) {
+					reports.push((start, report));
+				}
+				start = match start.checked_add(&T::BudgetPeriod::get()) {
+					Some(next) => next,
+					None => break,
+				};
+			}
+			reports
+		}
+	}
+}
+
+impl<T: Config> RecordTreasuryIncome<T::Balance> for Pallet<T> {
+	fn record_income(source: IncomeSource, amount: T::Balance) {
+		if amount.is_zero() {
+			return;
+		}
+		let period = Self::period_start(frame_system::Pallet::<T>::current_block_number());
+		Reports::<T>::mutate(period, |report| report.add_income(source, amount));
+	}
+}
@@ -0,0 +1,75 @@
+//! Storage migrations of the treasury pallet.
+
+use super::*;
+use frame_support::{storage_alias, traits::OnRuntimeUpgrade};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+pub mod v1 {
+	use super::*;
+
+	/// The reports of version 0.
+	mod v0 {
+		use super::*;
+
+		#[derive(Encode, Decode)]
+		pub struct TreasuryReport<Balance> {
+			pub income: BoundedVec<(IncomeSource, Balance), MaxIncomeSources>,
+			pub spent: Balance,
+			pub burned: Balance,
+		}
+
+		#[storage_alias]
+		pub type Reports<T: Config> = StorageMap<
+			Pallet<T>,
+			Twox64Concat,
+			BlockNumberFor<T>,
+			TreasuryReport<<T as Config>::Balance>,
+		>;
+	}
+
+	/// Migrates the storage from version 0 to 1.
+	///
+	/// The reports record the fees of sponsored calls in version 1. No fees were sponsored by the
+	/// treasury's budget before.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() != 0 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut translated = 0u64;
+			Reports::<T>::translate::<v0::TreasuryReport<T::Balance>, _>(|_, report| {
+				translated += 1;
+				Some(TreasuryReport {
+					income: report.income,
+					spent: report.spent,
+					sponsored: Zero::zero(),
+					burned: report.burned,
+				})
+			});
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok((v0::Reports::<T>::iter_keys().count() as u64).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(StorageVersion::get::<Pallet<T>>() == 1, "The storage version is not 1.");
+			let count = u64::decode(&mut &state[..])
+				.map_err(|_| TryRuntimeError::Other("Failed to decode the report count."))?;
+			ensure!(
+				Reports::<T>::iter().count() as u64 == count,
+				"Some reports failed to migrate."
+			);
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,174 @@
+#![cfg(test)]
+
+use super::*;
+use frame_support::{
+	construct_runtime, derive_impl, parameter_types,
+	traits::{ConstU32, ConstU64, Everything},
+};
+
+use primitives::{Balance, Role, YEAR};
+use sp_runtime::{testing::H256, traits::IdentityLookup, BuildStorage, Perbill};
+
+use crate as pallet_treasury;
+
+pub type AccountId = u32;
+
+type BlockNumber = u64;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+
+pub const TREASURY: AccountId = 0;
+pub const TREASURY_BALANCE: Balance = 1_000;
+pub const BUDGET_PERIOD: u64 = 100;
+pub const ED: u128 = 3u128;
+pub const MIN: u128 = 5u128;
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Runtime {
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type DbWeight = ();
+	type BaseCallFilter = Everything;
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+	type Nonce = u128;
+	type Block = Block;
+}
+
+parameter_types! {
+	pub const BudgetPeriod: BlockNumber = BUDGET_PERIOD;
+}
+
+impl Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Balance = Balance;
+	type Bank = Bank;
+	type EnsureGovernance = traits::SuccessOrigin<Runtime>;
+	type BudgetPeriod = BudgetPeriod;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = ED;
+	pub const MinimumAmount: Balance = MIN;
+	pub const RedeemPeriod: BlockNumber = 200;
+	pub const StakePeriod: BlockNumber = 150;
+	pub const InterestPayoutPeriod: BlockNumber = 100;
+	pub const TotalBlocksPerYear: BlockNumber = YEAR as BlockNumber;
+	pub const InterestRateNoticePeriod: BlockNumber = 10;
+	pub const WithdrawalRequestPeriod: BlockNumber = 10;
+	pub const JointTransferPeriod: BlockNumber = 10;
+	pub const BlocksPerDay: BlockNumber = 10;
+	pub const BlocksPerWeek: BlockNumber = 70;
+	pub const MaxLoanToValue: Perbill = Perbill::from_percent(50);
+	pub const LiquidationLoanToValue: Perbill = Perbill::from_percent(80);
+}
+
+impl pallet_bank::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Balance = Balance;
+	type RoleManager = Roles;
+	type EnsureGovernance = traits::SuccessOrigin<Runtime>;
+	type TreasuryIncome = Treasury;
+	type RuntimeHoldReason = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type MinimumAmount = MinimumAmount;
+	type RedeemPeriod = RedeemPeriod;
+	type StakePeriod = StakePeriod;
+	type InterestPayoutPeriod = InterestPayoutPeriod;
+	type TotalBlocksPerYear = TotalBlocksPerYear;
+	type MaxInterestPayoutsPerBlock = ConstU32<100>;
+	type InterestRateNoticePeriod = InterestRateNoticePeriod;
+	type WithdrawalRequestPeriod = WithdrawalRequestPeriod;
+	type JointTransferPeriod = JointTransferPeriod;
+	type BlocksPerDay = BlocksPerDay;
+	type BlocksPerWeek = BlocksPerWeek;
+	type MaxLoanToValue = MaxLoanToValue;
+	type LiquidationLoanToValue = LiquidationLoanToValue;
+	type MaxReapsPerBlock = ConstU32<2>;
+	type MaxStandingOrderRetries = ConstU32<2>;
+	type MaxLedgerEntries = ConstU32<5>;
+	type MaxJointHolders = ConstU32<3>;
+	type MaxBatchTransfers = ConstU32<3>;
+	type MaxLocks = ConstU32<10>;
+	type MaxScheduledPerBlock = ConstU32<10>;
+	type MaxSponsoredCalls = ConstU32<10>;
+}
+
+impl pallet_roles::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type EnsureGovernance = traits::SuccessOrigin<Runtime>;
+}
+
+construct_runtime!(
+	pub enum Runtime
+	{
+		System: frame_system,
+		Treasury: pallet_treasury,
+		Bank: pallet_bank,
+		Roles: pallet_roles,
+	}
+);
+
+#[derive(Default)]
+pub struct MockGenesisConfig {
+	balances: Vec<(AccountId, Balance)>,
+}
+
+impl MockGenesisConfig {
+	pub fn with_balances(mut self, balances: Vec<(AccountId, Balance)>) -> Self {
+		self.balances = balances;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut endowed = self.balances;
+		endowed.push((TREASURY, TREASURY_BALANCE));
+
+		let config = RuntimeGenesisConfig {
+			system: Default::default(),
+			bank: pallet_bank::GenesisConfig {
+				balances: endowed
+					.iter()
+					.map(|(account, free)| (*account, *free, 0u128))
+					.collect::<Vec<_>>(),
+				treasury: Some(TREASURY),
+			},
+			roles: pallet_roles::GenesisConfig {
+				roles: endowed.iter().map(|(account, _)| (*account, Role::Customer)).collect(),
+			},
+		};
+
+		let mut ext: sp_io::TestExternalities = config.build_storage().unwrap().into();
+
+		ext.execute_with(|| {
+			System::set_block_number(1);
+		});
+
+		ext
+	}
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn default_test_ext() -> sp_io::TestExternalities {
+	MockGenesisConfig::default().build()
+}
@@ -0,0 +1,175 @@
+#![cfg(test)]
+
+use crate::{mock::*, *};
+
+use frame_support::{assert_noop, assert_ok, bounded_vec, traits::Hooks};
+use pallet_bank::FeeSchedule;
+use traits::BasicAccounting;
+
+#[test]
+fn income_is_recorded_by_source_and_period() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000), (BOB, 1_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Bank::set_transfer_fee_schedule(
+				RuntimeOrigin::root(),
+				Some(FeeSchedule::Flat(10))
+			));
+			assert_ok!(Bank::transfer(RuntimeOrigin::signed(ALICE), BOB, 100));
+			assert_ok!(Bank::transfer(RuntimeOrigin::signed(BOB), ALICE, 100));
+			Treasury::record_income(IncomeSource::LotteryTax, 50);
+			assert_eq!(
+				Treasury::reports(0),
+				TreasuryReport {
+					income: bounded_vec![
+						(IncomeSource::TransferFee, 20),
+						(IncomeSource::LotteryTax, 50)
+					],
+					spent: 0,
+					sponsored: 0,
+					burned: 0,
+				}
+			);
+
+			// Income of the next period is reported separately.
+			System::set_block_number(BUDGET_PERIOD + 1);
+			Treasury::record_income(IncomeSource::AuctionFee, 30);
+			Treasury::record_income(IncomeSource::AuctionFee, 0);
+			assert_eq!(
+				Treasury::reports_between(50, BUDGET_PERIOD + 1),
+				vec![
+					(0, Treasury::reports(0)),
+					(
+						BUDGET_PERIOD,
+						TreasuryReport {
+							income: bounded_vec![(IncomeSource::AuctionFee, 30)],
+							spent: 0,
+							sponsored: 0,
+							burned: 0,
+						}
+					),
+				]
+			);
+			assert_eq!(Treasury::reports_between(BUDGET_PERIOD, BUDGET_PERIOD).len(), 1);
+			assert_eq!(Treasury::reports_between(0, 3 * BUDGET_PERIOD).len(), 2);
+			assert_eq!(Treasury::reports_between(2 * BUDGET_PERIOD, 3 * BUDGET_PERIOD), vec![]);
+		});
+}
+
+#[test]
+fn governance_can_spend_within_budget() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Treasury::spend(RuntimeOrigin::root(), ALICE, 0),
+				Error::<Runtime>::InvalidAmount
+			);
+
+			assert_ok!(Treasury::set_budget(RuntimeOrigin::root(), Some(100)));
+			System::assert_last_event(RuntimeEvent::Treasury(Event::<Runtime>::BudgetSet {
+				budget: Some(100),
+			}));
+
+			assert_ok!(Treasury::spend(RuntimeOrigin::root(), ALICE, 60));
+			System::assert_last_event(RuntimeEvent::Treasury(Event::<Runtime>::Spent {
+				beneficiary: ALICE,
+				amount: 60,
+			}));
+			assert_eq!(Bank::free_balance(&ALICE), 1_060);
+			assert_eq!(Bank::free_balance(&TREASURY), TREASURY_BALANCE - 60);
+			assert_noop!(
+				Treasury::spend(RuntimeOrigin::root(), ALICE, 50),
+				Error::<Runtime>::BudgetExceeded
+			);
+
+			// The budget is renewed every period.
+			System::set_block_number(BUDGET_PERIOD + 1);
+			assert_ok!(Treasury::spend(RuntimeOrigin::root(), ALICE, 50));
+			assert_eq!(Treasury::reports(0).spent, 60);
+			assert_eq!(Treasury::reports(BUDGET_PERIOD).spent, 50);
+
+			// Spending is not limited without a budget.
+			assert_ok!(Treasury::set_budget(RuntimeOrigin::root(), None));
+			assert_ok!(Treasury::spend(RuntimeOrigin::root(), ALICE, 500));
+			assert_eq!(Bank::free_balance(&TREASURY), TREASURY_BALANCE - 610);
+		});
+}
+
+#[test]
+fn sponsored_fees_count_towards_the_budget() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000)])
+		.build()
+		.execute_with(|| {
+			// No calls are sponsored without a budget.
+			assert!(!Treasury::can_sponsor(10));
+
+			assert_ok!(Treasury::set_budget(RuntimeOrigin::root(), Some(100)));
+			assert!(Treasury::can_sponsor(100));
+			Treasury::record_sponsored(30);
+			assert_eq!(Treasury::reports(0).sponsored, 30);
+			assert!(Treasury::can_sponsor(70));
+			assert!(!Treasury::can_sponsor(71));
+
+			// Spends and sponsored fees share the budget.
+			assert_noop!(
+				Treasury::spend(RuntimeOrigin::root(), ALICE, 71),
+				Error::<Runtime>::BudgetExceeded
+			);
+			assert_ok!(Treasury::spend(RuntimeOrigin::root(), ALICE, 60));
+			assert!(!Treasury::can_sponsor(11));
+
+			// The budget is renewed every period.
+			System::set_block_number(BUDGET_PERIOD + 1);
+			assert!(Treasury::can_sponsor(100));
+		});
+}
+
+#[test]
+fn unspent_budget_is_burned() {
+	MockGenesisConfig::default()
+		.with_balances(vec![(ALICE, 1_000)])
+		.build()
+		.execute_with(|| {
+			// Nothing is burned without a burn rate.
+			assert_ok!(Treasury::set_budget(RuntimeOrigin::root(), Some(500)));
+			Treasury::on_finalize(BUDGET_PERIOD);
+			assert_eq!(Bank::free_balance(&TREASURY), TREASURY_BALANCE);
+
+			assert_ok!(Treasury::set_burn_rate(RuntimeOrigin::root(), Permill::from_percent(50)));
+			System::assert_last_event(RuntimeEvent::Treasury(Event::<Runtime>::BurnRateSet {
+				burn_rate: Permill::from_percent(50),
+			}));
+
+			// Funds are only burned at the start of a period.
+			Treasury::on_finalize(BUDGET_PERIOD + 1);
+			assert_eq!(Bank::free_balance(&TREASURY), TREASURY_BALANCE);
+
+			// The burn rate of the budget left unspent in the previous period is burned.
+			System::set_block_number(BUDGET_PERIOD + 1);
+			assert_ok!(Treasury::spend(RuntimeOrigin::root(), ALICE, 100));
+			Treasury::on_finalize(2 * BUDGET_PERIOD);
+			System::assert_last_event(RuntimeEvent::Treasury(Event::<Runtime>::Burned {
+				amount: 200,
+			}));
+			assert_eq!(Bank::free_balance(&TREASURY), 700);
+			assert_eq!(Treasury::reports(2 * BUDGET_PERIOD).burned, 200);
+			assert_eq!(Bank::total_issuance(), 1_800);
+
+			// No more than the treasury's funds are burned.
+			assert_ok!(Treasury::set_burn_rate(RuntimeOrigin::root(), Permill::one()));
+			assert_ok!(Treasury::set_budget(RuntimeOrigin::root(), Some(1_000)));
+			Treasury::on_finalize(3 * BUDGET_PERIOD);
+			assert_eq!(Treasury::reports(3 * BUDGET_PERIOD).burned, 700);
+			assert_eq!(Bank::free_balance(&TREASURY), 0);
+
+			// Nothing is burned without a budget.
+			assert_ok!(Treasury::set_budget(RuntimeOrigin::root(), None));
+			assert_ok!(Bank::transfer(RuntimeOrigin::signed(ALICE), TREASURY, 100));
+			Treasury::on_finalize(4 * BUDGET_PERIOD);
+			assert_eq!(Bank::free_balance(&TREASURY), 100);
+		});
+}
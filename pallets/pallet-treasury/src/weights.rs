@@ -0,0 +1,73 @@
+//! Weights for pallet_treasury
+//!
+//! These weights are estimated by hand, and are placeholders until the pallet is benchmarked.
+
+// Command to generate this file, once the pallet is benchmarked:
+// ./target/release/xy-chain
+// benchmark
+// pallet
+// --extrinsic
+// *
+// --pallet
+// pallet_treasury
+// --output
+// ./pallets/pallet-treasury/src/weights.rs
+// --steps=20
+// --repeat=20
+// --template=weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_treasury.
+pub trait WeightInfo {
+	fn spend() -> Weight;
+	fn set_budget() -> Weight;
+	fn set_burn_rate() -> Weight;
+}
+
+/// Weights for pallet_treasury using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn spend() -> Weight {
+		// Placeholder: estimated by hand, not benchmarked.
+		Weight::from_parts(39_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	fn set_budget() -> Weight {
+		// Placeholder: estimated by hand, not benchmarked.
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_burn_rate() -> Weight {
+		// Placeholder: estimated by hand, not benchmarked.
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn spend() -> Weight {
+		// Placeholder: estimated by hand, not benchmarked.
+		Weight::from_parts(39_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	fn set_budget() -> Weight {
+		// Placeholder: estimated by hand, not benchmarked.
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_burn_rate() -> Weight {
+		// Placeholder: estimated by hand, not benchmarked.
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
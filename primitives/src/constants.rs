@@ -1,7 +1,7 @@
 #![allow(dead_code)]
 
 /// Defines all the constants for the project
-use crate::{Balance, BlockNumber};
+use crate::{AssetId, Balance, BlockNumber};
 
 /// This determines the average expected block time that we are targeting.
 /// Blocks will be produced at a minimum duration defined by `SLOT_DURATION`.
@@ -26,3 +26,12 @@ pub const CENT: Balance = 10_000_000_000u128;
 pub const MILLICENT: Balance = 10_000_000u128;
 
 pub const FILENAME_MAXSIZE: u32 = 255u32;
+
+pub const ASSET_NAME_MAXSIZE: u32 = 32u32;
+pub const ASSET_SYMBOL_MAXSIZE: u32 = 8u32;
+
+/// The maximum length of a memo or reference attached to a payment.
+pub const MEMO_MAXSIZE: u32 = 64u32;
+
+/// The asset id of the bank's native currency.
+pub const NATIVE_ASSET_ID: AssetId = 0u32;
@@ -59,6 +59,76 @@ pub enum NftState {
 	Auction(AuctionId),
 }
 
+/// Enum representing the different sources of the treasury's income.
+#[derive(
+	Encode,
+	Decode,
+	Copy,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub enum IncomeSource {
+	/// The tax on lottery prizes.
+	LotteryTax,
+	/// The fees to create PODs, and the PODs of NFTs owned by managers.
+	PodFee,
+	/// The fees to start auctions, and the fees on successful auctions.
+	AuctionFee,
+	/// The dust of reaped accounts.
+	Reaping,
+	/// The fees on customer transfers.
+	TransferFee,
+	/// The interest on overdrafts and loans.
+	Interest,
+	/// The penalties of early redemptions, and the collateral of liquidated loans.
+	Penalty,
+	/// The fees and tips of transactions.
+	TransactionFee,
+}
+
+/// Enum representing why a pallet moves funds between accounts.
+#[derive(
+	Encode,
+	Decode,
+	Copy,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub enum TransferReason {
+	/// The price of lottery tickets, paid into the prize pool.
+	LotteryTicket,
+	/// A lottery prize, paid out of the prize pool.
+	LotteryPrize,
+	/// The tax on a lottery prize, paid to the treasury.
+	LotteryTax,
+	/// A bid, paid into the bids pool.
+	AuctionBid,
+	/// A bid returned to its bidder.
+	AuctionRefund,
+	/// The price of a successful auction, paid to the seller.
+	AuctionPayout,
+	/// The fees to start auctions, and the fees on successful auctions.
+	AuctionFee,
+	/// The fee to create a POD.
+	PodFee,
+	/// The price of a POD, paid to the seller.
+	PodPayment,
+	/// A spend approved by governance, paid out of the treasury.
+	TreasurySpend,
+}
+
 /// Enum representing the different state that an Nft can have.
 #[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
 pub enum Response {
@@ -117,6 +187,15 @@ pub use sp_runtime::OpaqueExtrinsic as UncheckedExtrinsic;
 /// Lock Id
 pub type LockId = u64;
 
+/// Asset Id
+pub type AssetId = u32;
+
+/// Standing order Id
+pub type OrderId = u32;
+
+/// Interest product Id
+pub type ProductId = u32;
+
 /// Some way of identifying an account on the chain. We intentionally make it equivalent
 /// to the public key of our transaction signing scheme.
 pub type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::AccountId;
@@ -153,7 +232,18 @@ pub struct PendingNftPods {
 }
 
 /// Represents Auction data including its reserve price, expiry block, current price and bider.
-#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Serialize, Deserialize)]
+#[derive(
+	Encode,
+	Decode,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
 pub struct AuctionData<AccountId, Balance, BlockNumber> {
 	pub nft_id: NftId,
 	pub start: Option<Balance>,
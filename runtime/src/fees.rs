@@ -0,0 +1,96 @@
+//! Payment of transaction fees from the funds kept by pallet-bank.
+
+use frame_support::traits::{
+	fungible::{Balanced, Credit, Debt},
+	tokens::{Fortitude, Precision, Preservation},
+	Imbalance, OnUnbalanced,
+};
+use pallet_transaction_payment::OnChargeTransaction;
+use sp_runtime::{
+	traits::{DispatchInfoOf, PostDispatchInfoOf, Zero},
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+};
+use sp_std::marker::PhantomData;
+
+use crate::{AccountId, Balance, Bank, Runtime, RuntimeCall, Treasury};
+
+/// Charges transaction fees from the "free" funds of the sender's bank account, and hands the fee
+/// and the tip to `OU`.
+///
+/// The treasury pays the fees of the calls sponsored by governance, within the treasury's budget of
+/// the period. Those fees are burned rather than handed to `OU`, and sponsored transactions cannot
+/// carry a tip. Once the budget is used up, the senders of sponsored calls pay their own fees.
+pub struct BankFeeAdapter<OU>(PhantomData<OU>);
+
+impl<OU> OnChargeTransaction<Runtime> for BankFeeAdapter<OU>
+where
+	OU: OnUnbalanced<Credit<AccountId, Bank>>,
+{
+	type Balance = Balance;
+	/// The account that paid the fee, and the fee withdrawn from it.
+	type LiquidityInfo = Option<(AccountId, Credit<AccountId, Bank>)>;
+
+	fn withdraw_fee(
+		who: &AccountId,
+		call: &RuntimeCall,
+		_dispatch_info: &DispatchInfoOf<RuntimeCall>,
+		fee: Balance,
+		tip: Balance,
+	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+		if fee.is_zero() {
+			return Ok(None);
+		}
+		let payer = if Bank::is_sponsored(call) && Treasury::can_sponsor(fee) {
+			if !tip.is_zero() {
+				return Err(InvalidTransaction::Payment.into());
+			}
+			pallet_bank::TreasuryAccount::<Runtime>::get().ok_or(InvalidTransaction::Payment)?
+		} else {
+			who.clone()
+		};
+
+		match <Bank as Balanced<AccountId>>::withdraw(
+			&payer,
+			fee,
+			Precision::Exact,
+			Preservation::Preserve,
+			Fortitude::Polite,
+		) {
+			Ok(credit) => Ok(Some((payer, credit))),
+			Err(_) => Err(InvalidTransaction::Payment.into()),
+		}
+	}
+
+	fn correct_and_deposit_fee(
+		who: &AccountId,
+		_dispatch_info: &DispatchInfoOf<RuntimeCall>,
+		_post_info: &PostDispatchInfoOf<RuntimeCall>,
+		corrected_fee: Balance,
+		tip: Balance,
+		already_withdrawn: Self::LiquidityInfo,
+	) -> Result<(), TransactionValidityError> {
+		let (payer, paid) = match already_withdrawn {
+			Some(withdrawn) => withdrawn,
+			None => return Ok(()),
+		};
+
+		// Refund the part of the fee that was not used.
+		let refund_amount = paid.peek().saturating_sub(corrected_fee);
+		let refund =
+			<Bank as Balanced<AccountId>>::deposit(&payer, refund_amount, Precision::BestEffort)
+				.unwrap_or_else(|_| Debt::<AccountId, Bank>::zero());
+		let paid = paid
+			.offset(refund)
+			.same()
+			.map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
+
+		// The fees of sponsored calls are burned, and counted against the treasury's budget.
+		if payer == *who {
+			let (tip, fee) = paid.split(tip);
+			OU::on_unbalanceds(Some(fee).into_iter().chain(Some(tip)));
+		} else {
+			Treasury::record_sponsored(paid.peek());
+		}
+		Ok(())
+	}
+}
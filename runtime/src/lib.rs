@@ -8,7 +8,7 @@ include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
 use pallet_auction::AuctionDataFor;
 use pallet_grandpa::AuthorityId as GrandpaId;
-use pallet_nft::NftData;
+use pallet_nft::NftDataOf;
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
@@ -40,18 +40,20 @@ pub use frame_support::{
 	StorageValue,
 };
 pub use frame_system::Call as SystemCall;
-pub use pallet_balances::Call as BalancesCall;
+pub use pallet_bank::Call as BankCall;
 pub use pallet_timestamp::Call as TimestampCall;
-use pallet_transaction_payment::{ConstFeeMultiplier, CurrencyAdapter, Multiplier};
+use pallet_transaction_payment::{ConstFeeMultiplier, Multiplier};
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
 pub use sp_runtime::{Perbill, Permill};
 
 use primitives::{
-	AccountId, AuctionId, Balance, BlockNumber, Hash, LockId, NftId, Nonce, PendingNftPods,
-	RpcNftData, Signature, DAY, DOLLAR, HOUR, SLOT_DURATION, YEAR,
+	AccountId, AssetId, AuctionId, Balance, BlockNumber, Hash, LockId, NftId, Nonce, OrderId,
+	PendingNftPods, ProductId, RpcNftData, Signature, DAY, DOLLAR, HOUR, SLOT_DURATION, YEAR,
 };
 
+pub mod fees;
+mod migrations;
 pub mod runtime_api;
 
 /// Opaque types. These are used by the CLI to instantiate machinery that don't need to know
@@ -142,8 +144,8 @@ impl frame_system::Config for Runtime {
 	type DbWeight = RocksDbWeight;
 	/// Version of the runtime.
 	type Version = Version;
-	/// The data to be stored in an account.
-	type AccountData = pallet_balances::AccountData<Balance>;
+	/// The data to be stored in an account. Balances are kept by pallet-bank.
+	type AccountData = ();
 	/// This is used as an identifier of the chain. 42 is the generic substrate prefix.
 	type SS58Prefix = SS58Prefix;
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
@@ -179,32 +181,13 @@ impl pallet_timestamp::Config for Runtime {
 	type WeightInfo = ();
 }
 
-impl pallet_balances::Config for Runtime {
-	type MaxLocks = ConstU32<50>;
-	type MaxReserves = ();
-	type ReserveIdentifier = [u8; 8];
-	/// The type for recording an account's balance.
-	type Balance = Balance;
-	/// The ubiquitous event type.
-	type RuntimeEvent = RuntimeEvent;
-	type DustRemoval = ();
-	type ExistentialDeposit = ConstU128<DOLLAR>;
-	type AccountStore = System;
-	type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
-	type FreezeIdentifier = ();
-	type MaxFreezes = ();
-	type RuntimeHoldReason = ();
-	type RuntimeFreezeReason = ();
-	type MaxHolds = ();
-}
-
 parameter_types! {
 	pub FeeMultiplier: Multiplier = Multiplier::one();
 }
 
 impl pallet_transaction_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnChargeTransaction = CurrencyAdapter<Balances, ()>;
+	type OnChargeTransaction = fees::BankFeeAdapter<pallet_bank::FeesToTreasury<Runtime>>;
 	type OperationalFeeMultiplier = ConstU8<5>;
 	type WeightToFee = IdentityFee<Balance>;
 	type LengthToFee = IdentityFee<Balance>;
@@ -221,6 +204,8 @@ impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
 
 parameter_types! {
 	pub MinimumAmount: Balance = 2 * DOLLAR;
+	pub const MaxLoanToValue: Perbill = Perbill::from_percent(50);
+	pub const LiquidationLoanToValue: Perbill = Perbill::from_percent(80);
 }
 
 impl pallet_bank::Config for Runtime {
@@ -229,12 +214,30 @@ impl pallet_bank::Config for Runtime {
 	type Balance = Balance;
 	type RoleManager = Roles;
 	type EnsureGovernance = pallet_governance::EnsureGovernance;
+	type TreasuryIncome = Treasury;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type ExistentialDeposit = ConstU128<DOLLAR>;
 	type MinimumAmount = MinimumAmount;
 	type RedeemPeriod = ConstU32<{ 5 * DAY }>;
 	type StakePeriod = ConstU32<{ 2 * DAY }>;
 	type InterestPayoutPeriod = ConstU32<DAY>;
 	type TotalBlocksPerYear = ConstU32<YEAR>;
+	type MaxInterestPayoutsPerBlock = ConstU32<500>;
+	type InterestRateNoticePeriod = ConstU32<{ 7 * DAY }>;
+	type WithdrawalRequestPeriod = ConstU32<{ 7 * DAY }>;
+	type JointTransferPeriod = ConstU32<{ 7 * DAY }>;
+	type BlocksPerDay = ConstU32<DAY>;
+	type BlocksPerWeek = ConstU32<{ 7 * DAY }>;
+	type MaxLoanToValue = MaxLoanToValue;
+	type LiquidationLoanToValue = LiquidationLoanToValue;
+	type MaxReapsPerBlock = ConstU32<100>;
+	type MaxStandingOrderRetries = ConstU32<3>;
+	type MaxLedgerEntries = ConstU32<1_000>;
+	type MaxJointHolders = ConstU32<16>;
+	type MaxBatchTransfers = ConstU32<500>;
+	type MaxLocks = ConstU32<100>;
+	type MaxScheduledPerBlock = ConstU32<1_000>;
+	type MaxSponsoredCalls = ConstU32<128>;
 }
 
 /// Configure the pallet-template in pallets/template.
@@ -257,10 +260,12 @@ impl pallet_lottery::Config for Runtime {
 	type RoleManager = Roles;
 	type EnsureGovernance = pallet_governance::EnsureGovernance;
 	type Bank = Bank;
+	type TreasuryIncome = Treasury;
 	type Randomness = Random;
 	type LotteryPayoutPeriod = LotteryPayoutPeriod;
 	type PrizePoolAccount = PrizePoolAccount;
 	type TaxRate = TaxRate;
+	type MaxWinners = ConstU32<100>;
 }
 
 parameter_types! {
@@ -276,6 +281,10 @@ impl pallet_governance::Config for Runtime {
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeCall = RuntimeCall;
 	type EnsureGovernance = pallet_governance::EnsureGovernance;
+	type MaxAuthorities = ConstU32<100>;
+	type MaxProposals = ConstU32<100>;
+	// Large enough for a runtime upgrade.
+	type MaxProposalLength = ConstU32<{ 5 * 1024 * 1024 }>;
 }
 
 impl pallet_nft::Config for Runtime {
@@ -286,9 +295,11 @@ impl pallet_nft::Config for Runtime {
 	type Bank = Bank;
 	type AuctionManager = Auction;
 	type EnsureGovernance = pallet_governance::EnsureGovernance;
+	type TreasuryIncome = Treasury;
 	type MaxSize = ConstU32<1_048_576>; // 1MB
 	type PodFee = ConstU128<DOLLAR>;
 	type NftLockedPeriod = ConstU32<DAY>;
+	type MaxPodsPerBlock = ConstU32<1_000>;
 }
 
 parameter_types! {
@@ -304,6 +315,7 @@ impl pallet_auction::Config for Runtime {
 	type RoleManager = Roles;
 	type Balance = Balance;
 	type Bank = Bank;
+	type TreasuryIncome = Treasury;
 	type NftManager = Nft;
 	type BidsPoolAccount = BidsPoolAccount;
 	type AuctionSuccessFeePercentage = AuctionSuccessFeePercentage;
@@ -311,7 +323,18 @@ impl pallet_auction::Config for Runtime {
 	type MinimumIncrease = ConstU128<DOLLAR>;
 	type AuctionLength = AuctionLength;
 	type ExtendedLength = ExtendedLength;
+	type MaxAuctionsPerBlock = ConstU32<100>;
+}
+
+impl pallet_treasury::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
+	type Balance = Balance;
+	type Bank = Bank;
+	type EnsureGovernance = pallet_governance::EnsureGovernance;
+	type BudgetPeriod = ConstU32<{ 30 * DAY }>;
 }
+
 // Create the runtime by composing the FRAME pallets that were previously configured.
 construct_runtime!(
 	pub enum Runtime {
@@ -319,7 +342,6 @@ construct_runtime!(
 		Timestamp: pallet_timestamp,
 		Aura: pallet_aura,
 		Grandpa: pallet_grandpa,
-		Balances: pallet_balances,
 		TransactionPayment: pallet_transaction_payment,
 		Sudo: pallet_sudo,
 		Random: pallet_insecure_randomness_collective_flip,
@@ -330,6 +352,7 @@ construct_runtime!(
 		Governance: pallet_governance,
 		Nft: pallet_nft,
 		Auction: pallet_auction,
+		Treasury: pallet_treasury,
 	}
 );
 
@@ -351,11 +374,20 @@ pub type SignedExtra = (
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
 );
 
-/// All migrations of the runtime, aside from the ones declared in the pallets.
+/// All migrations of the runtime, including the storage migrations of the pallets.
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+	migrations::RemoveBalances,
+	pallet_bank::migrations::v1::MigrateToV1<Runtime>,
+	pallet_bank::migrations::v2::MigrateToV2<Runtime>,
+	pallet_bank::migrations::v3::MigrateToV3<Runtime>,
+	pallet_bank::migrations::v4::MigrateToV4<Runtime>,
+	pallet_bank::migrations::v5::MigrateToV5<Runtime>,
+	pallet_governance::migrations::v1::MigrateToV1<Runtime>,
+	pallet_treasury::migrations::v1::MigrateToV1<Runtime>,
+);
 
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
@@ -381,7 +413,6 @@ mod benches {
 	define_benchmarks!(
 		[frame_benchmarking, BaselineBench::<Runtime>]
 		[frame_system, SystemBench::<Runtime>]
-		[pallet_balances, Balances]
 		[pallet_timestamp, Timestamp]
 		[pallet_sudo, Sudo]
 		[pallet_bank, Bank]
@@ -390,14 +421,15 @@ mod benches {
 		[pallet_governance, Governance]
 		[pallet_nft, Nft]
 		[pallet_auction, Auction]
+		[pallet_treasury, Treasury]
 	);
 }
 
 impl_runtime_apis! {
 	impl runtime_api::CustomRuntimeApi<Block> for Runtime {
-		/// Returns account Data for a user
-		fn account_data(who: AccountId) -> pallet_bank::AccountData<Balance> {
-			Bank::accounts(who)
+		/// Returns account Data for a user, including the accrued interest.
+		fn account_data(who: AccountId) -> pallet_bank::AccountDataOf<Runtime> {
+			Bank::account_data(&who)
 		}
 		/// Calculate and returns the actual interest return per annum.
 		fn interest_pa(who: AccountId) -> Balance {
@@ -422,7 +454,8 @@ impl_runtime_apis! {
 							nft_id: pod_info.nft_id,
 							nft_name: pallet_nft::Nfts::<Runtime>::get(pod_info.nft_id)
 								.expect("Nft in POD must have an owner.")
-								.file_name,
+								.file_name
+								.into_inner(),
 							expiry_block: pod_info.expiry_block,
 							price: pod_info.price,
 						})
@@ -437,7 +470,8 @@ impl_runtime_apis! {
 							nft_id: pod_info.nft_id,
 							nft_name: pallet_nft::Nfts::<Runtime>::get(pod_info.nft_id)
 								.expect("Nft in POD must have an owner.")
-								.file_name,
+								.file_name
+								.into_inner(),
 							expiry_block: pod_info.expiry_block,
 							price: pod_info.price,
 						})
@@ -458,9 +492,75 @@ impl_runtime_apis! {
 		}
 
 		/// Return a specific NFT data with a NFT id.
-		fn nft_data(nft_id: NftId) -> Option<NftData>{
+		fn nft_data(nft_id: NftId) -> Option<NftDataOf<Runtime>>{
 			pallet_nft::Nfts::<Runtime>::get(nft_id)
 		}
+
+		/// Returns account Data of an asset for a user
+		fn asset_account_data(who: AccountId, asset_id: AssetId) -> pallet_bank::AccountDataOf<Runtime> {
+			Bank::asset_account(asset_id, &who)
+		}
+
+		/// Returns all the registered assets with their metadata.
+		fn assets() -> Vec<(AssetId, pallet_bank::AssetMetadata<Balance>)> {
+			pallet_bank::Assets::<Runtime>::iter().collect()
+		}
+
+		/// Returns the active standing orders paid by a user.
+		fn standing_orders(who: AccountId) -> Vec<(OrderId, pallet_bank::StandingOrderOf<Runtime>)> {
+			Bank::standing_orders_of(who)
+		}
+
+		/// Returns the catalogue of savings products.
+		fn products() -> Vec<(ProductId, pallet_bank::InterestProductOf<Runtime>)> {
+			pallet_bank::Products::<Runtime>::iter().collect()
+		}
+
+		/// Returns the product deposits of a user, with the interest earned so far.
+		fn product_deposits(who: AccountId) -> Vec<(LockId, pallet_bank::ProductDepositOf<Runtime>, Balance)> {
+			Bank::product_deposits_of(who)
+		}
+
+		/// Returns the open loans of a user, with the interest accrued so far.
+		fn loans(who: AccountId) -> Vec<(LockId, pallet_bank::LoanOf<Runtime>)> {
+			Bank::loans_of(who)
+		}
+
+		/// Returns the pending withdrawal requests of a user, or of all users without a user.
+		fn withdrawal_requests(who: Option<AccountId>) -> Vec<(LockId, pallet_bank::WithdrawalRequestOf<Runtime>)> {
+			Bank::withdrawal_requests_of(who)
+		}
+
+		/// Returns the freeze on a user's account, if it is frozen.
+		fn freeze_info(who: AccountId) -> Option<pallet_bank::FreezeInfoOf<Runtime>> {
+			Bank::frozen_accounts(who)
+		}
+
+		/// Returns the ledger entries of a user recorded between two blocks inclusive.
+		fn account_statement(who: AccountId, from_block: BlockNumber, to_block: BlockNumber, offset: u32, limit: u32) -> Vec<pallet_bank::LedgerEntryOf<Runtime>> {
+			Bank::account_statement(who, from_block, to_block, offset, limit)
+		}
+
+		/// Returns the fee a user pays to transfer an amount.
+		fn transfer_fee(who: AccountId, amount: Balance) -> Balance {
+			Bank::transfer_fee(&who, amount)
+		}
+
+		/// Returns the outgoing value a user can still send today and this week, or `None` if the
+		/// user is not limited.
+		fn remaining_allowance(who: AccountId) -> Option<pallet_bank::VelocityLimit<Balance>> {
+			Bank::remaining_allowance(&who)
+		}
+
+		/// Returns a page of the interest rates that took effect between two blocks inclusive.
+		fn interest_rate_history(from_block: BlockNumber, to_block: BlockNumber, offset: u32, limit: u32) -> Vec<(BlockNumber, Perbill)> {
+			Bank::interest_rate_history(from_block, to_block, offset, limit)
+		}
+
+		/// Returns the treasury reports of the budget periods between two blocks inclusive.
+		fn treasury_reports(from_block: BlockNumber, to_block: BlockNumber) -> Vec<(BlockNumber, pallet_treasury::TreasuryReport<Balance>)> {
+			Treasury::reports_between(from_block, to_block)
+		}
 	}
 
 	impl sp_api::Core<Block> for Runtime {
@@ -0,0 +1,77 @@
+//! Storage migrations of the runtime, aside from the ones declared in the pallets.
+
+use codec::{Decode, Encode};
+use frame_support::{
+	migrations::RemovePallet,
+	parameter_types,
+	storage::migration::have_storage_value,
+	traits::{Get, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use frame_system::AccountInfo;
+
+use crate::{Balance, Nonce, Runtime};
+
+type DbWeight = <Runtime as frame_system::Config>::DbWeight;
+
+parameter_types! {
+	pub const BalancesPalletName: &'static str = "Balances";
+}
+
+/// The account data `pallet_balances` kept in `frame_system::Account`.
+#[derive(Encode, Decode)]
+struct BalancesAccountData {
+	free: Balance,
+	reserved: Balance,
+	frozen: Balance,
+	flags: u128,
+}
+
+/// Strips the account data of `pallet_balances` from `frame_system::Account`, now that
+/// pallet-bank keeps every balance.
+///
+/// The provider and consumer references of `pallet_balances` are dropped, and every bank account
+/// is given a provider reference by pallet-bank instead. Accounts left without any reference are
+/// removed.
+pub struct StripBalancesAccountData;
+
+impl OnRuntimeUpgrade for StripBalancesAccountData {
+	fn on_runtime_upgrade() -> Weight {
+		// The account data is only there while the storage of `pallet_balances` is.
+		if !have_storage_value(b"Balances", b"TotalIssuance", b"") {
+			return DbWeight::get().reads(1);
+		}
+
+		let mut translated = 0u64;
+		frame_system::Account::<Runtime>::translate::<AccountInfo<Nonce, BalancesAccountData>, _>(
+			|who, info| {
+				translated += 1;
+				let providers = info.providers.saturating_sub(1) +
+					pallet_bank::Accounts::<Runtime>::contains_key(&who) as u32;
+				(providers > 0 || info.sufficients > 0).then_some(AccountInfo {
+					nonce: info.nonce,
+					consumers: 0,
+					providers,
+					sufficients: info.sufficients,
+					data: (),
+				})
+			},
+		);
+
+		// Bank accounts that never held a balance in `pallet_balances`.
+		let (mut visited, mut provided) = (0u64, 0u64);
+		pallet_bank::Accounts::<Runtime>::iter_keys().for_each(|who| {
+			visited += 1;
+			if !frame_system::Account::<Runtime>::contains_key(&who) {
+				frame_system::Pallet::<Runtime>::inc_providers(&who);
+				provided += 1;
+			}
+		});
+
+		DbWeight::get().reads_writes(2 * translated + 2 * visited + 1, translated + provided)
+	}
+}
+
+/// Removes `pallet_balances` from the runtime: strips its account data from
+/// `frame_system::Account`, then clears the rest of its storage.
+pub type RemoveBalances = (StripBalancesAccountData, RemovePallet<BalancesPalletName, DbWeight>);
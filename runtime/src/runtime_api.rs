@@ -2,14 +2,21 @@
 
 use codec::{Decode, Encode};
 use pallet_auction::AuctionDataFor;
+use pallet_treasury::TreasuryReport;
 use scale_info::TypeInfo;
 
 use sp_api::decl_runtime_apis;
-use sp_runtime::DispatchError;
+use sp_runtime::{DispatchError, Perbill};
 use sp_std::vec::Vec;
 
-use pallet_bank::AccountData;
-use primitives::{AccountId, AuctionId, Balance, BlockNumber, LockId, NftId, PendingNftPods};
+use pallet_bank::{
+	AccountDataOf, AssetMetadata, FreezeInfo, InterestProduct, LedgerEntry, Loan, LockedFund,
+	ProductDeposit, StandingOrder, VelocityLimit, WithdrawalRequest,
+};
+use primitives::{
+	AccountId, AssetId, AuctionId, Balance, BlockNumber, LockId, NftId, OrderId, PendingNftPods,
+	ProductId,
+};
 
 /// Custom tool for translating Dispatch error to a human readable format.
 #[derive(Debug, Decode, Encode, TypeInfo)]
@@ -28,11 +35,26 @@ impl From<DispatchError> for DispatchErrorTranslator {
 	}
 }
 
+/// The account data of a user returned by version 1 of the API, without the overdraft debt.
+#[derive(Debug, Decode, Encode, TypeInfo)]
+pub struct AccountDataV1 {
+	pub free: Balance,
+	pub reserved: Balance,
+	pub locked: Vec<LockedFund<Balance>>,
+}
+
 decl_runtime_apis!(
 	/// Custom Runtime API for the xy-chain
+	///
+	/// Version 2 returns the overdraft debt in the account data, and adds the methods after
+	/// `nft_data`.
+	#[api_version(2)]
 	pub trait CustomRuntimeApi {
+		/// Returns account Data for a user, including the accrued interest.
+		fn account_data(who: AccountId) -> AccountDataOf<crate::Runtime>;
 		/// Returns account Data for a user
-		fn account_data(who: AccountId) -> AccountData<Balance>;
+		#[changed_in(2)]
+		fn account_data(who: AccountId) -> AccountDataV1;
 		/// Calculate and returns the actual interest return per annum.
 		fn interest_pa(who: AccountId) -> Balance;
 		/// Returns when a locked fund is released.
@@ -45,6 +67,56 @@ decl_runtime_apis!(
 			auction_id: Option<AuctionId>,
 		) -> Vec<(AuctionId, AuctionDataFor<crate::Runtime>)>;
 		/// Return a specific NFT data with a NFT id.
-		fn nft_data(nft_id: NftId) -> Option<pallet_nft::NftData>;
+		fn nft_data(nft_id: NftId) -> Option<pallet_nft::NftDataOf<crate::Runtime>>;
+		/// Returns account Data of an asset for a user
+		fn asset_account_data(who: AccountId, asset_id: AssetId) -> AccountDataOf<crate::Runtime>;
+		/// Returns all the registered assets with their metadata.
+		fn assets() -> Vec<(AssetId, AssetMetadata<Balance>)>;
+		/// Returns the active standing orders paid by a user.
+		fn standing_orders(
+			who: AccountId,
+		) -> Vec<(OrderId, StandingOrder<AccountId, Balance, BlockNumber>)>;
+		/// Returns the catalogue of savings products.
+		fn products() -> Vec<(ProductId, InterestProduct<Balance, BlockNumber>)>;
+		/// Returns the product deposits of a user, with the interest earned so far.
+		fn product_deposits(
+			who: AccountId,
+		) -> Vec<(LockId, ProductDeposit<Balance, BlockNumber>, Balance)>;
+		/// Returns the open loans of a user, with the interest accrued so far.
+		fn loans(who: AccountId) -> Vec<(LockId, Loan<AccountId, Balance, BlockNumber>)>;
+		/// Returns the freeze on a user's account, if it is frozen.
+		fn freeze_info(who: AccountId) -> Option<FreezeInfo<AccountId, BlockNumber>>;
+		/// Returns a page of the ledger entries of a user recorded between two blocks inclusive,
+		/// skipping `offset` entries and holding at most `limit` entries.
+		fn account_statement(
+			who: AccountId,
+			from_block: BlockNumber,
+			to_block: BlockNumber,
+			offset: u32,
+			limit: u32,
+		) -> Vec<LedgerEntry<AccountId, Balance, BlockNumber>>;
+		/// Returns the fee a user pays to transfer an amount.
+		fn transfer_fee(who: AccountId, amount: Balance) -> Balance;
+		/// Returns the outgoing value a user can still send today and this week, or `None` if the
+		/// user is not limited.
+		fn remaining_allowance(who: AccountId) -> Option<VelocityLimit<Balance>>;
+		/// Returns a page of the interest rates that took effect between two blocks inclusive,
+		/// with the block they took effect at, skipping `offset` rates and holding at most
+		/// `limit` rates.
+		fn interest_rate_history(
+			from_block: BlockNumber,
+			to_block: BlockNumber,
+			offset: u32,
+			limit: u32,
+		) -> Vec<(BlockNumber, Perbill)>;
+		/// Returns the pending withdrawal requests of a user, or of all users without a user.
+		fn withdrawal_requests(
+			who: Option<AccountId>,
+		) -> Vec<(LockId, WithdrawalRequest<AccountId, Balance, BlockNumber>)>;
+		/// Returns the treasury reports of the budget periods between two blocks inclusive.
+		fn treasury_reports(
+			from_block: BlockNumber,
+			to_block: BlockNumber,
+		) -> Vec<(BlockNumber, TreasuryReport<Balance>)>;
 	}
 );
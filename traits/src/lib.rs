@@ -4,7 +4,7 @@
 
 use sp_runtime::{DispatchError, DispatchResult};
 
-use primitives::{AuctionId, NftId, NftState, Role};
+use primitives::{AuctionId, IncomeSource, NftId, NftState, Role, TransferReason};
 
 use sp_std::marker::PhantomData;
 
@@ -29,6 +29,20 @@ pub trait BasicAccounting<AccountId, Balance> {
 	fn deposit(user: &AccountId, amount: Balance) -> DispatchResult;
 	fn withdraw(user: &AccountId, amount: Balance) -> DispatchResult;
 	fn transfer(from: &AccountId, to: &AccountId, amount: Balance) -> DispatchResult;
+	/// Transfer with the reason of the transfer, recorded in its event and ledger entries.
+	fn transfer_with_reason(
+		from: &AccountId,
+		to: &AccountId,
+		amount: Balance,
+		reason: TransferReason,
+	) -> DispatchResult;
+	/// Transfer on behalf of `from`, spending the allowance `from` granted to `spender`.
+	fn transfer_from(
+		spender: &AccountId,
+		from: &AccountId,
+		to: &AccountId,
+		amount: Balance,
+	) -> DispatchResult;
 	fn free_balance(user: &AccountId) -> Balance;
 }
 
@@ -39,6 +53,26 @@ pub trait Stakable<AccountId, Balance> {
 	fn staked(user: &AccountId) -> Balance;
 }
 
+/// A trait for basic accounting operations on a specific asset.
+pub trait MultiAssetAccounting<AccountId, AssetId, Balance> {
+	fn deposit(asset_id: AssetId, user: &AccountId, amount: Balance) -> DispatchResult;
+	fn withdraw(asset_id: AssetId, user: &AccountId, amount: Balance) -> DispatchResult;
+	fn transfer(
+		asset_id: AssetId,
+		from: &AccountId,
+		to: &AccountId,
+		amount: Balance,
+	) -> DispatchResult;
+	fn free_balance(asset_id: AssetId, user: &AccountId) -> Balance;
+}
+
+/// A trait for stake and redeem funds of a specific asset.
+pub trait MultiAssetStakable<AccountId, AssetId, Balance> {
+	fn stake_funds(asset_id: AssetId, user: &AccountId, amount: Balance) -> DispatchResult;
+	fn redeem_funds(asset_id: AssetId, user: &AccountId, amount: Balance) -> DispatchResult;
+	fn staked(asset_id: AssetId, user: &AccountId) -> Balance;
+}
+
 /// A trait for Nft operations like request mint, burn, transfer and approve.
 pub trait ManageNfts<AccountId> {
 	fn nft_transfer(nft_id: NftId, to_user: &AccountId) -> Result<AccountId, DispatchError>;
@@ -53,6 +87,8 @@ pub trait ManageNfts<AccountId> {
 /// A trait for Auction operations like force cancel.
 pub trait ManageAuctions<AccountId> {
 	fn force_cancel(auction_id: AuctionId) -> DispatchResult;
+	/// Returns the NFT of an auction, if the auction exists.
+	fn auction_nft(auction_id: AuctionId) -> Option<NftId>;
 }
 
 /// A trait for getting the treasury account.
@@ -60,6 +96,15 @@ pub trait GetTreasury<AccountId> {
 	fn treasury() -> Result<AccountId, DispatchError>;
 }
 
+/// A trait for recording the income paid into the treasury account.
+pub trait RecordTreasuryIncome<Balance> {
+	fn record_income(source: IncomeSource, amount: Balance);
+}
+
+impl<Balance> RecordTreasuryIncome<Balance> for () {
+	fn record_income(_source: IncomeSource, _amount: Balance) {}
+}
+
 pub struct SuccessOrigin<T>(PhantomData<T>);
 
 impl<T: frame_system::Config> frame_support::traits::EnsureOrigin<T::RuntimeOrigin>